@@ -739,7 +739,11 @@ public class LoginUIController : MonoBehaviour
             WorldCoords: worldCoords,
             WorldName: "Center World",
             WorldType: "center",
-            ShellLevel: 0
+            ShellLevel: 0,
+            AllowedFrequencies: new(),
+            RadiusX: null,
+            RadiusY: null,
+            RadiusZ: null
         );
         
         GameEventBus.Instance.Publish(new WorldLoadedEvent
@@ -603,7 +603,7 @@ namespace SYSTEM.Game
                 selectedDestination.Type == LocationType.StorageDevice)
             {
                 // Inventory to storage - use initiate_transfer reducer
-                GameManager.Conn.Reducers.InitiateTransfer(composition, selectedDestination.DeviceId);
+                GameManager.Conn.Reducers.InitiateTransfer(composition, selectedDestination.DeviceId, null, null); // default priority, all-or-nothing
             }
             else
             {
@@ -12,12 +12,12 @@ namespace SpacetimeDB.Types
 {
     public sealed partial class RemoteReducers : RemoteBase
     {
-        public delegate void InitiateTransferHandler(ReducerEventContext ctx, System.Collections.Generic.List<WavePacketSample> composition, ulong destinationDeviceId);
+        public delegate void InitiateTransferHandler(ReducerEventContext ctx, System.Collections.Generic.List<WavePacketSample> composition, ulong destinationDeviceId, byte? priority, bool? partialFill);
         public event InitiateTransferHandler? OnInitiateTransfer;
 
-        public void InitiateTransfer(System.Collections.Generic.List<WavePacketSample> composition, ulong destinationDeviceId)
+        public void InitiateTransfer(System.Collections.Generic.List<WavePacketSample> composition, ulong destinationDeviceId, byte? priority, bool? partialFill)
         {
-            conn.InternalCallReducer(new Reducer.InitiateTransfer(composition, destinationDeviceId), this.SetCallReducerFlags.InitiateTransferFlags);
+            conn.InternalCallReducer(new Reducer.InitiateTransfer(composition, destinationDeviceId, priority, partialFill), this.SetCallReducerFlags.InitiateTransferFlags);
         }
 
         public bool InvokeInitiateTransfer(ReducerEventContext ctx, Reducer.InitiateTransfer args)
@@ -37,7 +37,9 @@ namespace SpacetimeDB.Types
             OnInitiateTransfer(
                 ctx,
                 args.Composition,
-                args.DestinationDeviceId
+                args.DestinationDeviceId,
+                args.Priority,
+                args.PartialFill
             );
             return true;
         }
@@ -53,14 +55,22 @@ namespace SpacetimeDB.Types
             public System.Collections.Generic.List<WavePacketSample> Composition;
             [DataMember(Name = "destination_device_id")]
             public ulong DestinationDeviceId;
+            [DataMember(Name = "priority")]
+            public byte? Priority;
+            [DataMember(Name = "partial_fill")]
+            public bool? PartialFill;
 
             public InitiateTransfer(
                 System.Collections.Generic.List<WavePacketSample> Composition,
-                ulong DestinationDeviceId
+                ulong DestinationDeviceId,
+                byte? Priority,
+                bool? PartialFill
             )
             {
                 this.Composition = Composition;
                 this.DestinationDeviceId = DestinationDeviceId;
+                this.Priority = Priority;
+                this.PartialFill = PartialFill;
             }
 
             public InitiateTransfer()
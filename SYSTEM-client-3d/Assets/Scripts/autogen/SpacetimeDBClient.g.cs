@@ -22,26 +22,59 @@ namespace SpacetimeDB.Types
         public RemoteTables(DbConnection conn)
         {
             AddTable(Account = new(conn));
+            AddTable(AdminIdentity = new(conn));
             AddTable(BroadcastMessage = new(conn));
             AddTable(DistributionSphere = new(conn));
             AddTable(EnergySpire = new(conn));
+            AddTable(FrequencyAvailability = new(conn));
             AddTable(GameLoopSchedule = new(conn));
             AddTable(GameTickCounter = new(conn));
+            AddTable(InventorySnapshot = new(conn));
+            AddTable(LogConfig = new(conn));
             AddTable(LoggedOutPlayer = new(conn));
+            AddTable(MaintenanceSchedule = new(conn));
+            AddTable(MiningLeaderboard = new(conn));
             AddTable(MiningSession = new(conn));
+            AddTable(MyStorageList = new(conn));
+            AddTable(NearbyPlayers = new(conn));
+            AddTable(NearestMineableOrb = new(conn));
+            AddTable(NetWorth = new(conn));
+            AddTable(OrbCompositionReport = new(conn));
+            AddTable(OrbDepletedEvent = new(conn));
+            AddTable(OrbMiners = new(conn));
+            AddTable(OrbSpawnEvent = new(conn));
+            AddTable(PacketEconomy = new(conn));
             AddTable(PacketTransfer = new(conn));
             AddTable(Player = new(conn));
             AddTable(PlayerChatMessage = new(conn));
             AddTable(PlayerCrystal = new(conn));
             AddTable(PlayerInventory = new(conn));
+            AddTable(PlayerLookupResult = new(conn));
             AddTable(PlayerSession = new(conn));
+            AddTable(PlayerSettings = new(conn));
+            AddTable(PlayerStats = new(conn));
             AddTable(QuantumTunnel = new(conn));
             AddTable(SessionResult = new(conn));
+            AddTable(SpawnCounter = new(conn));
+            AddTable(SphereThroughputSample = new(conn));
             AddTable(StorageDevice = new(conn));
+            AddTable(StorageDeviceFullness = new(conn));
+            AddTable(TradeOffer = new(conn));
+            AddTable(TransferEvent = new(conn));
+            AddTable(TransferHistory = new(conn));
+            AddTable(TransferLog = new(conn));
+            AddTable(TransferRemainder = new(conn));
+            AddTable(TransferValidation = new(conn));
+            AddTable(TunnelPathResult = new(conn));
             AddTable(WavePacketExtraction = new(conn));
             AddTable(WavePacketSource = new(conn));
             AddTable(World = new(conn));
             AddTable(WorldCircuit = new(conn));
+            AddTable(WorldConstants = new(conn));
+            AddTable(WorldGameLoopSchedule = new(conn));
+            AddTable(WorldSnapshot = new(conn));
+            AddTable(WorldSummary = new(conn));
+            AddTable(WorldTickCounter = new(conn));
         }
     }
 
@@ -486,41 +519,93 @@ namespace SpacetimeDB.Types
             return update.ReducerCall.ReducerName switch
             {
                 "__identity_disconnected__" => BSATNHelpers.Decode<Reducer.IdentityDisconnected>(encodedArgs),
+                "accept_trade_offer" => BSATNHelpers.Decode<Reducer.AcceptTradeOffer>(encodedArgs),
                 "add_test_inventory" => BSATNHelpers.Decode<Reducer.AddTestInventory>(encodedArgs),
                 "broadcast_chat_message" => BSATNHelpers.Decode<Reducer.BroadcastChatMessage>(encodedArgs),
+                "can_mine_frequency" => BSATNHelpers.Decode<Reducer.CanMineFrequency>(encodedArgs),
+                "cancel_trade_offer" => BSATNHelpers.Decode<Reducer.CancelTradeOffer>(encodedArgs),
                 "capture_extracted_packet_v2" => BSATNHelpers.Decode<Reducer.CaptureExtractedPacketV2>(encodedArgs),
                 "choose_crystal" => BSATNHelpers.Decode<Reducer.ChooseCrystal>(encodedArgs),
                 "cleanup_expired_sessions" => BSATNHelpers.Decode<Reducer.CleanupExpiredSessions>(encodedArgs),
+                "cleanup_inactive_mining_sessions" => BSATNHelpers.Decode<Reducer.CleanupInactiveMiningSessions>(encodedArgs),
                 "cleanup_my_mining_sessions" => BSATNHelpers.Decode<Reducer.CleanupMyMiningSessions>(encodedArgs),
+                "cleanup_stale_logged_out_players" => BSATNHelpers.Decode<Reducer.CleanupStaleLoggedOutPlayers>(encodedArgs),
                 "clear_all_sources" => BSATNHelpers.Decode<Reducer.ClearAllSources>(encodedArgs),
                 "clear_all_storage_devices" => BSATNHelpers.Decode<Reducer.ClearAllStorageDevices>(encodedArgs),
                 "complete_transfer" => BSATNHelpers.Decode<Reducer.CompleteTransfer>(encodedArgs),
+                "compute_net_worth" => BSATNHelpers.Decode<Reducer.ComputeNetWorth>(encodedArgs),
                 "create_energy_spire" => BSATNHelpers.Decode<Reducer.CreateEnergySpire>(encodedArgs),
                 "create_player" => BSATNHelpers.Decode<Reducer.CreatePlayer>(encodedArgs),
                 "create_storage_device" => BSATNHelpers.Decode<Reducer.CreateStorageDevice>(encodedArgs),
+                "create_trade_offer" => BSATNHelpers.Decode<Reducer.CreateTradeOffer>(encodedArgs),
+                "create_world" => BSATNHelpers.Decode<Reducer.CreateWorld>(encodedArgs),
                 "debug_add_test_packets" => BSATNHelpers.Decode<Reducer.DebugAddTestPackets>(encodedArgs),
                 "debug_create_storage_device" => BSATNHelpers.Decode<Reducer.DebugCreateStorageDevice>(encodedArgs),
+                "debug_force_complete_transfer" => BSATNHelpers.Decode<Reducer.DebugForceCompleteTransfer>(encodedArgs),
                 "debug_give_crystal" => BSATNHelpers.Decode<Reducer.DebugGiveCrystal>(encodedArgs),
                 "debug_list_extractions" => BSATNHelpers.Decode<Reducer.DebugListExtractions>(encodedArgs),
                 "debug_mining_status" => BSATNHelpers.Decode<Reducer.DebugMiningStatus>(encodedArgs),
                 "debug_reset_spawn_position" => BSATNHelpers.Decode<Reducer.DebugResetSpawnPosition>(encodedArgs),
+                "debug_teleport_to_spire" => BSATNHelpers.Decode<Reducer.DebugTeleportToSpire>(encodedArgs),
                 "debug_test_spawn_positions" => BSATNHelpers.Decode<Reducer.DebugTestSpawnPositions>(encodedArgs),
                 "debug_validate_all_players" => BSATNHelpers.Decode<Reducer.DebugValidateAllPlayers>(encodedArgs),
+                "debug_validate_orbs" => BSATNHelpers.Decode<Reducer.DebugValidateOrbs>(encodedArgs),
+                "delete_storage_device" => BSATNHelpers.Decode<Reducer.DeleteStorageDevice>(encodedArgs),
                 "disconnect" => BSATNHelpers.Decode<Reducer.Disconnect>(encodedArgs),
+                "disconnect_tunnel" => BSATNHelpers.Decode<Reducer.DisconnectTunnel>(encodedArgs),
+                "distribute_to_devices" => BSATNHelpers.Decode<Reducer.DistributeToDevices>(encodedArgs),
                 "emit_wave_packet_source" => BSATNHelpers.Decode<Reducer.EmitWavePacketSource>(encodedArgs),
                 "ensure_player_inventory" => BSATNHelpers.Decode<Reducer.EnsurePlayerInventory>(encodedArgs),
+                "equip_crystal" => BSATNHelpers.Decode<Reducer.EquipCrystal>(encodedArgs),
+                "export_world_snapshot" => BSATNHelpers.Decode<Reducer.ExportWorldSnapshot>(encodedArgs),
+                "extract_by_color" => BSATNHelpers.Decode<Reducer.ExtractByColor>(encodedArgs),
                 "extract_packets_v2" => BSATNHelpers.Decode<Reducer.ExtractPacketsV2>(encodedArgs),
+                "find_nearest_orb" => BSATNHelpers.Decode<Reducer.FindNearestOrb>(encodedArgs),
+                "find_player_by_name" => BSATNHelpers.Decode<Reducer.FindPlayerByName>(encodedArgs),
+                "find_tunnel_path" => BSATNHelpers.Decode<Reducer.FindTunnelPath>(encodedArgs),
+                "flush_sphere_buffer" => BSATNHelpers.Decode<Reducer.FlushSphereBuffer>(encodedArgs),
                 "game_loop" => BSATNHelpers.Decode<Reducer.GameLoop>(encodedArgs),
+                "generate_shell" => BSATNHelpers.Decode<Reducer.GenerateShell>(encodedArgs),
+                "get_inventory_snapshot" => BSATNHelpers.Decode<Reducer.GetInventorySnapshot>(encodedArgs),
+                "get_transfer_history" => BSATNHelpers.Decode<Reducer.GetTransferHistory>(encodedArgs),
+                "gift_packets" => BSATNHelpers.Decode<Reducer.GiftPackets>(encodedArgs),
+                "grant_admin" => BSATNHelpers.Decode<Reducer.GrantAdmin>(encodedArgs),
+                "import_world_snapshot" => BSATNHelpers.Decode<Reducer.ImportWorldSnapshot>(encodedArgs),
                 "initialize_player_inventory" => BSATNHelpers.Decode<Reducer.InitializePlayerInventory>(encodedArgs),
                 "initiate_transfer" => BSATNHelpers.Decode<Reducer.InitiateTransfer>(encodedArgs),
+                "inspect_orb" => BSATNHelpers.Decode<Reducer.InspectOrb>(encodedArgs),
+                "inspect_orb_miners" => BSATNHelpers.Decode<Reducer.InspectOrbMiners>(encodedArgs),
                 "list_active_mining" => BSATNHelpers.Decode<Reducer.ListActiveMining>(encodedArgs),
+                "list_my_storage_devices" => BSATNHelpers.Decode<Reducer.ListMyStorageDevices>(encodedArgs),
                 "login" => BSATNHelpers.Decode<Reducer.Login>(encodedArgs),
                 "login_with_session" => BSATNHelpers.Decode<Reducer.LoginWithSession>(encodedArgs),
                 "logout" => BSATNHelpers.Decode<Reducer.Logout>(encodedArgs),
+                "mine_to_storage" => BSATNHelpers.Decode<Reducer.MineToStorage>(encodedArgs),
+                "move_storage_device" => BSATNHelpers.Decode<Reducer.MoveStorageDevice>(encodedArgs),
+                "reclaim_stuck_transfers" => BSATNHelpers.Decode<Reducer.ReclaimStuckTransfers>(encodedArgs),
+                "refresh_mining_leaderboard" => BSATNHelpers.Decode<Reducer.RefreshMiningLeaderboard>(encodedArgs),
+                "refresh_nearby_players" => BSATNHelpers.Decode<Reducer.RefreshNearbyPlayers>(encodedArgs),
+                "refresh_packet_economy" => BSATNHelpers.Decode<Reducer.RefreshPacketEconomy>(encodedArgs),
+                "refresh_storage_fullness" => BSATNHelpers.Decode<Reducer.RefreshStorageFullness>(encodedArgs),
+                "refresh_world_summary" => BSATNHelpers.Decode<Reducer.RefreshWorldSummary>(encodedArgs),
                 "register_account" => BSATNHelpers.Decode<Reducer.RegisterAccount>(encodedArgs),
+                "rename_storage_device" => BSATNHelpers.Decode<Reducer.RenameStorageDevice>(encodedArgs),
+                "reset_crystal" => BSATNHelpers.Decode<Reducer.ResetCrystal>(encodedArgs),
                 "restore_session" => BSATNHelpers.Decode<Reducer.RestoreSession>(encodedArgs),
+                "resurface_orb" => BSATNHelpers.Decode<Reducer.ResurfaceOrb>(encodedArgs),
                 "send_player_chat" => BSATNHelpers.Decode<Reducer.SendPlayerChat>(encodedArgs),
+                "set_auto_capture" => BSATNHelpers.Decode<Reducer.SetAutoCapture>(encodedArgs),
+                "set_circuit_emission_composition" => BSATNHelpers.Decode<Reducer.SetCircuitEmissionComposition>(encodedArgs),
+                "set_circuit_enabled" => BSATNHelpers.Decode<Reducer.SetCircuitEnabled>(encodedArgs),
+                "set_frequency_capacity" => BSATNHelpers.Decode<Reducer.SetFrequencyCapacity>(encodedArgs),
+                "set_log_level" => BSATNHelpers.Decode<Reducer.SetLogLevel>(encodedArgs),
+                "set_orb_composition" => BSATNHelpers.Decode<Reducer.SetOrbComposition>(encodedArgs),
+                "set_orb_lifetime" => BSATNHelpers.Decode<Reducer.SetOrbLifetime>(encodedArgs),
                 "set_orb_packets" => BSATNHelpers.Decode<Reducer.SetOrbPackets>(encodedArgs),
+                "set_storage_capacity" => BSATNHelpers.Decode<Reducer.SetStorageCapacity>(encodedArgs),
+                "set_world_allowed_frequencies" => BSATNHelpers.Decode<Reducer.SetWorldAllowedFrequencies>(encodedArgs),
+                "set_world_constant" => BSATNHelpers.Decode<Reducer.SetWorldConstant>(encodedArgs),
+                "set_world_radii" => BSATNHelpers.Decode<Reducer.SetWorldRadii>(encodedArgs),
                 "spawn_6_cardinal_circuits" => BSATNHelpers.Decode<Reducer.Spawn6CardinalCircuits>(encodedArgs),
                 "spawn_all_26_spires" => BSATNHelpers.Decode<Reducer.SpawnAll26Spires>(encodedArgs),
                 "spawn_circuit_at_spire" => BSATNHelpers.Decode<Reducer.SpawnCircuitAtSpire>(encodedArgs),
@@ -528,15 +613,22 @@ namespace SpacetimeDB.Types
                 "spawn_full_spectrum_orb" => BSATNHelpers.Decode<Reducer.SpawnFullSpectrumOrb>(encodedArgs),
                 "spawn_main_spires" => BSATNHelpers.Decode<Reducer.SpawnMainSpires>(encodedArgs),
                 "spawn_mixed_orb" => BSATNHelpers.Decode<Reducer.SpawnMixedOrb>(encodedArgs),
+                "spawn_orb_line" => BSATNHelpers.Decode<Reducer.SpawnOrbLine>(encodedArgs),
                 "spawn_test_orb" => BSATNHelpers.Decode<Reducer.SpawnTestOrb>(encodedArgs),
                 "start_game_loop" => BSATNHelpers.Decode<Reducer.StartGameLoop>(encodedArgs),
                 "start_mining_v2" => BSATNHelpers.Decode<Reducer.StartMiningV2>(encodedArgs),
+                "start_world_game_loop" => BSATNHelpers.Decode<Reducer.StartWorldGameLoop>(encodedArgs),
                 "stop_game_loop" => BSATNHelpers.Decode<Reducer.StopGameLoop>(encodedArgs),
                 "stop_mining_v2" => BSATNHelpers.Decode<Reducer.StopMiningV2>(encodedArgs),
+                "stop_world_game_loop" => BSATNHelpers.Decode<Reducer.StopWorldGameLoop>(encodedArgs),
                 "tick" => BSATNHelpers.Decode<Reducer.Tick>(encodedArgs),
                 "tick_player_transfers" => BSATNHelpers.Decode<Reducer.TickPlayerTransfers>(encodedArgs),
                 "travel_to_world" => BSATNHelpers.Decode<Reducer.TravelToWorld>(encodedArgs),
+                "tune_frequency" => BSATNHelpers.Decode<Reducer.TuneFrequency>(encodedArgs),
+                "unlock_crystal_slot" => BSATNHelpers.Decode<Reducer.UnlockCrystalSlot>(encodedArgs),
                 "update_player_position" => BSATNHelpers.Decode<Reducer.UpdatePlayerPosition>(encodedArgs),
+                "validate_transfer" => BSATNHelpers.Decode<Reducer.ValidateTransfer>(encodedArgs),
+                "world_game_loop" => BSATNHelpers.Decode<Reducer.WorldGameLoop>(encodedArgs),
                 "world_sphere_pulse" => BSATNHelpers.Decode<Reducer.WorldSpherePulse>(encodedArgs),
                 var reducer => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
@@ -560,41 +652,93 @@ namespace SpacetimeDB.Types
             return reducer switch
             {
                 Reducer.IdentityDisconnected args => Reducers.InvokeIdentityDisconnected(eventContext, args),
+                Reducer.AcceptTradeOffer args => Reducers.InvokeAcceptTradeOffer(eventContext, args),
                 Reducer.AddTestInventory args => Reducers.InvokeAddTestInventory(eventContext, args),
                 Reducer.BroadcastChatMessage args => Reducers.InvokeBroadcastChatMessage(eventContext, args),
+                Reducer.CanMineFrequency args => Reducers.InvokeCanMineFrequency(eventContext, args),
+                Reducer.CancelTradeOffer args => Reducers.InvokeCancelTradeOffer(eventContext, args),
                 Reducer.CaptureExtractedPacketV2 args => Reducers.InvokeCaptureExtractedPacketV2(eventContext, args),
                 Reducer.ChooseCrystal args => Reducers.InvokeChooseCrystal(eventContext, args),
                 Reducer.CleanupExpiredSessions args => Reducers.InvokeCleanupExpiredSessions(eventContext, args),
+                Reducer.CleanupInactiveMiningSessions args => Reducers.InvokeCleanupInactiveMiningSessions(eventContext, args),
                 Reducer.CleanupMyMiningSessions args => Reducers.InvokeCleanupMyMiningSessions(eventContext, args),
+                Reducer.CleanupStaleLoggedOutPlayers args => Reducers.InvokeCleanupStaleLoggedOutPlayers(eventContext, args),
                 Reducer.ClearAllSources args => Reducers.InvokeClearAllSources(eventContext, args),
                 Reducer.ClearAllStorageDevices args => Reducers.InvokeClearAllStorageDevices(eventContext, args),
                 Reducer.CompleteTransfer args => Reducers.InvokeCompleteTransfer(eventContext, args),
+                Reducer.ComputeNetWorth args => Reducers.InvokeComputeNetWorth(eventContext, args),
                 Reducer.CreateEnergySpire args => Reducers.InvokeCreateEnergySpire(eventContext, args),
                 Reducer.CreatePlayer args => Reducers.InvokeCreatePlayer(eventContext, args),
                 Reducer.CreateStorageDevice args => Reducers.InvokeCreateStorageDevice(eventContext, args),
+                Reducer.CreateTradeOffer args => Reducers.InvokeCreateTradeOffer(eventContext, args),
+                Reducer.CreateWorld args => Reducers.InvokeCreateWorld(eventContext, args),
                 Reducer.DebugAddTestPackets args => Reducers.InvokeDebugAddTestPackets(eventContext, args),
                 Reducer.DebugCreateStorageDevice args => Reducers.InvokeDebugCreateStorageDevice(eventContext, args),
+                Reducer.DebugForceCompleteTransfer args => Reducers.InvokeDebugForceCompleteTransfer(eventContext, args),
                 Reducer.DebugGiveCrystal args => Reducers.InvokeDebugGiveCrystal(eventContext, args),
                 Reducer.DebugListExtractions args => Reducers.InvokeDebugListExtractions(eventContext, args),
                 Reducer.DebugMiningStatus args => Reducers.InvokeDebugMiningStatus(eventContext, args),
                 Reducer.DebugResetSpawnPosition args => Reducers.InvokeDebugResetSpawnPosition(eventContext, args),
+                Reducer.DebugTeleportToSpire args => Reducers.InvokeDebugTeleportToSpire(eventContext, args),
                 Reducer.DebugTestSpawnPositions args => Reducers.InvokeDebugTestSpawnPositions(eventContext, args),
                 Reducer.DebugValidateAllPlayers args => Reducers.InvokeDebugValidateAllPlayers(eventContext, args),
+                Reducer.DebugValidateOrbs args => Reducers.InvokeDebugValidateOrbs(eventContext, args),
+                Reducer.DeleteStorageDevice args => Reducers.InvokeDeleteStorageDevice(eventContext, args),
                 Reducer.Disconnect args => Reducers.InvokeDisconnect(eventContext, args),
+                Reducer.DisconnectTunnel args => Reducers.InvokeDisconnectTunnel(eventContext, args),
+                Reducer.DistributeToDevices args => Reducers.InvokeDistributeToDevices(eventContext, args),
                 Reducer.EmitWavePacketSource args => Reducers.InvokeEmitWavePacketSource(eventContext, args),
                 Reducer.EnsurePlayerInventory args => Reducers.InvokeEnsurePlayerInventory(eventContext, args),
+                Reducer.EquipCrystal args => Reducers.InvokeEquipCrystal(eventContext, args),
+                Reducer.ExportWorldSnapshot args => Reducers.InvokeExportWorldSnapshot(eventContext, args),
+                Reducer.ExtractByColor args => Reducers.InvokeExtractByColor(eventContext, args),
                 Reducer.ExtractPacketsV2 args => Reducers.InvokeExtractPacketsV2(eventContext, args),
+                Reducer.FindNearestOrb args => Reducers.InvokeFindNearestOrb(eventContext, args),
+                Reducer.FindPlayerByName args => Reducers.InvokeFindPlayerByName(eventContext, args),
+                Reducer.FindTunnelPath args => Reducers.InvokeFindTunnelPath(eventContext, args),
+                Reducer.FlushSphereBuffer args => Reducers.InvokeFlushSphereBuffer(eventContext, args),
                 Reducer.GameLoop args => Reducers.InvokeGameLoop(eventContext, args),
+                Reducer.GenerateShell args => Reducers.InvokeGenerateShell(eventContext, args),
+                Reducer.GetInventorySnapshot args => Reducers.InvokeGetInventorySnapshot(eventContext, args),
+                Reducer.GetTransferHistory args => Reducers.InvokeGetTransferHistory(eventContext, args),
+                Reducer.GiftPackets args => Reducers.InvokeGiftPackets(eventContext, args),
+                Reducer.GrantAdmin args => Reducers.InvokeGrantAdmin(eventContext, args),
+                Reducer.ImportWorldSnapshot args => Reducers.InvokeImportWorldSnapshot(eventContext, args),
                 Reducer.InitializePlayerInventory args => Reducers.InvokeInitializePlayerInventory(eventContext, args),
                 Reducer.InitiateTransfer args => Reducers.InvokeInitiateTransfer(eventContext, args),
+                Reducer.InspectOrb args => Reducers.InvokeInspectOrb(eventContext, args),
+                Reducer.InspectOrbMiners args => Reducers.InvokeInspectOrbMiners(eventContext, args),
                 Reducer.ListActiveMining args => Reducers.InvokeListActiveMining(eventContext, args),
+                Reducer.ListMyStorageDevices args => Reducers.InvokeListMyStorageDevices(eventContext, args),
                 Reducer.Login args => Reducers.InvokeLogin(eventContext, args),
                 Reducer.LoginWithSession args => Reducers.InvokeLoginWithSession(eventContext, args),
                 Reducer.Logout args => Reducers.InvokeLogout(eventContext, args),
+                Reducer.MineToStorage args => Reducers.InvokeMineToStorage(eventContext, args),
+                Reducer.MoveStorageDevice args => Reducers.InvokeMoveStorageDevice(eventContext, args),
+                Reducer.ReclaimStuckTransfers args => Reducers.InvokeReclaimStuckTransfers(eventContext, args),
+                Reducer.RefreshMiningLeaderboard args => Reducers.InvokeRefreshMiningLeaderboard(eventContext, args),
+                Reducer.RefreshNearbyPlayers args => Reducers.InvokeRefreshNearbyPlayers(eventContext, args),
+                Reducer.RefreshPacketEconomy args => Reducers.InvokeRefreshPacketEconomy(eventContext, args),
+                Reducer.RefreshStorageFullness args => Reducers.InvokeRefreshStorageFullness(eventContext, args),
+                Reducer.RefreshWorldSummary args => Reducers.InvokeRefreshWorldSummary(eventContext, args),
                 Reducer.RegisterAccount args => Reducers.InvokeRegisterAccount(eventContext, args),
+                Reducer.RenameStorageDevice args => Reducers.InvokeRenameStorageDevice(eventContext, args),
+                Reducer.ResetCrystal args => Reducers.InvokeResetCrystal(eventContext, args),
                 Reducer.RestoreSession args => Reducers.InvokeRestoreSession(eventContext, args),
+                Reducer.ResurfaceOrb args => Reducers.InvokeResurfaceOrb(eventContext, args),
                 Reducer.SendPlayerChat args => Reducers.InvokeSendPlayerChat(eventContext, args),
+                Reducer.SetAutoCapture args => Reducers.InvokeSetAutoCapture(eventContext, args),
+                Reducer.SetCircuitEmissionComposition args => Reducers.InvokeSetCircuitEmissionComposition(eventContext, args),
+                Reducer.SetCircuitEnabled args => Reducers.InvokeSetCircuitEnabled(eventContext, args),
+                Reducer.SetFrequencyCapacity args => Reducers.InvokeSetFrequencyCapacity(eventContext, args),
+                Reducer.SetLogLevel args => Reducers.InvokeSetLogLevel(eventContext, args),
+                Reducer.SetOrbComposition args => Reducers.InvokeSetOrbComposition(eventContext, args),
+                Reducer.SetOrbLifetime args => Reducers.InvokeSetOrbLifetime(eventContext, args),
                 Reducer.SetOrbPackets args => Reducers.InvokeSetOrbPackets(eventContext, args),
+                Reducer.SetStorageCapacity args => Reducers.InvokeSetStorageCapacity(eventContext, args),
+                Reducer.SetWorldAllowedFrequencies args => Reducers.InvokeSetWorldAllowedFrequencies(eventContext, args),
+                Reducer.SetWorldConstant args => Reducers.InvokeSetWorldConstant(eventContext, args),
+                Reducer.SetWorldRadii args => Reducers.InvokeSetWorldRadii(eventContext, args),
                 Reducer.Spawn6CardinalCircuits args => Reducers.InvokeSpawn6CardinalCircuits(eventContext, args),
                 Reducer.SpawnAll26Spires args => Reducers.InvokeSpawnAll26Spires(eventContext, args),
                 Reducer.SpawnCircuitAtSpire args => Reducers.InvokeSpawnCircuitAtSpire(eventContext, args),
@@ -602,15 +746,22 @@ namespace SpacetimeDB.Types
                 Reducer.SpawnFullSpectrumOrb args => Reducers.InvokeSpawnFullSpectrumOrb(eventContext, args),
                 Reducer.SpawnMainSpires args => Reducers.InvokeSpawnMainSpires(eventContext, args),
                 Reducer.SpawnMixedOrb args => Reducers.InvokeSpawnMixedOrb(eventContext, args),
+                Reducer.SpawnOrbLine args => Reducers.InvokeSpawnOrbLine(eventContext, args),
                 Reducer.SpawnTestOrb args => Reducers.InvokeSpawnTestOrb(eventContext, args),
                 Reducer.StartGameLoop args => Reducers.InvokeStartGameLoop(eventContext, args),
                 Reducer.StartMiningV2 args => Reducers.InvokeStartMiningV2(eventContext, args),
+                Reducer.StartWorldGameLoop args => Reducers.InvokeStartWorldGameLoop(eventContext, args),
                 Reducer.StopGameLoop args => Reducers.InvokeStopGameLoop(eventContext, args),
                 Reducer.StopMiningV2 args => Reducers.InvokeStopMiningV2(eventContext, args),
+                Reducer.StopWorldGameLoop args => Reducers.InvokeStopWorldGameLoop(eventContext, args),
                 Reducer.Tick args => Reducers.InvokeTick(eventContext, args),
                 Reducer.TickPlayerTransfers args => Reducers.InvokeTickPlayerTransfers(eventContext, args),
                 Reducer.TravelToWorld args => Reducers.InvokeTravelToWorld(eventContext, args),
+                Reducer.TuneFrequency args => Reducers.InvokeTuneFrequency(eventContext, args),
+                Reducer.UnlockCrystalSlot args => Reducers.InvokeUnlockCrystalSlot(eventContext, args),
                 Reducer.UpdatePlayerPosition args => Reducers.InvokeUpdatePlayerPosition(eventContext, args),
+                Reducer.ValidateTransfer args => Reducers.InvokeValidateTransfer(eventContext, args),
+                Reducer.WorldGameLoop args => Reducers.InvokeWorldGameLoop(eventContext, args),
                 Reducer.WorldSpherePulse args => Reducers.InvokeWorldSpherePulse(eventContext, args),
                 _ => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
@@ -29,6 +29,8 @@ namespace SpacetimeDB.Types
         public SpacetimeDB.Timestamp LastPacketTime;
         [DataMember(Name = "transit_buffer")]
         public System.Collections.Generic.List<WavePacketSample> TransitBuffer;
+        [DataMember(Name = "buffer_capacity")]
+        public uint BufferCapacity;
 
         public DistributionSphere(
             ulong SphereId,
@@ -38,7 +40,8 @@ namespace SpacetimeDB.Types
             byte SphereRadius,
             ulong PacketsRouted,
             SpacetimeDB.Timestamp LastPacketTime,
-            System.Collections.Generic.List<WavePacketSample> TransitBuffer
+            System.Collections.Generic.List<WavePacketSample> TransitBuffer,
+            uint BufferCapacity
         )
         {
             this.SphereId = SphereId;
@@ -49,6 +52,7 @@ namespace SpacetimeDB.Types
             this.PacketsRouted = PacketsRouted;
             this.LastPacketTime = LastPacketTime;
             this.TransitBuffer = TransitBuffer;
+            this.BufferCapacity = BufferCapacity;
         }
 
         public DistributionSphere()
@@ -49,6 +49,10 @@ namespace SpacetimeDB.Types
         public string CurrentLegType;
         [DataMember(Name = "predicted_arrival_time")]
         public SpacetimeDB.Timestamp PredictedArrivalTime;
+        [DataMember(Name = "priority")]
+        public byte Priority;
+        [DataMember(Name = "world_coords")]
+        public WorldCoords WorldCoords;
 
         public PacketTransfer(
             ulong TransferId,
@@ -68,7 +72,9 @@ namespace SpacetimeDB.Types
             string DestinationObjectType,
             ulong DestinationObjectId,
             string CurrentLegType,
-            SpacetimeDB.Timestamp PredictedArrivalTime
+            SpacetimeDB.Timestamp PredictedArrivalTime,
+            byte Priority,
+            WorldCoords WorldCoords
         )
         {
             this.TransferId = TransferId;
@@ -89,6 +95,8 @@ namespace SpacetimeDB.Types
             this.DestinationObjectId = DestinationObjectId;
             this.CurrentLegType = CurrentLegType;
             this.PredictedArrivalTime = PredictedArrivalTime;
+            this.Priority = Priority;
+            this.WorldCoords = WorldCoords;
         }
 
         public PacketTransfer()
@@ -100,6 +108,7 @@ namespace SpacetimeDB.Types
             this.SourceObjectType = "";
             this.DestinationObjectType = "";
             this.CurrentLegType = "";
+            this.WorldCoords = new();
         }
     }
 }
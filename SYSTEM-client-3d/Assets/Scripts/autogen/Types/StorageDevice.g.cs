@@ -29,6 +29,8 @@ namespace SpacetimeDB.Types
         public System.Collections.Generic.List<WavePacketSample> StoredComposition;
         [DataMember(Name = "created_at")]
         public SpacetimeDB.Timestamp CreatedAt;
+        [DataMember(Name = "per_frequency_capacity")]
+        public System.Collections.Generic.List<FrequencyCapacity> PerFrequencyCapacity;
 
         public StorageDevice(
             ulong DeviceId,
@@ -38,7 +40,8 @@ namespace SpacetimeDB.Types
             string DeviceName,
             uint CapacityPerFrequency,
             System.Collections.Generic.List<WavePacketSample> StoredComposition,
-            SpacetimeDB.Timestamp CreatedAt
+            SpacetimeDB.Timestamp CreatedAt,
+            System.Collections.Generic.List<FrequencyCapacity> PerFrequencyCapacity
         )
         {
             this.DeviceId = DeviceId;
@@ -49,6 +52,7 @@ namespace SpacetimeDB.Types
             this.CapacityPerFrequency = CapacityPerFrequency;
             this.StoredComposition = StoredComposition;
             this.CreatedAt = CreatedAt;
+            this.PerFrequencyCapacity = PerFrequencyCapacity;
         }
 
         public StorageDevice()
@@ -57,6 +61,7 @@ namespace SpacetimeDB.Types
             this.Position = new();
             this.DeviceName = "";
             this.StoredComposition = new();
+            this.PerFrequencyCapacity = new();
         }
     }
 }
@@ -41,6 +41,10 @@ namespace SpacetimeDB.Types
         public uint ActiveMinerCount;
         [DataMember(Name = "last_depletion")]
         public ulong LastDepletion;
+        [DataMember(Name = "expiring_soon")]
+        public bool ExpiringSoon;
+        [DataMember(Name = "dissipates")]
+        public bool Dissipates;
 
         public WavePacketSource(
             ulong SourceId,
@@ -56,7 +60,9 @@ namespace SpacetimeDB.Types
             uint LifetimeMs,
             ulong LastDissipation,
             uint ActiveMinerCount,
-            ulong LastDepletion
+            ulong LastDepletion,
+            bool ExpiringSoon,
+            bool Dissipates
         )
         {
             this.SourceId = SourceId;
@@ -73,6 +79,8 @@ namespace SpacetimeDB.Types
             this.LastDissipation = LastDissipation;
             this.ActiveMinerCount = ActiveMinerCount;
             this.LastDepletion = LastDepletion;
+            this.ExpiringSoon = ExpiringSoon;
+            this.Dissipates = Dissipates;
         }
 
         public WavePacketSource()
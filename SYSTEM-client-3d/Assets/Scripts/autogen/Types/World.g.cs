@@ -23,13 +23,25 @@ namespace SpacetimeDB.Types
         public string WorldType;
         [DataMember(Name = "shell_level")]
         public byte ShellLevel;
+        [DataMember(Name = "allowed_frequencies")]
+        public System.Collections.Generic.List<float> AllowedFrequencies;
+        [DataMember(Name = "radius_x")]
+        public float? RadiusX;
+        [DataMember(Name = "radius_y")]
+        public float? RadiusY;
+        [DataMember(Name = "radius_z")]
+        public float? RadiusZ;
 
         public World(
             ulong WorldId,
             WorldCoords WorldCoords,
             string WorldName,
             string WorldType,
-            byte ShellLevel
+            byte ShellLevel,
+            System.Collections.Generic.List<float> AllowedFrequencies,
+            float? RadiusX,
+            float? RadiusY,
+            float? RadiusZ
         )
         {
             this.WorldId = WorldId;
@@ -37,6 +49,10 @@ namespace SpacetimeDB.Types
             this.WorldName = WorldName;
             this.WorldType = WorldType;
             this.ShellLevel = ShellLevel;
+            this.AllowedFrequencies = AllowedFrequencies;
+            this.RadiusX = RadiusX;
+            this.RadiusY = RadiusY;
+            this.RadiusZ = RadiusZ;
         }
 
         public World()
@@ -44,6 +60,7 @@ namespace SpacetimeDB.Types
             this.WorldCoords = new();
             this.WorldName = "";
             this.WorldType = "";
+            this.AllowedFrequencies = new();
         }
     }
 }
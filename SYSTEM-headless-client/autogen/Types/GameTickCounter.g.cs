@@ -17,14 +17,22 @@ namespace SpacetimeDB.Types
         public uint Id;
         [DataMember(Name = "tick_count")]
         public ulong TickCount;
+        [DataMember(Name = "idle_ticks")]
+        public uint IdleTicks;
+        [DataMember(Name = "idle_mode")]
+        public bool IdleMode;
 
         public GameTickCounter(
             uint Id,
-            ulong TickCount
+            ulong TickCount,
+            uint IdleTicks,
+            bool IdleMode
         )
         {
             this.Id = Id;
             this.TickCount = TickCount;
+            this.IdleTicks = IdleTicks;
+            this.IdleMode = IdleMode;
         }
 
         public GameTickCounter()
@@ -21,22 +21,27 @@ namespace SpacetimeDB.Types
         public byte SlotCount;
         [DataMember(Name = "chosen_at")]
         public ulong ChosenAt;
+        [DataMember(Name = "extra_types")]
+        public System.Collections.Generic.List<CrystalType> ExtraTypes;
 
         public PlayerCrystal(
             ulong PlayerId,
             CrystalType CrystalType,
             byte SlotCount,
-            ulong ChosenAt
+            ulong ChosenAt,
+            System.Collections.Generic.List<CrystalType> ExtraTypes
         )
         {
             this.PlayerId = PlayerId;
             this.CrystalType = CrystalType;
             this.SlotCount = SlotCount;
             this.ChosenAt = ChosenAt;
+            this.ExtraTypes = ExtraTypes;
         }
 
         public PlayerCrystal()
         {
+            this.ExtraTypes = new();
         }
     }
 }
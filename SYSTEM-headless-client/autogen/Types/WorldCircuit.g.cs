@@ -29,6 +29,12 @@ namespace SpacetimeDB.Types
         public ulong EmissionIntervalMs;
         [DataMember(Name = "last_emission_time")]
         public ulong LastEmissionTime;
+        [DataMember(Name = "emission_composition")]
+        public System.Collections.Generic.List<WavePacketSample> EmissionComposition;
+        [DataMember(Name = "enabled")]
+        public bool Enabled;
+        [DataMember(Name = "emission_phase_ms")]
+        public ulong EmissionPhaseMs;
 
         public WorldCircuit(
             ulong CircuitId,
@@ -38,7 +44,10 @@ namespace SpacetimeDB.Types
             byte QubitCount,
             uint SourcesPerEmission,
             ulong EmissionIntervalMs,
-            ulong LastEmissionTime
+            ulong LastEmissionTime,
+            System.Collections.Generic.List<WavePacketSample> EmissionComposition,
+            bool Enabled,
+            ulong EmissionPhaseMs
         )
         {
             this.CircuitId = CircuitId;
@@ -49,6 +58,9 @@ namespace SpacetimeDB.Types
             this.SourcesPerEmission = SourcesPerEmission;
             this.EmissionIntervalMs = EmissionIntervalMs;
             this.LastEmissionTime = LastEmissionTime;
+            this.EmissionComposition = EmissionComposition;
+            this.Enabled = Enabled;
+            this.EmissionPhaseMs = EmissionPhaseMs;
         }
 
         public WorldCircuit()
@@ -56,6 +68,7 @@ namespace SpacetimeDB.Types
             this.WorldCoords = new();
             this.CardinalDirection = "";
             this.CircuitType = "";
+            this.EmissionComposition = new();
         }
     }
 }
@@ -0,0 +1,168 @@
+// ============================================================================
+// Wave Packet Composition Helpers
+// ============================================================================
+//
+// Sum, diff and total Vec<WavePacketSample> compositions by frequency.
+// Frequencies are matched with same_frequency and stored canonicalized,
+// and results are kept sorted by frequency so clients get a stable order.
+
+use crate::{canonical_frequency, same_frequency, WavePacketSample};
+
+/// Merge samples into a composition, combining counts of matching frequencies
+pub fn merge_compositions(into: &mut Vec<WavePacketSample>, from: &[WavePacketSample]) {
+    for sample in from {
+        let canonical = canonical_frequency(sample.frequency);
+        match into.iter_mut().find(|existing| same_frequency(existing.frequency, canonical)) {
+            Some(existing) => existing.count += sample.count,
+            None => {
+                let mut new_sample = sample.clone();
+                new_sample.frequency = canonical;
+                into.push(new_sample);
+            }
+        }
+    }
+    sort_composition(into);
+}
+
+/// Remove amounts from a composition, dropping samples that reach zero
+/// Fails without modifying the composition if any frequency is short
+pub fn subtract_composition(from: &mut Vec<WavePacketSample>, amounts: &[WavePacketSample]) -> Result<(), String> {
+    let mut result = from.clone();
+
+    for requested in amounts {
+        if requested.count == 0 {
+            continue;
+        }
+        let existing = result.iter_mut()
+            .find(|s| same_frequency(s.frequency, requested.frequency))
+            .ok_or(format!("Frequency {} not found", requested.frequency))?;
+
+        if existing.count < requested.count {
+            return Err(format!("Insufficient packets for frequency {}: have {}, need {}",
+                requested.frequency, existing.count, requested.count));
+        }
+        existing.count -= requested.count;
+    }
+
+    result.retain(|s| s.count > 0);
+    sort_composition(&mut result);
+    *from = result;
+    Ok(())
+}
+
+/// Remove up to the given amounts from a composition, dropping samples that reach zero
+/// Unlike subtract_composition, missing or short frequencies just remove what is there
+pub fn remove_up_to(from: &mut Vec<WavePacketSample>, amounts: &[WavePacketSample]) {
+    for requested in amounts {
+        if let Some(existing) = from.iter_mut().find(|s| same_frequency(s.frequency, requested.frequency)) {
+            existing.count = existing.count.saturating_sub(requested.count);
+        }
+    }
+    from.retain(|s| s.count > 0);
+}
+
+/// Cut a composition down to at most max_count packets, highest frequencies first
+/// Returns the number of packets removed
+pub fn truncate_composition(composition: &mut Vec<WavePacketSample>, max_count: u32) -> u32 {
+    let total = total_count(composition);
+    if total <= max_count {
+        return 0;
+    }
+
+    sort_composition(composition);
+    let mut remaining = total - max_count;
+    for sample in composition.iter_mut().rev() {
+        if remaining == 0 {
+            break;
+        }
+        let cut = sample.count.min(remaining);
+        sample.count -= cut;
+        remaining -= cut;
+    }
+    composition.retain(|s| s.count > 0);
+
+    total - max_count
+}
+
+/// Order samples by frequency ascending (red first, magenta last)
+pub fn sort_composition(composition: &mut [WavePacketSample]) {
+    composition.sort_by(|a, b| a.frequency.total_cmp(&b.frequency));
+}
+
+/// Consolidated, canonicalized and sorted copy of a composition
+pub fn normalized(composition: &[WavePacketSample]) -> Vec<WavePacketSample> {
+    let mut result = Vec::new();
+    merge_compositions(&mut result, composition);
+    result
+}
+
+/// Sum of all sample counts in a composition
+pub fn total_count(composition: &[WavePacketSample]) -> u32 {
+    composition.iter().map(|s| s.count).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FREQ_BLUE, FREQ_GREEN, FREQ_RED};
+
+    fn sample(frequency: f32, count: u32) -> WavePacketSample {
+        WavePacketSample { frequency, amplitude: 1.0, phase: 0.0, count }
+    }
+
+    #[test]
+    fn merge_sums_matching_frequencies_and_sorts() {
+        let mut into = vec![sample(FREQ_BLUE, 3), sample(FREQ_RED, 2)];
+        merge_compositions(&mut into, &[sample(FREQ_RED, 5), sample(FREQ_GREEN, 1)]);
+
+        assert_eq!(into.len(), 3);
+        assert_eq!(into[0].frequency, FREQ_RED);
+        assert_eq!(into[0].count, 7);
+        assert_eq!(into[1].frequency, FREQ_GREEN);
+        assert_eq!(into[2].frequency, FREQ_BLUE);
+        assert_eq!(into[2].count, 3);
+    }
+
+    #[test]
+    fn merge_consolidates_duplicates_in_the_input() {
+        let mut into = Vec::new();
+        merge_compositions(&mut into, &[sample(FREQ_GREEN, 4), sample(FREQ_GREEN, 6)]);
+
+        assert_eq!(into.len(), 1);
+        assert_eq!(into[0].count, 10);
+    }
+
+    #[test]
+    fn merge_snaps_non_canonical_frequencies() {
+        // 0.5 is nearer Red than Yellow but outside the same_frequency tolerance of both
+        let mut into = Vec::new();
+        merge_compositions(&mut into, &[sample(0.5, 2)]);
+        merge_compositions(&mut into, &[sample(0.5, 3)]);
+
+        assert_eq!(into.len(), 1);
+        assert_eq!(into[0].frequency, FREQ_RED);
+        assert_eq!(into[0].count, 5);
+    }
+
+    #[test]
+    fn subtract_is_all_or_nothing() {
+        let mut from = vec![sample(FREQ_RED, 5), sample(FREQ_GREEN, 2)];
+        assert!(subtract_composition(&mut from, &[sample(FREQ_RED, 1), sample(FREQ_GREEN, 3)]).is_err());
+        assert_eq!(total_count(&from), 7);
+
+        subtract_composition(&mut from, &[sample(FREQ_GREEN, 2)]).unwrap();
+        assert_eq!(from.len(), 1);
+        assert_eq!(from[0].count, 5);
+    }
+
+    #[test]
+    fn truncate_cuts_highest_frequencies_first() {
+        let mut composition = vec![sample(FREQ_BLUE, 4), sample(FREQ_RED, 4)];
+        assert_eq!(truncate_composition(&mut composition, 6), 2);
+
+        assert_eq!(composition.len(), 2);
+        assert_eq!(composition[0].count, 4);
+        assert_eq!(composition[1].frequency, FREQ_BLUE);
+        assert_eq!(composition[1].count, 2);
+    }
+}
@@ -3,18 +3,39 @@ use spacetimedb::{
 };
 use std::time::Duration;
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::sync::OnceLock;
 use std::f32::consts::PI;
 
+mod composition;
+
 // ============================================================================
 // World Constants
 // ============================================================================
 
-/// Radius of the game world sphere in units
+/// Default radius of the game world sphere in units
+/// Tunable via world_constants - read it through world_radii or get_world_constants, not directly
 const WORLD_RADIUS: f32 = 300.0;
 
 /// Height offset above the sphere surface for player spawning
 const SURFACE_OFFSET: f32 = 1.0;
+/// Distance between neighbouring world centers (world (x,y,z) is centered at (x,y,z) * WORLD_SPACING)
+const WORLD_SPACING: f32 = 10000.0;
+/// Furthest a client position may be from its world's center, in world radii
+const MAX_POSITION_RADII: f32 = 3.0;
+/// Default max player movement speed (units per second), tunable via world_constants
+const MAX_PLAYER_SPEED: f32 = 12.0;
+/// Default packets of the current crystal's color charged by reset_crystal, tunable via world_constants
+const CRYSTAL_RESET_COST: u32 = 100;
+/// Default packets of the primary crystal's color charged by unlock_crystal_slot, tunable via world_constants
+const CRYSTAL_SLOT_UNLOCK_COST: u32 = 250;
+/// Most crystal slots a player can unlock (one per crystal type)
+const MAX_CRYSTAL_SLOTS: u8 = 3;
+/// Default duration of the client-side extraction flight animation, tunable via world_constants
+const VISUAL_FLIGHT_TIME_MS: u64 = 3000;
+/// Multiplier on allowed movement to absorb client/network jitter
+const MOVEMENT_GRACE_FACTOR: f32 = 2.0;
+/// Flat distance always allowed between updates (covers near-simultaneous updates)
+const MOVEMENT_SLACK: f32 = 5.0;
 /// Packet travel speed for transfer timing (units per second)
 const PACKET_SPEED: f32 = 5.0;
 
@@ -25,6 +46,55 @@ const MINING_PACKET_HEIGHT: f32 = 1.0;
 const OBJECT_PACKET_HEIGHT: f32 = 1.0;
 /// Height for packets traveling between spheres
 const SPHERE_PACKET_HEIGHT: f32 = 10.0;
+/// Max total packets a player inventory can hold
+const MAX_INVENTORY_PACKETS: u32 = 300;
+/// Capacity per frequency of a newly created storage device
+const DEFAULT_STORAGE_CAPACITY_PER_FREQUENCY: u32 = 1000;
+/// Upper bound on a storage device's capacity summed over all six frequencies
+const MAX_STORAGE_CAPACITY: u32 = 6000;
+/// Percentage of packets lost when tuning between adjacent frequencies
+const TUNE_LOSS_PERCENT: u32 = 20;
+/// Max distance a storage device may sit from the world surface raised by OBJECT_PACKET_HEIGHT
+const DEVICE_PLACEMENT_TOLERANCE: f32 = 5.0;
+/// Minimum distance between any two storage devices on the same world
+const MIN_DEVICE_SEPARATION: f32 = 5.0;
+/// Minimum time between a player's storage device creations
+const DEVICE_CREATE_COOLDOWN_MS: u64 = 30_000;
+/// Default max packets a distribution sphere holds in its transit buffer
+const SPHERE_BUFFER_CAPACITY: u32 = 1000;
+/// Expired session cleanup interval (1 hour)
+const SESSION_CLEANUP_INTERVAL_MS: u64 = 60 * 60 * 1000;
+/// Stale extraction cleanup interval (10 seconds)
+const EXTRACTION_CLEANUP_INTERVAL_MS: u64 = 10_000;
+/// Game loop interval while anything is active (10Hz)
+const GAME_LOOP_ACTIVE_INTERVAL_MS: u64 = 100;
+/// Game loop interval once the world has gone idle (1Hz)
+const GAME_LOOP_IDLE_INTERVAL_MS: u64 = 1000;
+/// Consecutive idle ticks (no players or transfers in flight) before slowing the game loop
+/// Orbs don't count as activity - see game_is_idle
+const GAME_LOOP_IDLE_TICKS: u32 = 50;
+/// Abandoned logged-out player cleanup interval (1 day)
+const LOGGED_OUT_CLEANUP_INTERVAL_MS: u64 = 24 * 60 * 60 * 1000;
+/// Logged-out players idle this long (and whose account hasn't logged in) are purged by maintenance
+const LOGGED_OUT_RETENTION_DAYS: u32 = 180;
+/// Lowest retention cleanup_stale_logged_out_players will accept
+const MIN_LOGGED_OUT_RETENTION_DAYS: u32 = 30;
+/// Inactive mining session cleanup interval (1 hour)
+const MINING_SESSION_CLEANUP_INTERVAL_MS: u64 = 60 * 60 * 1000;
+/// Inactive mining sessions untouched this long are deleted by maintenance (1 day)
+const INACTIVE_MINING_SESSION_RETENTION_MS: u64 = 24 * 60 * 60 * 1000;
+/// How long transfer events stay in transfer_event before cleanup
+const TRANSFER_EVENT_TTL_MS: u64 = 5_000;
+/// How long orb depletion events stay in orb_depleted_event before cleanup
+const ORB_DEPLETED_EVENT_TTL_MS: u64 = 5_000;
+/// How long circuit emission events stay in orb_spawn_event before cleanup
+const ORB_SPAWN_EVENT_TTL_MS: u64 = 5_000;
+/// Completed transfers kept per player in transfer_log - oldest are pruned
+const TRANSFER_LOG_MAX_PER_PLAYER: usize = 100;
+/// Largest leaderboard refresh_mining_leaderboard will build
+const MAX_LEADERBOARD_SIZE: u32 = 100;
+/// Throughput samples kept per sphere (one per 10s pulse, ~5 minutes)
+const THROUGHPUT_SAMPLE_WINDOW: usize = 30;
 
 // ============================================================================
 // Wave Packet Source Movement Constants
@@ -44,8 +114,24 @@ const SOURCE_HEIGHT_1: f32 = 1.0;
 const SOURCE_RISE_SPEED: f32 = 2.0;
 /// Radius to check for existing sources near circuit
 const CIRCUIT_CHECK_RADIUS: f32 = 30.0;
+/// Hard cap on sources per world - protects the per-tick movement budget
+const MAX_SOURCES_PER_WORLD: u32 = 200;
+/// Max sources all circuits in all worlds may spawn in one game loop pass; due circuits past it wait a pulse
+const CIRCUIT_SPAWN_BUDGET_PER_PULSE: u32 = 50;
 /// Direction variance ±π/16 radians (~11.25°)
 const DIRECTION_VARIANCE: f32 = 0.196;
+/// Orb dissipation check interval (every 10 seconds)
+const DISSIPATION_INTERVAL_MS: u64 = 10000;
+/// Packets lost per dissipation
+const DISSIPATION_RATE: u32 = 1;
+/// Chance an orb dissipates on each check
+const DISSIPATION_PROBABILITY: f32 = 0.5;
+
+/// Flag sources as expiring_soon this long before their lifetime ends
+const SOURCE_EXPIRY_WARNING_MS: u64 = 30_000;
+
+/// Radius error (units) beyond which a source position is re-projected onto its shell
+const SOURCE_RADIUS_TOLERANCE: f32 = 0.01;
 
 // Source state constants
 const SOURCE_STATE_MOVING_H: u8 = 0;    // Traveling horizontally on surface
@@ -75,6 +161,52 @@ const FREQ_BLUE: f32 = 4.189;
 /// Magenta frequency (300° = 5π/3 rad)
 const FREQ_MAGENTA: f32 = 5.236;
 
+/// All six frequencies in color-wheel order
+const ALL_FREQUENCIES: [f32; 6] = [FREQ_RED, FREQ_YELLOW, FREQ_GREEN, FREQ_CYAN, FREQ_BLUE, FREQ_MAGENTA];
+
+/// Max angular distance (radians) for two frequencies to count as the same.
+/// Adjacent colors are ~1.047 apart, so 0.01 only absorbs float noise.
+const FREQUENCY_TOLERANCE: f32 = 0.01;
+
+/// Max phase difference (radians) between crystal and orb sample for the phase-match bonus
+const PHASE_MATCH_WINDOW: f32 = 0.2;
+
+/// Extraction yield multiplier when crystal and orb phases are within PHASE_MATCH_WINDOW
+const PHASE_MATCH_BONUS: f32 = 1.25;
+
+/// Angular distance between two frequencies on the unit circle (wraps at 2π)
+fn frequency_distance(a: f32, b: f32) -> f32 {
+    let diff = (a - b).rem_euclid(2.0 * PI);
+    diff.min(2.0 * PI - diff)
+}
+
+/// Canonical frequency comparison - use this everywhere samples are matched
+fn same_frequency(a: f32, b: f32) -> bool {
+    frequency_distance(a, b) < FREQUENCY_TOLERANCE
+}
+
+/// Snap a frequency to the nearest of the six color constants
+fn canonical_frequency(frequency: f32) -> f32 {
+    ALL_FREQUENCIES
+        .iter()
+        .copied()
+        .min_by(|a, b| frequency_distance(frequency, *a).total_cmp(&frequency_distance(frequency, *b)))
+        .unwrap_or(FREQ_RED)
+}
+
+/// Canonical frequency for a color name ("Red".."Magenta", case-insensitive)
+fn color_frequency(color: &str) -> Option<f32> {
+    match color.trim().to_ascii_lowercase().as_str() {
+        "red" => Some(FREQ_RED),
+        "yellow" => Some(FREQ_YELLOW),
+        "green" => Some(FREQ_GREEN),
+        "cyan" => Some(FREQ_CYAN),
+        "blue" => Some(FREQ_BLUE),
+        "magenta" => Some(FREQ_MAGENTA),
+        _ => None,
+    }
+}
+
 // ============================================================================
 // Distribution Sphere Routing (Floyd-Warshall Precomputed)
 // ============================================================================
@@ -392,6 +524,45 @@ impl DbVector3 {
             z: self.x * other.y - self.y * other.x,
         }
     }
+
+    /// Project onto a sphere of the given radius centered at the origin
+    pub fn surface_project(&self, radius: f32) -> Self {
+        self.normalize().scale(radius)
+    }
+
+    /// Project radially onto an axis-aligned ellipsoid (semi-axes in `radii`), then
+    /// raise `height` further along the same ray. Equals surface_project(r + height)
+    /// when all radii are r
+    pub fn ellipsoid_project(&self, radii: &DbVector3, height: f32) -> Self {
+        let dir = self.normalize();
+        let scaled = DbVector3::new(dir.x / radii.x, dir.y / radii.y, dir.z / radii.z);
+        let surface_distance = 1.0 / scaled.magnitude();
+        dir.scale(surface_distance + height)
+    }
+
+    /// Great-circle distance to another point, measured on a sphere of the given radius
+    pub fn arc_distance_to(&self, other: &DbVector3, radius: f32) -> f32 {
+        let angle = self.normalize().dot(&other.normalize()).clamp(-1.0, 1.0).acos();
+        angle * radius
+    }
+
+    /// Two unit vectors spanning the tangent plane at this point (treated as a surface normal)
+    /// Together with the normal they form an orthonormal basis
+    pub fn tangent_basis(&self) -> (DbVector3, DbVector3) {
+        let normal = self.normalize();
+
+        // Choose an arbitrary vector not parallel to normal
+        let arbitrary = if normal.y.abs() < 0.9 {
+            DbVector3::new(0.0, 1.0, 0.0)  // Y-up
+        } else {
+            DbVector3::new(1.0, 0.0, 0.0)  // X-right
+        };
+
+        // tangent1 = normal × arbitrary, tangent2 = normal × tangent1
+        let tangent1 = normal.cross(&arbitrary).normalize();
+        let tangent2 = normal.cross(&tangent1);
+        (tangent1, tangent2)
+    }
 }
 
 #[derive(SpacetimeType, Debug, Clone, Copy)]
@@ -408,6 +579,37 @@ impl Default for DbQuaternion {
     }
 }
 
+/// Structured reducer error - lets clients branch on error kind
+/// Reducers still return Result<(), String>; `?` converts via From<GameError>
+#[derive(SpacetimeType, Debug, Clone, PartialEq)]
+pub enum GameError {
+    NotFound(String),          // Name of the missing thing, e.g. "Orb"
+    Unauthorized(String),
+    CapacityExceeded(String),
+    Cooldown(u64),             // Remaining milliseconds
+    InvalidInput(String),
+    InvalidState(String),
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::NotFound(what) => write!(f, "{} not found", what),
+            GameError::Cooldown(remaining_ms) => write!(f, "On cooldown ({} ms remaining)", remaining_ms),
+            GameError::Unauthorized(msg)
+            | GameError::CapacityExceeded(msg)
+            | GameError::InvalidInput(msg)
+            | GameError::InvalidState(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<GameError> for String {
+    fn from(error: GameError) -> Self {
+        error.to_string()
+    }
+}
+
 // ============================================================================
 // Authentication Tables
 // ============================================================================
@@ -426,6 +628,15 @@ pub struct Account {
     pub last_login: u64,
 }
 
+/// Identities allowed to call debug, spawn, and clear reducers
+#[spacetimedb::table(name = admin_identity)]
+#[derive(Debug, Clone)]
+pub struct AdminIdentity {
+    #[primary_key]
+    pub identity: Identity,
+    pub granted_at: Timestamp,
+}
+
 #[spacetimedb::table(name = player_session)]
 #[derive(Debug, Clone)]
 pub struct PlayerSession {
@@ -474,6 +685,47 @@ pub struct Player {
     pub last_update: u64,
 }
 
+/// Lifetime totals per player, readable by clients
+#[spacetimedb::table(name = player_stats, public)]
+#[derive(Debug, Clone)]
+pub struct PlayerStats {
+    #[primary_key]
+    pub player_id: u64,
+    pub total_mined: u64,            // Packets extracted from orbs
+    pub total_transferred: u64,      // Packets delivered to storage
+    pub total_discovered: u64,       // Reserved - no discovery source yet
+    pub worlds_visited: u32,
+    pub visited_worlds: Vec<WorldCoords>,  // Backs worlds_visited (first-visit detection)
+    pub created_at: Timestamp,
+    pub last_device_created_at: u64,       // ms since epoch, 0 = never (create_storage_device cooldown)
+}
+
+/// Players near the requester in the same world, written by refresh_nearby_players
+/// One row per requester - replaced on each refresh
+#[spacetimedb::table(name = nearby_players, public)]
+#[derive(Debug, Clone)]
+pub struct NearbyPlayers {
+    #[primary_key]
+    pub requester: Identity,
+    pub world_coords: WorldCoords,
+    pub radius: f32,
+    pub player_ids: Vec<u64>,     // Excludes the requester
+    pub refreshed_at: Timestamp,
+}
+
+/// Result of find_player_by_name - one row per requester, replaced on each lookup
+#[spacetimedb::table(name = player_lookup_result, public)]
+#[derive(Debug, Clone)]
+pub struct PlayerLookupResult {
+    #[primary_key]
+    pub requester: Identity,
+    pub query: String,
+    pub player_id: Option<u64>,   // None when no player matched
+    pub display_name: String,
+    pub is_online: bool,
+    pub looked_up_at: Timestamp,
+}
+
 #[spacetimedb::table(name = logged_out_player)]
 #[derive(Debug, Clone)]
 pub struct LoggedOutPlayer {
@@ -489,6 +741,16 @@ pub struct LoggedOutPlayer {
     pub last_rotation: DbQuaternion,
 }
 
+/// Per-player gameplay preferences, created on first change
+#[spacetimedb::table(name = player_settings, public)]
+#[derive(Debug, Clone)]
+pub struct PlayerSettings {
+    #[primary_key]
+    pub player_id: u64,
+    pub auto_capture: bool,  // Server credits extractions at expected_arrival instead of waiting for capture_extracted_packet_v2
+    pub updated_at: Timestamp,
+}
+
 /// Player's energy packet inventory
 /// Stores wave packet frequencies collected from mining
 /// Player inventory using unified wave packet composition
@@ -506,6 +768,53 @@ pub struct PlayerInventory {
     pub last_updated: Timestamp,
 }
 
+/// Read-only view of a player's inventory for trading UIs, written by get_inventory_snapshot
+#[spacetimedb::table(name = inventory_snapshot, public)]
+#[derive(Debug, Clone)]
+pub struct InventorySnapshot {
+    #[primary_key]
+    pub player_id: u64,
+    pub display_name: String,
+    pub composition: Vec<WavePacketSample>,
+    pub total_count: u32,
+    pub red_count: u32,
+    pub yellow_count: u32,
+    pub green_count: u32,
+    pub cyan_count: u32,
+    pub blue_count: u32,
+    pub magenta_count: u32,
+    pub snapshot_at: Timestamp,
+}
+
+/// Top miners by player_stats.total_mined, rebuilt by refresh_mining_leaderboard
+#[spacetimedb::table(name = mining_leaderboard, public)]
+#[derive(Debug, Clone)]
+pub struct MiningLeaderboardEntry {
+    #[primary_key]
+    pub rank: u32,           // 1 = most packets mined
+    pub player_id: u64,
+    pub display_name: String,
+    pub total_mined: u64,
+    pub refreshed_at: Timestamp,
+}
+
+/// Two-party packet trade offers
+/// status: "Pending", "Completed", "Cancelled"
+#[spacetimedb::table(name = trade_offer, public)]
+#[derive(Debug, Clone)]
+pub struct TradeOffer {
+    #[primary_key]
+    #[auto_inc]
+    pub offer_id: u64,
+    pub from_player_id: u64,
+    pub to_player_id: u64,
+    pub offered: Vec<WavePacketSample>,    // Given by from_player
+    pub requested: Vec<WavePacketSample>,  // Given by to_player
+    pub status: String,
+    pub created_at: Timestamp,
+    pub resolved_at: Option<Timestamp>,
+}
+
 // ============================================================================
 // World Tables
 // ============================================================================
@@ -520,6 +829,75 @@ pub struct World {
     pub world_name: String,
     pub world_type: String,
     pub shell_level: u8,
+    pub allowed_frequencies: Vec<f32>,  // Frequencies storage on this world accepts (empty = all)
+    // Ellipsoid semi-axes for oblate/prolate worlds (None = default sphere radius)
+    pub radius_x: Option<f32>,
+    pub radius_y: Option<f32>,
+    pub radius_z: Option<f32>,
+}
+
+/// Runtime-tunable world constants (singleton, id 0)
+/// Seeded from the compile-time consts on __init__, edited via set_world_constant
+#[spacetimedb::table(name = world_constants, public)]
+#[derive(Debug, Clone)]
+pub struct WorldConstants {
+    #[primary_key]
+    pub id: u32,  // Always 0 for singleton
+    pub world_radius: f32,
+    pub packet_speed: f32,
+    pub source_move_speed: f32,
+    pub source_rise_speed: f32,
+    pub dissipation_interval_ms: u64,
+    pub dissipation_rate: u32,
+    pub dissipation_probability: f32,
+    pub max_player_speed: f32,
+    pub crystal_reset_cost: u32,
+    pub crystal_slot_unlock_cost: u32,
+    pub visual_flight_time_ms: u64,  // Extraction animation only - does not affect extraction cooldown
+}
+
+impl WorldConstants {
+    /// Compile-time defaults, used when the singleton row is missing
+    pub fn defaults() -> Self {
+        WorldConstants {
+            id: 0,
+            world_radius: WORLD_RADIUS,
+            packet_speed: PACKET_SPEED,
+            source_move_speed: SOURCE_MOVE_SPEED,
+            source_rise_speed: SOURCE_RISE_SPEED,
+            dissipation_interval_ms: DISSIPATION_INTERVAL_MS,
+            dissipation_rate: DISSIPATION_RATE,
+            dissipation_probability: DISSIPATION_PROBABILITY,
+            max_player_speed: MAX_PLAYER_SPEED,
+            crystal_reset_cost: CRYSTAL_RESET_COST,
+            crystal_slot_unlock_cost: CRYSTAL_SLOT_UNLOCK_COST,
+            visual_flight_time_ms: VISUAL_FLIGHT_TIME_MS,
+        }
+    }
+}
+
+/// Read the world_constants row, falling back to compile-time defaults
+fn get_world_constants(ctx: &ReducerContext) -> WorldConstants {
+    ctx.db.world_constants().id().find(&0).unwrap_or_else(WorldConstants::defaults)
+}
+
+/// A world's ellipsoid semi-axes as (x, y, z), with unset axes (or a missing world)
+/// at the world_constants radius - the one place world geometry reads its size from
+fn world_radii(ctx: &ReducerContext, world_coords: &WorldCoords) -> DbVector3 {
+    let world = ctx.db.world().iter().find(|w| w.world_coords == *world_coords);
+    radii_of(world.as_ref(), get_world_constants(ctx).world_radius)
+}
+
+/// world_radii for a world already in hand - the tick paths use this to skip the world scan
+fn radii_of(world: Option<&World>, default_radius: f32) -> DbVector3 {
+    match world {
+        Some(world) => DbVector3::new(
+            world.radius_x.unwrap_or(default_radius),
+            world.radius_y.unwrap_or(default_radius),
+            world.radius_z.unwrap_or(default_radius),
+        ),
+        None => DbVector3::new(default_radius, default_radius, default_radius),
+    }
 }
 
 #[spacetimedb::table(name = world_circuit, public)]
@@ -535,6 +913,9 @@ pub struct WorldCircuit {
     pub sources_per_emission: u32,
     pub emission_interval_ms: u64,
     pub last_emission_time: u64,
+    pub emission_composition: Vec<WavePacketSample>,  // Optional template; empty = default 80/20 mix
+    pub enabled: bool,                                // Disabled circuits skip emission entirely
+    pub emission_phase_ms: u64,                       // Offset within the interval so circuits don't fire together
 }
 
 /// Energy distribution spheres (26 per world, cardinal directions)
@@ -568,7 +949,8 @@ pub struct DistributionSphere {
     pub sphere_radius: u8,            // Default 40 units
     pub packets_routed: u64,          // Lifetime stat
     pub last_packet_time: Timestamp,
-    pub transit_buffer: Vec<WavePacketSample>,  // Aggregated packets waiting for next pulse (unlimited capacity)
+    pub transit_buffer: Vec<WavePacketSample>,  // Packets of the transfers currently waiting at this sphere
+    pub buffer_capacity: u32,         // Max packets held in transit_buffer, arriving excess is dropped from its transfer
 }
 
 /// Quantum tunnels - top-level ring assemblies (26 per world, cardinal directions)
@@ -590,6 +972,81 @@ pub struct QuantumTunnel {
     pub formed_at: Option<Timestamp>,
 }
 
+/// Result of find_tunnel_path - one row per requester, replaced on each query
+#[spacetimedb::table(name = tunnel_path_result, public)]
+#[derive(Debug, Clone)]
+pub struct TunnelPathResult {
+    #[primary_key]
+    pub requester: Identity,
+    pub from_world: WorldCoords,
+    pub to_world: WorldCoords,
+    pub reachable: bool,
+    pub path: Vec<WorldCoords>,  // Worlds in travel order, including both ends; empty when unreachable
+    pub computed_at: Timestamp,
+}
+
+/// Saved copy of a world's spheres, tunnels, circuits and sources
+/// Written by export_world_snapshot, restored by import_world_snapshot (testing)
+#[spacetimedb::table(name = world_snapshot, public)]
+#[derive(Debug, Clone)]
+pub struct WorldSnapshot {
+    #[primary_key]
+    #[auto_inc]
+    pub snapshot_id: u64,
+    pub world_coords: WorldCoords,
+    pub spheres: Vec<DistributionSphere>,
+    pub tunnels: Vec<QuantumTunnel>,
+    pub circuits: Vec<WorldCircuit>,
+    pub sources: Vec<WavePacketSource>,
+    pub created_at: Timestamp,
+}
+
+/// Aggregated infrastructure counts for a world
+/// Refreshed on demand by refresh_world_summary
+#[spacetimedb::table(name = world_summary, public)]
+#[derive(Debug, Clone)]
+pub struct WorldSummary {
+    #[primary_key]
+    #[auto_inc]
+    pub summary_id: u64,
+    pub world_coords: WorldCoords,
+    pub distribution_sphere_count: u32,
+    pub quantum_tunnel_count: u32,
+    pub circuit_count: u32,
+    pub active_tunnel_count: u32,
+    pub total_packets_routed: u64,
+    pub updated_at: Timestamp,
+}
+
+/// Where every packet in the game currently sits (singleton, id 0)
+/// Refreshed on demand by refresh_packet_economy
+#[spacetimedb::table(name = packet_economy, public)]
+#[derive(Debug, Clone)]
+pub struct PacketEconomy {
+    #[primary_key]
+    pub id: u32,  // Always 0 for singleton
+    pub total_in_inventories: u64,
+    pub total_in_storage: u64,
+    pub total_in_flight: u64,          // Non-completed transfers travelling or queued at their source
+    pub total_in_orbs: u64,
+    pub total_in_sphere_buffers: u64,  // Non-completed transfers waiting at a sphere
+    pub updated_at: Timestamp,
+}
+
+/// Per-sphere routing throughput, sampled every 10s pulse
+/// Rolling window of THROUGHPUT_SAMPLE_WINDOW samples per sphere
+#[spacetimedb::table(name = sphere_throughput_sample, public)]
+#[derive(Debug, Clone)]
+pub struct SphereThroughputSample {
+    #[primary_key]
+    #[auto_inc]
+    pub sample_id: u64,
+    pub sphere_id: u64,
+    pub sampled_at: Timestamp,
+    pub packets_routed_delta: u64,    // Packets routed since the previous sample
+    pub packets_routed_total: u64,    // Sphere's lifetime counter at sample time
+}
+
 /// Player-placed energy storage devices
 /// Store wave packets for later use or trade
 #[spacetimedb::table(name = storage_device, public)]
@@ -602,9 +1059,118 @@ pub struct StorageDevice {
     pub world_coords: WorldCoords,
     pub position: DbVector3,
     pub device_name: String,                    // Display name for UI
-    pub capacity_per_frequency: u32,            // Max per frequency (default 1000, total 6000)
+    pub capacity_per_frequency: u32,            // Max per frequency (default 1000); all six together are capped at MAX_STORAGE_CAPACITY
     pub stored_composition: Vec<WavePacketSample>,  // Current stored packets by frequency
     pub created_at: Timestamp,
+    pub per_frequency_capacity: Vec<FrequencyCapacity>,  // Overrides capacity_per_frequency for listed frequencies
+}
+
+/// Capacity override for a single frequency in a storage device
+#[derive(SpacetimeType, Debug, Clone)]
+pub struct FrequencyCapacity {
+    pub frequency: f32,
+    pub capacity: u32,
+}
+
+/// Capacity of a storage device for one frequency - the override if set, else capacity_per_frequency
+fn storage_capacity_for(device: &StorageDevice, frequency: f32) -> u32 {
+    device.per_frequency_capacity
+        .iter()
+        .find(|c| same_frequency(c.frequency, frequency))
+        .map_or(device.capacity_per_frequency, |c| c.capacity)
+}
+
+/// Capacity of a storage device summed over all six frequencies (u64 so it can't overflow)
+fn total_storage_capacity(device: &StorageDevice) -> u64 {
+    ALL_FREQUENCIES.iter().map(|f| storage_capacity_for(device, *f) as u64).sum()
+}
+
+/// Validate a storage device's capacity settings: the six-frequency total may not exceed
+/// MAX_STORAGE_CAPACITY, and no frequency may drop below what is already stored
+fn check_storage_capacity(device: &StorageDevice) -> Result<(), String> {
+    let total = total_storage_capacity(device);
+    if total > MAX_STORAGE_CAPACITY as u64 {
+        return Err(GameError::InvalidInput(format!(
+            "Total capacity {} exceeds device limit {}", total, MAX_STORAGE_CAPACITY)).into());
+    }
+
+    for frequency in ALL_FREQUENCIES {
+        let capacity = storage_capacity_for(device, frequency);
+        let stored = stored_at_frequency(device, frequency);
+        if capacity < stored {
+            return Err(GameError::CapacityExceeded(format!(
+                "Capacity {} is below currently stored amount {}", capacity, stored)).into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Packets currently stored in a device at one frequency
+fn stored_at_frequency(device: &StorageDevice, frequency: f32) -> u32 {
+    device.stored_composition
+        .iter()
+        .filter(|s| same_frequency(s.frequency, frequency))
+        .map(|s| s.count)
+        .sum()
+}
+
+/// Fill level of a single frequency in a storage device
+#[derive(SpacetimeType, Debug, Clone)]
+pub struct FrequencyFullness {
+    pub frequency: f32,
+    pub stored: u32,
+    pub fraction_full: f32,   // stored / capacity for this frequency
+}
+
+/// Precomputed storage device fill levels for UI fill bars
+/// Refreshed by refresh_storage_fullness
+#[spacetimedb::table(name = storage_device_fullness, public)]
+#[derive(Debug, Clone)]
+pub struct StorageDeviceFullness {
+    #[primary_key]
+    pub device_id: u64,
+    pub total_stored: u32,
+    pub total_capacity: u32,                      // Sum of capacities across all 6 frequencies
+    pub fraction_full: f32,
+    pub per_frequency_breakdown: Vec<FrequencyFullness>,
+    pub updated_at: Timestamp,
+}
+
+/// One storage device in a my_storage_list result
+#[derive(SpacetimeType, Debug, Clone)]
+pub struct StorageListEntry {
+    pub device_id: u64,
+    pub device_name: String,
+    pub world_coords: WorldCoords,
+    pub position: DbVector3,
+    pub stored_composition: Vec<WavePacketSample>,
+    pub total_stored: u32,
+}
+
+/// Caller's storage devices with contents, written by list_my_storage_devices
+#[spacetimedb::table(name = my_storage_list, public)]
+#[derive(Debug, Clone)]
+pub struct MyStorageList {
+    #[primary_key]
+    pub requester: Identity,
+    pub devices: Vec<StorageListEntry>,  // Ordered by device_id
+    pub refreshed_at: Timestamp,
+}
+
+/// Caller's packets across all holdings, written by compute_net_worth
+#[spacetimedb::table(name = net_worth, public)]
+#[derive(Debug, Clone)]
+pub struct NetWorth {
+    #[primary_key]
+    pub requester: Identity,
+    pub player_id: u64,
+    pub composition: Vec<WavePacketSample>,  // Per-frequency totals across everything below
+    pub inventory_total: u32,
+    pub storage_total: u32,                  // All owned storage devices
+    pub in_flight_total: u32,                // Incomplete transfers and uncaptured extractions
+    pub total: u32,
+    pub computed_at: Timestamp,
 }
 
 /// Active energy packet transfers
@@ -631,8 +1197,109 @@ pub struct PacketTransfer {
     pub source_object_id: u64,               // ID of source object
     pub destination_object_type: String,     // "Player", "StorageDevice", "Miner", etc.
     pub destination_object_id: u64,          // ID of destination object
-    pub current_leg_type: String,            // "PendingAtObject", "ObjectToSphere", "SphereToSphere", "SphereToObject", "ArrivedAtSphere"
+    pub current_leg_type: String,            // "PendingAtObject", "ObjectToSphere", "SphereToSphere", "SphereToObject", "ArrivedAtSphere", "Dropped"
     pub predicted_arrival_time: Timestamp,   // When packet should arrive at current destination
+    pub priority: u8,                        // Higher departs first from a queued source (default 0)
+    pub world_coords: WorldCoords,           // World the route lies in - ticked by that world's loop
+}
+
+/// Record of a completed transfer, appended on delivery
+/// Pruned to TRANSFER_LOG_MAX_PER_PLAYER entries per player
+#[spacetimedb::table(name = transfer_log, public)]
+#[derive(Debug, Clone)]
+pub struct TransferLog {
+    #[primary_key]
+    #[auto_inc]
+    pub log_id: u64,
+    pub player_id: u64,
+    pub transfer_id: u64,
+    pub composition: Vec<WavePacketSample>,
+    pub packet_count: u32,
+    pub source_type: String,
+    pub dest_type: String,
+    pub completed_at: Timestamp,
+    pub actual_duration_ms: u64,  // completed_at - initiated_at
+    pub hop_count: u32,           // Spires on the route
+}
+
+/// Caller's recent transfer_log entries (newest first), written by get_transfer_history
+#[spacetimedb::table(name = transfer_history, public)]
+#[derive(Debug, Clone)]
+pub struct TransferHistory {
+    #[primary_key]
+    pub requester: Identity,
+    pub entries: Vec<TransferLog>,
+    pub refreshed_at: Timestamp,
+}
+
+/// Dry-run result of validate_transfer for the requester
+#[spacetimedb::table(name = transfer_validation, public)]
+#[derive(Debug, Clone)]
+pub struct TransferValidation {
+    #[primary_key]
+    pub requester: Identity,
+    pub destination_device_id: u64,
+    pub composition: Vec<WavePacketSample>,
+    pub valid: bool,
+    pub reason: String,  // Why initiate_transfer would fail; empty when valid
+    pub validated_at: Timestamp,
+}
+
+/// Packets a partial-fill initiate_transfer could not fit, left in the requester's inventory
+/// Replaced on every partial-fill call; remainder is empty when everything fit
+#[spacetimedb::table(name = transfer_remainder, public)]
+#[derive(Debug, Clone)]
+pub struct TransferRemainder {
+    #[primary_key]
+    pub requester: Identity,
+    pub destination_device_id: u64,
+    pub remainder: Vec<WavePacketSample>,
+    pub reported_at: Timestamp,
+}
+
+/// Short-lived transfer lifecycle events for client sound/VFX triggers
+/// Rows are removed TRANSFER_EVENT_TTL_MS after they are written
+#[spacetimedb::table(name = transfer_event, public)]
+#[derive(Debug, Clone)]
+pub struct TransferEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub event_id: u64,
+    pub transfer_id: u64,
+    pub event_type: String,  // "Departed", "ArrivedAtSphere", "Delivered", "Dropped"
+    pub position: DbVector3,
+    pub timestamp: Timestamp,
+}
+
+/// Short-lived record of an orb emptying out, for client "pop" effects
+/// Rows are removed ORB_DEPLETED_EVENT_TTL_MS after they are written
+#[spacetimedb::table(name = orb_depleted_event, public)]
+#[derive(Debug, Clone)]
+pub struct OrbDepletedEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub event_id: u64,
+    pub source_id: u64,
+    pub world_coords: WorldCoords,
+    pub position: DbVector3,
+    pub last_miner_player_id: Option<u64>,  // None when the orb dissipated away
+    pub timestamp: Timestamp,
+}
+
+/// Short-lived record of a circuit emitting an orb, for client emergence effects
+/// Rows are removed ORB_SPAWN_EVENT_TTL_MS after they are written
+#[spacetimedb::table(name = orb_spawn_event, public)]
+#[derive(Debug, Clone)]
+pub struct OrbSpawnEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub event_id: u64,
+    pub source_id: u64,
+    pub circuit_id: u64,
+    pub world_coords: WorldCoords,
+    pub position: DbVector3,
+    pub composition: Vec<WavePacketSample>,
+    pub timestamp: Timestamp,
 }
 
 // ============================================================================
@@ -710,6 +1377,8 @@ pub struct WavePacketSource {
     // Concurrent mining support
     pub active_miner_count: u32,  // Track how many miners
     pub last_depletion: u64,      // When packets were last removed
+    pub expiring_soon: bool,      // Within SOURCE_EXPIRY_WARNING_MS of end of lifetime (client fade-out)
+    pub dissipates: bool,         // False freezes the orb's packets (debug-spawned orbs)
 }
 
 
@@ -718,9 +1387,10 @@ pub struct WavePacketSource {
 pub struct PlayerCrystal {
     #[primary_key]
     pub player_id: u64,
-    pub crystal_type: CrystalType,
-    pub slot_count: u8, // 1 for free, 2 for paid
+    pub crystal_type: CrystalType,             // Primary crystal (slot 1)
+    pub slot_count: u8,                        // 1 for free, up to MAX_CRYSTAL_SLOTS via unlock_crystal_slot
     pub chosen_at: u64,
+    pub extra_types: Vec<CrystalType>,         // Crystals equipped in slots 2+ (len < slot_count)
 }
 
 // Add this table to communicate extractions to client
@@ -740,6 +1410,58 @@ pub struct WavePacketExtraction {
     pub expected_arrival: u64,
 }
 
+/// Snapshot of a single orb's composition, written on request by inspect_orb
+/// One row per requester - replaced on each inspection
+#[spacetimedb::table(name = orb_composition_report, public)]
+#[derive(Debug, Clone)]
+pub struct OrbCompositionReport {
+    #[primary_key]
+    pub requester: Identity,
+    pub source_id: u64,
+    pub composition: Vec<WavePacketSample>,
+    pub total_wave_packets: u32,
+    pub active_miner_count: u32,
+    pub reported_at: Timestamp,
+}
+
+/// Players actively mining an orb, written by inspect_orb_miners
+#[spacetimedb::table(name = orb_miners, public)]
+#[derive(Debug, Clone)]
+pub struct OrbMiners {
+    #[primary_key]
+    pub source_id: u64,
+    pub miner_player_ids: Vec<u64>,
+    pub count: u32,
+    pub updated_at: Timestamp,
+}
+
+/// Whether a frequency is mineable in the requester's world, written by can_mine_frequency
+#[spacetimedb::table(name = frequency_availability, public)]
+#[derive(Debug, Clone)]
+pub struct FrequencyAvailability {
+    #[primary_key]
+    pub requester: Identity,
+    pub world_coords: WorldCoords,
+    pub frequency: f32,            // Canonicalized
+    pub available: bool,
+    pub source_count: u32,         // Stationary orbs holding the frequency
+    pub total_packets: u32,        // Packets of the frequency across those orbs
+    pub checked_at: Timestamp,
+}
+
+/// Closest mineable orb to the requester, written by find_nearest_orb
+/// One row per requester - source_id is None when no orb is available
+#[spacetimedb::table(name = nearest_mineable_orb, public)]
+#[derive(Debug, Clone)]
+pub struct NearestMineableOrb {
+    #[primary_key]
+    pub requester: Identity,
+    pub source_id: Option<u64>,
+    pub distance: f32,
+    pub composition: Vec<WavePacketSample>,
+    pub refreshed_at: Timestamp,
+}
+
 // ============================================================================
 // Mining System State
 // ============================================================================
@@ -1148,48 +1870,203 @@ pub fn cleanup_expired_sessions(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
-// ============================================================================
-// Spawn Position Helpers
-// ============================================================================
+/// Delete logged-out players that have been gone longer than max_age_days
+/// Refuses thresholds below MIN_LOGGED_OUT_RETENTION_DAYS
+#[spacetimedb::reducer]
+pub fn cleanup_stale_logged_out_players(ctx: &ReducerContext, max_age_days: u32) -> Result<(), String> {
+    require_admin(ctx)?;
 
-/// Calculate a proper spawn position on the sphere surface for a given world
-/// Returns a position at the north pole of the sphere with proper offset
-fn calculate_spawn_position(world_coords: &WorldCoords) -> DbVector3 {
-    // For now, all worlds spawn at their north pole
-    // The world center is at the world coordinates
-    let world_center_x = world_coords.x as f32 * 10000.0; // Worlds are spaced 10000 units apart
-    let world_center_y = world_coords.y as f32 * 10000.0;
-    let world_center_z = world_coords.z as f32 * 10000.0;
-    
-    // For center world (0,0,0), spawn at north pole
-    if world_coords.x == 0 && world_coords.y == 0 && world_coords.z == 0 {
-        // North pole is at positive Y direction
-        // Position = center + (up vector * (radius + offset))
-        let spawn_x = 0.0;
-        let spawn_y = WORLD_RADIUS + SURFACE_OFFSET;
-        let spawn_z = 0.0;
-        
-        log::info!("Calculated spawn position for center world: ({:.2}, {:.2}, {:.2})", 
-            spawn_x, spawn_y, spawn_z);
-        
-        return DbVector3::new(spawn_x, spawn_y, spawn_z);
+    if max_age_days < MIN_LOGGED_OUT_RETENTION_DAYS {
+        return Err(GameError::InvalidInput(format!(
+            "max_age_days must be at least {}", MIN_LOGGED_OUT_RETENTION_DAYS)).into());
     }
-    
-    // For other worlds, calculate relative north pole
-    let spawn_x = world_center_x;
-    let spawn_y = world_center_y + WORLD_RADIUS + SURFACE_OFFSET;
-    let spawn_z = world_center_z;
-    
-    log::info!("Calculated spawn position for world ({},{},{}): ({:.2}, {:.2}, {:.2})", 
-        world_coords.x, world_coords.y, world_coords.z,
-        spawn_x, spawn_y, spawn_z);
-    
-    DbVector3::new(spawn_x, spawn_y, spawn_z)
-}
 
-// ============================================================================
-// Player Reducers
-// ============================================================================
+    let removed = purge_stale_logged_out_players(ctx, max_age_days);
+    log::info!("Removed {} logged-out players older than {} days", removed, max_age_days);
+
+    Ok(())
+}
+
+/// Delete inactive mining sessions with no activity for max_age_ms
+/// Active sessions are never touched
+#[spacetimedb::reducer]
+pub fn cleanup_inactive_mining_sessions(ctx: &ReducerContext, max_age_ms: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let removed = purge_inactive_mining_sessions(ctx, max_age_ms);
+    log::info!("Removed {} inactive mining sessions older than {}ms", removed, max_age_ms);
+
+    Ok(())
+}
+
+/// Remove inactive MiningSession rows whose last start/extraction is older than max_age_ms,
+/// returning how many were removed
+fn purge_inactive_mining_sessions(ctx: &ReducerContext, max_age_ms: u64) -> u32 {
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+
+    let stale: Vec<MiningSession> = ctx.db.mining_session()
+        .iter()
+        .filter(|s| !s.is_active)
+        .filter(|s| current_time.saturating_sub(s.started_at.max(s.last_extraction)) >= max_age_ms)
+        .collect();
+
+    let count = stale.len() as u32;
+    for session in stale {
+        ctx.db.mining_session().delete(session);
+    }
+    count
+}
+
+/// Remove LoggedOutPlayer rows older than max_age_days, returning how many were removed
+/// Rows whose account logged in within the window are kept - that account may still come back
+fn purge_stale_logged_out_players(ctx: &ReducerContext, max_age_days: u32) -> u32 {
+    let max_age_ms = max_age_days as u64 * 24 * 60 * 60 * 1000;
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+
+    let stale: Vec<LoggedOutPlayer> = ctx.db.logged_out_player()
+        .iter()
+        .filter(|p| {
+            let logged_out_ms = ctx.timestamp
+                .duration_since(p.logout_time)
+                .map_or(0, |age| age.as_millis() as u64);
+            if logged_out_ms < max_age_ms {
+                return false;
+            }
+
+            let account_recently_active = p.account_id
+                .and_then(|account_id| ctx.db.account().account_id().find(&account_id))
+                .map_or(false, |account| current_time.saturating_sub(account.last_login) < max_age_ms);
+            !account_recently_active
+        })
+        .collect();
+
+    let count = stale.len() as u32;
+    for logged_out in stale {
+        log::info!("Purging stale logged-out player '{}' (ID: {})", logged_out.name, logged_out.player_id);
+        ctx.db.logged_out_player().delete(logged_out);
+    }
+
+    count
+}
+
+/// Whether the caller is in the admin_identity table
+fn is_admin(ctx: &ReducerContext) -> bool {
+    ctx.db.admin_identity().identity().find(&ctx.sender).is_some()
+}
+
+/// Reject callers that aren't in the admin_identity table
+fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin(ctx) {
+        log::warn!("Rejected admin-only call from {:?}", ctx.sender);
+        return Err("Not authorized".to_string());
+    }
+    Ok(())
+}
+
+/// Grant admin rights to another identity (admin only)
+#[spacetimedb::reducer]
+pub fn grant_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if ctx.db.admin_identity().identity().find(&identity).is_some() {
+        return Err("Identity is already an admin".to_string());
+    }
+
+    ctx.db.admin_identity().insert(AdminIdentity {
+        identity,
+        granted_at: ctx.timestamp,
+    });
+
+    log::info!("Admin granted to {:?} by {:?}", identity, ctx.sender);
+    Ok(())
+}
+
+// ============================================================================
+// Spawn Position Helpers
+// ============================================================================
+
+/// Calculate a proper spawn position on the sphere surface for a given world
+/// Returns a position at the north pole of the sphere with proper offset
+fn calculate_spawn_position(ctx: &ReducerContext, world_coords: &WorldCoords) -> DbVector3 {
+    // For now, all worlds spawn at their north pole
+    // The world center is at the world coordinates
+    // North pole height follows the world's y semi-axis (oblate worlds are shorter)
+    let radius_y = world_radii(ctx, world_coords).y;
+    let center = world_center(world_coords);
+    
+    // For center world (0,0,0), spawn at north pole
+    if world_coords.x == 0 && world_coords.y == 0 && world_coords.z == 0 {
+        // North pole is at positive Y direction
+        // Position = center + (up vector * (radius + offset))
+        let spawn_x = 0.0;
+        let spawn_y = radius_y + SURFACE_OFFSET;
+        let spawn_z = 0.0;
+        
+        log::info!("Calculated spawn position for center world: ({:.2}, {:.2}, {:.2})", 
+            spawn_x, spawn_y, spawn_z);
+        
+        return DbVector3::new(spawn_x, spawn_y, spawn_z);
+    }
+    
+    // For other worlds, calculate relative north pole
+    let spawn_x = center.x;
+    let spawn_y = center.y + radius_y + SURFACE_OFFSET;
+    let spawn_z = center.z;
+    
+    log::info!("Calculated spawn position for world ({},{},{}): ({:.2}, {:.2}, {:.2})", 
+        world_coords.x, world_coords.y, world_coords.z,
+        spawn_x, spawn_y, spawn_z);
+    
+    DbVector3::new(spawn_x, spawn_y, spawn_z)
+}
+
+/// Center of a world in global coordinates
+fn world_center(world_coords: &WorldCoords) -> DbVector3 {
+    DbVector3::new(
+        world_coords.x as f32 * WORLD_SPACING,
+        world_coords.y as f32 * WORLD_SPACING,
+        world_coords.z as f32 * WORLD_SPACING,
+    )
+}
+
+/// Reject NaN/infinite positions and positions more than MAX_POSITION_RADII of the
+/// world's largest semi-axis away from that world's center
+fn check_player_position(position: &DbVector3, world_coords: &WorldCoords, radii: &DbVector3) -> Result<(), String> {
+    if !position.x.is_finite() || !position.y.is_finite() || !position.z.is_finite() {
+        return Err(GameError::InvalidInput("Position contains NaN or infinite values".to_string()).into());
+    }
+
+    let max_distance = radii.x.max(radii.y).max(radii.z) * MAX_POSITION_RADII;
+    let distance = position.distance_to(&world_center(world_coords));
+    if distance > max_distance {
+        return Err(GameError::InvalidInput(format!(
+            "Position is {:.2} from the world center, limit {:.2}", distance, max_distance)).into());
+    }
+    Ok(())
+}
+
+/// Reject a move further than `max_speed` (with MOVEMENT_GRACE_FACTOR and MOVEMENT_SLACK)
+/// allows in `elapsed_ms`
+fn check_player_movement(from: &DbVector3, to: &DbVector3, elapsed_ms: u64, max_speed: f32) -> Result<(), String> {
+    let elapsed_secs = elapsed_ms as f32 / 1000.0;
+    let allowed_distance = max_speed * MOVEMENT_GRACE_FACTOR * elapsed_secs + MOVEMENT_SLACK;
+    let moved = from.distance_to(to);
+    if moved > allowed_distance {
+        return Err(GameError::InvalidInput(format!(
+            "Movement too fast: {:.2} units in {:.3}s (allowed {:.2})", moved, elapsed_secs, allowed_distance)).into());
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Player Reducers
+// ============================================================================
 
 #[spacetimedb::reducer]
 pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
@@ -1200,6 +2077,9 @@ pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
         log::warn!("Create player failed: Invalid name length '{}'", name);
         return Err("Player name must be 1-20 characters".to_string());
     }
+
+    // A player is coming online - resume full-speed ticks if the loop slowed down
+    wake_game_loop(ctx);
     
     // Initialize worlds first if needed
     if ctx.db.world().iter().find(|w| w.world_coords == WorldCoords { x: 0, y: 0, z: 0 }).is_none() {
@@ -1240,6 +2120,7 @@ pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
         
         ctx.db.player().insert(player.clone());
         ctx.db.logged_out_player().delete(logged_out);
+        create_inventory_if_missing(ctx, player.player_id);
         
         log::info!("Restored player '{}' (ID: {}) with saved position", player.name, player.player_id);
         return Ok(());
@@ -1273,6 +2154,7 @@ pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
             
             ctx.db.player().delete(existing_player);
             ctx.db.player().insert(updated_player.clone());
+            create_inventory_if_missing(ctx, updated_player.player_id);
             
             log::info!("Updated player '{}' to new identity {:?}", updated_player.name, ctx.sender);
             return Ok(());
@@ -1307,6 +2189,7 @@ pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
             
             ctx.db.player().insert(player.clone());
             ctx.db.logged_out_player().delete(logged_out.clone());
+            create_inventory_if_missing(ctx, player.player_id);
             
             log::info!("Restored player '{}' (ID: {}) with new identity", player.name, player.player_id);
             return Ok(());
@@ -1320,7 +2203,7 @@ pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
     
     // Create new player at center world with proper spawn position
     let center_world = WorldCoords { x: 0, y: 0, z: 0 };
-    let spawn_position = calculate_spawn_position(&center_world);
+    let spawn_position = calculate_spawn_position(ctx, &center_world);
     
     log::info!("Creating new player '{}' at spawn position ({:.2}, {:.2}, {:.2})", 
         name, spawn_position.x, spawn_position.y, spawn_position.z);
@@ -1339,13 +2222,98 @@ pub fn create_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
             .as_millis() as u64,
     };
     
-    ctx.db.player().insert(player.clone());
-    log::info!("Created new player '{}' (ID will be auto-generated) at north pole of center world", name);
+    let player = ctx.db.player().insert(player);
+    log::info!("Created new player '{}' (ID: {}) at north pole of center world", name, player.player_id);
+    
+    update_player_stats(ctx, player.player_id, |_| {});
+    create_inventory_if_missing(ctx, player.player_id);
     
     log::info!("Player creation successful for '{}'", name);
     log::info!("=== CREATE_PLAYER END ===");
     Ok(())
 }
+
+/// Apply an update to a player's lifetime stats, creating the row on first use
+/// New rows count the player's current world as visited
+fn update_player_stats<F: FnOnce(&mut PlayerStats)>(ctx: &ReducerContext, player_id: u64, update: F) {
+    let mut stats = match ctx.db.player_stats().player_id().find(&player_id) {
+        Some(existing) => {
+            ctx.db.player_stats().player_id().delete(&player_id);
+            existing
+        }
+        None => {
+            let start_world = ctx.db.player()
+                .player_id()
+                .find(&player_id)
+                .map(|p| p.current_world)
+                .unwrap_or(WorldCoords { x: 0, y: 0, z: 0 });
+            PlayerStats {
+                player_id,
+                total_mined: 0,
+                total_transferred: 0,
+                total_discovered: 0,
+                worlds_visited: 1,
+                visited_worlds: vec![start_world],
+                created_at: ctx.timestamp,
+                last_device_created_at: 0,
+            }
+        }
+    };
+
+    update(&mut stats);
+    ctx.db.player_stats().insert(stats);
+}
+
+/// ADMIN: Rebuild mining_leaderboard with the top_n players by total_mined (capped at MAX_LEADERBOARD_SIZE)
+/// Ties keep the lower player_id first; players who haven't mined are left off
+#[spacetimedb::reducer]
+pub fn refresh_mining_leaderboard(ctx: &ReducerContext, top_n: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if top_n == 0 {
+        return Err(GameError::InvalidInput("Leaderboard size must be at least 1".to_string()).into());
+    }
+    let top_n = top_n.min(MAX_LEADERBOARD_SIZE);
+
+    let mut ranked: Vec<PlayerStats> = ctx.db.player_stats()
+        .iter()
+        .filter(|s| s.total_mined > 0)
+        .collect();
+    ranked.sort_by(|a, b| b.total_mined.cmp(&a.total_mined).then(a.player_id.cmp(&b.player_id)));
+    ranked.truncate(top_n as usize);
+
+    let old_entries: Vec<MiningLeaderboardEntry> = ctx.db.mining_leaderboard().iter().collect();
+    for entry in old_entries {
+        ctx.db.mining_leaderboard().delete(entry);
+    }
+
+    for (index, stats) in ranked.iter().enumerate() {
+        // Online players first, then logged-out ones; account display name wins when linked
+        let (name, account_id) = match ctx.db.player().player_id().find(&stats.player_id) {
+            Some(player) => (player.name, player.account_id),
+            None => match ctx.db.logged_out_player().iter().find(|p| p.player_id == stats.player_id) {
+                Some(player) => (player.name, player.account_id),
+                None => (format!("Player {}", stats.player_id), None),
+            },
+        };
+        let display_name = account_id
+            .and_then(|account_id| ctx.db.account().account_id().find(&account_id))
+            .map(|account| account.display_name)
+            .unwrap_or(name);
+
+        ctx.db.mining_leaderboard().insert(MiningLeaderboardEntry {
+            rank: index as u32 + 1,
+            player_id: stats.player_id,
+            display_name,
+            total_mined: stats.total_mined,
+            refreshed_at: ctx.timestamp,
+        });
+    }
+
+    log::info!("Mining leaderboard refreshed: {} entries (requested top {})", ranked.len(), top_n);
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn update_player_position(
     ctx: &ReducerContext,
@@ -1357,12 +2325,23 @@ pub fn update_player_position(
         .identity()
         .find(&ctx.sender)
         .ok_or("Player not found")?;
+
+    // Reject corrupt positions before writing anything, measured from the player's own world
+    check_player_position(&position, &player.current_world, &world_radii(ctx, &player.current_world))?;
     
     let current_time = ctx.timestamp
         .duration_since(Timestamp::UNIX_EPOCH)
         .expect("Valid timestamp")
         .as_millis() as u64;
     
+    // Speed check: reject jumps further than max speed allows since the last update.
+    // World changes go through travel_to_world, which resets position and last_update.
+    let elapsed_ms = current_time.saturating_sub(player.last_update);
+    if let Err(e) = check_player_movement(&player.position, &position, elapsed_ms, get_world_constants(ctx).max_player_speed) {
+        log::warn!("Rejected movement for '{}': {}", player.name, e);
+        return Err(e);
+    }
+    
     // Log position update every 100th time to avoid spam (based on update count)
     // In production, this should be disabled or use trace level logging
     let update_count = player.last_update % 100;
@@ -1383,6 +2362,102 @@ pub fn update_player_position(
     Ok(())
 }
 
+/// Write the players within `radius` of the caller in the caller's current world
+/// Groundwork for spatial interest management
+#[spacetimedb::reducer]
+pub fn refresh_nearby_players(ctx: &ReducerContext, radius: f32) -> Result<(), String> {
+    if !radius.is_finite() || radius <= 0.0 {
+        return Err(GameError::InvalidInput("Radius must be a positive number".to_string()).into());
+    }
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let player_ids: Vec<u64> = ctx.db.player()
+        .iter()
+        .filter(|p| p.player_id != player.player_id &&
+                    p.current_world == player.current_world &&
+                    p.position.distance_to(&player.position) <= radius)
+        .map(|p| p.player_id)
+        .collect();
+
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.nearby_players().requester().find(&ctx.sender) {
+        ctx.db.nearby_players().delete(existing);
+    }
+
+    ctx.db.nearby_players().insert(NearbyPlayers {
+        requester: ctx.sender,
+        world_coords: player.current_world,
+        radius,
+        player_ids,
+        refreshed_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Look up a player by display name (case-insensitive), online or logged out
+/// Resolves through account display names, falling back to player names for accountless players
+#[spacetimedb::reducer]
+pub fn find_player_by_name(ctx: &ReducerContext, display_name: String) -> Result<(), String> {
+    let query = display_name.trim().to_lowercase();
+    if query.is_empty() {
+        return Err(GameError::InvalidInput("Name must not be empty".to_string()).into());
+    }
+
+    let account = ctx.db.account()
+        .iter()
+        .find(|a| a.display_name.to_lowercase() == query);
+
+    // (player_id, display name, online)
+    let found: Option<(u64, String, bool)> = match account {
+        Some(account) => {
+            if let Some(player) = ctx.db.player().iter().find(|p| p.account_id == Some(account.account_id)) {
+                Some((player.player_id, account.display_name, true))
+            } else {
+                ctx.db.logged_out_player()
+                    .iter()
+                    .find(|p| p.account_id == Some(account.account_id))
+                    .map(|p| (p.player_id, account.display_name, false))
+            }
+        }
+        None => {
+            if let Some(player) = ctx.db.player().iter().find(|p| p.name.to_lowercase() == query) {
+                Some((player.player_id, player.name, true))
+            } else {
+                ctx.db.logged_out_player()
+                    .iter()
+                    .find(|p| p.name.to_lowercase() == query)
+                    .map(|p| (p.player_id, p.name, false))
+            }
+        }
+    };
+
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.player_lookup_result().requester().find(&ctx.sender) {
+        ctx.db.player_lookup_result().delete(existing);
+    }
+
+    let (player_id, resolved_name, is_online) = match found {
+        Some((id, name, online)) => (Some(id), name, online),
+        None => (None, String::new(), false),
+    };
+
+    ctx.db.player_lookup_result().insert(PlayerLookupResult {
+        requester: ctx.sender,
+        query: display_name,
+        player_id,
+        display_name: resolved_name,
+        is_online,
+        looked_up_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn travel_to_world(
     ctx: &ReducerContext,
@@ -1417,7 +2492,7 @@ pub fn travel_to_world(
     }
     
     // Calculate spawn position for the target world
-    let spawn_position = calculate_spawn_position(&world_coords);
+    let spawn_position = calculate_spawn_position(ctx, &world_coords);
     
     log::info!("Setting spawn position for world ({},{},{}): ({:.2}, {:.2}, {:.2})",
         world_coords.x, world_coords.y, world_coords.z,
@@ -1432,9 +2507,17 @@ pub fn travel_to_world(
         .expect("Valid timestamp")
         .as_millis() as u64;
     
+    let player_id = player.player_id;
     ctx.db.player().delete(player);
     ctx.db.player().insert(updated_player);
     
+    update_player_stats(ctx, player_id, |stats| {
+        if !stats.visited_worlds.contains(&world_coords) {
+            stats.visited_worlds.push(world_coords);
+            stats.worlds_visited = stats.visited_worlds.len() as u32;
+        }
+    });
+    
     log::info!("Player '{}' successfully traveled to world ({},{},{}) at position ({:.2}, {:.2}, {:.2})",
         player_name, world_coords.x, world_coords.y, world_coords.z,
         spawn_position.x, spawn_position.y, spawn_position.z);
@@ -1476,6 +2559,7 @@ pub fn choose_crystal(
             .duration_since(Timestamp::UNIX_EPOCH)
             .expect("Valid timestamp")
             .as_millis() as u64,
+        extra_types: Vec::new(),
     };
     
     ctx.db.player_crystal().insert(crystal);
@@ -1486,6 +2570,155 @@ pub fn choose_crystal(
     Ok(())
 }
 
+/// Swap the player's crystal for a different type
+/// Costs crystal_reset_cost packets of the current crystal's color from inventory
+#[spacetimedb::reducer]
+pub fn reset_crystal(ctx: &ReducerContext, new_type: CrystalType) -> Result<(), String> {
+    log::info!("=== RESET_CRYSTAL START ===");
+    log::info!("New crystal type: {:?}, Identity: {:?}", new_type, ctx.sender);
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let crystal = ctx.db.player_crystal()
+        .player_id()
+        .find(&player.player_id)
+        .ok_or(GameError::NotFound("Crystal (choose one first)".to_string()))?;
+
+    if crystal.crystal_type == new_type || crystal.extra_types.contains(&new_type) {
+        return Err(GameError::InvalidInput(format!("You already have a {:?} crystal", new_type)).into());
+    }
+
+    let cost = get_world_constants(ctx).crystal_reset_cost;
+    pay_crystal_cost(ctx, player.player_id, crystal.crystal_type, cost)?;
+
+    // Keep the slot count (paid slots survive a reset)
+    let mut updated = crystal.clone();
+    updated.crystal_type = new_type;
+    updated.chosen_at = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+
+    ctx.db.player_crystal().delete(crystal);
+    ctx.db.player_crystal().insert(updated);
+
+    log::info!("Player '{}' reset crystal to {:?} for {} packets", player.name, new_type, cost);
+    log::info!("=== RESET_CRYSTAL END ===");
+
+    Ok(())
+}
+
+/// Frequency a crystal type is tuned to
+fn crystal_frequency(crystal_type: CrystalType) -> f32 {
+    match crystal_type {
+        CrystalType::Red => FREQ_RED,
+        CrystalType::Green => FREQ_GREEN,
+        CrystalType::Blue => FREQ_BLUE,
+    }
+}
+
+/// Whether any equipped crystal can extract a frequency
+/// A crystal covers its own color and the two adjacent colors on the wheel
+/// (Red: Magenta, Red, Yellow), so each extra slot opens up more of the spectrum
+fn crystal_slots_cover(crystal: &PlayerCrystal, frequency: f32) -> bool {
+    let step = std::f32::consts::TAU / ALL_FREQUENCIES.len() as f32;
+    std::iter::once(crystal.crystal_type)
+        .chain(crystal.extra_types.iter().copied())
+        .any(|t| frequency_distance(crystal_frequency(t), frequency) <= step + FREQUENCY_TOLERANCE)
+}
+
+/// Charge a crystal cost in packets of the given crystal's color
+fn pay_crystal_cost(ctx: &ReducerContext, player_id: u64, crystal_type: CrystalType, cost: u32) -> Result<(), String> {
+    if cost == 0 {
+        return Ok(());
+    }
+
+    let payment = vec![WavePacketSample {
+        frequency: crystal_frequency(crystal_type),
+        amplitude: 1.0,
+        phase: 0.0,
+        count: cost,
+    }];
+
+    if !inventory_covers(ctx, player_id, &payment) {
+        log::warn!("Player {} can't pay {} {:?} packets", player_id, cost, crystal_type);
+        return Err(GameError::InvalidInput(format!(
+            "This costs {} {:?} packets", cost, crystal_type)).into());
+    }
+
+    deduct_composition_from_inventory(ctx, player_id, &payment)
+}
+
+/// Unlock another crystal slot (up to MAX_CRYSTAL_SLOTS)
+/// Costs crystal_slot_unlock_cost packets of the primary crystal's color
+#[spacetimedb::reducer]
+pub fn unlock_crystal_slot(ctx: &ReducerContext) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let crystal = ctx.db.player_crystal()
+        .player_id()
+        .find(&player.player_id)
+        .ok_or(GameError::NotFound("Crystal (choose one first)".to_string()))?;
+
+    if crystal.slot_count >= MAX_CRYSTAL_SLOTS {
+        return Err(GameError::CapacityExceeded(format!(
+            "All {} crystal slots already unlocked", MAX_CRYSTAL_SLOTS)).into());
+    }
+
+    let cost = get_world_constants(ctx).crystal_slot_unlock_cost;
+    pay_crystal_cost(ctx, player.player_id, crystal.crystal_type, cost)?;
+
+    let mut updated = crystal.clone();
+    updated.slot_count += 1;
+
+    log::info!("Player '{}' unlocked crystal slot {} for {} packets", player.name, updated.slot_count, cost);
+
+    ctx.db.player_crystal().delete(crystal);
+    ctx.db.player_crystal().insert(updated);
+
+    Ok(())
+}
+
+/// Equip a crystal type into a free unlocked slot
+#[spacetimedb::reducer]
+pub fn equip_crystal(ctx: &ReducerContext, crystal_type: CrystalType) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let crystal = ctx.db.player_crystal()
+        .player_id()
+        .find(&player.player_id)
+        .ok_or(GameError::NotFound("Crystal (choose one first)".to_string()))?;
+
+    if crystal.crystal_type == crystal_type || crystal.extra_types.contains(&crystal_type) {
+        return Err(GameError::InvalidInput(format!("{:?} crystal is already equipped", crystal_type)).into());
+    }
+
+    // Slot 1 holds the primary crystal
+    if crystal.extra_types.len() + 1 >= crystal.slot_count as usize {
+        return Err(GameError::CapacityExceeded("No free crystal slot - unlock one first".to_string()).into());
+    }
+
+    let mut updated = crystal.clone();
+    updated.extra_types.push(crystal_type);
+
+    log::info!("Player '{}' equipped {:?} crystal ({}/{} slots used)",
+        player.name, crystal_type, updated.extra_types.len() + 1, updated.slot_count);
+
+    ctx.db.player_crystal().delete(crystal);
+    ctx.db.player_crystal().insert(updated);
+
+    Ok(())
+}
+
 // ============================================================================
 // World Initialization
 // ============================================================================
@@ -1500,6 +2733,10 @@ fn init_worlds(ctx: &ReducerContext) -> Result<(), String> {
         world_name: "Genesis".to_string(),
         world_type: "Core".to_string(),
         shell_level: 0,
+        allowed_frequencies: Vec::new(),
+        radius_x: None,
+        radius_y: None,
+        radius_z: None,
     };
     ctx.db.world().insert(center_world);
     log::info!("Created center world: Genesis at (0,0,0)");
@@ -1521,6 +2758,10 @@ fn init_worlds(ctx: &ReducerContext) -> Result<(), String> {
             world_name: name.to_string(),
             world_type: "Prime".to_string(),
             shell_level: 1,
+            allowed_frequencies: Vec::new(),
+            radius_x: None,
+            radius_y: None,
+            radius_z: None,
         };
         ctx.db.world().insert(world);
         log::info!("Created shell 1 world: {} at ({},{},{})", 
@@ -1531,18 +2772,324 @@ fn init_worlds(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
-// ============================================================================
-// Disconnect Handler
-// ============================================================================
+/// Create a new world at the given coordinates (admin only)
+/// Optionally bootstraps all 26 spires and the 6 cardinal circuits
+#[spacetimedb::reducer]
+pub fn create_world(
+    ctx: &ReducerContext,
+    world_coords: WorldCoords,
+    world_name: String,
+    world_type: String,
+    shell_level: u8,
+    bootstrap: bool,
+) -> Result<(), String> {
+    require_admin(ctx)?;
 
-/// Called automatically when a client disconnects from SpacetimeDB
-#[spacetimedb::reducer(client_disconnected)]
-pub fn __identity_disconnected__(ctx: &ReducerContext) -> Result<(), String> {
-    log::info!("=== CLIENT DISCONNECTED (AUTO) ===");
-    log::info!("Identity: {:?}", ctx.sender);
-    
-    // Move player to logged out table
-    if let Some(player) = ctx.db.player().identity().find(&ctx.sender) {
+    log::info!("=== CREATE_WORLD START ===");
+    log::info!("Coords: ({},{},{}), Name: '{}', Type: '{}', Shell: {}, Bootstrap: {}",
+        world_coords.x, world_coords.y, world_coords.z, world_name, world_type, shell_level, bootstrap);
+
+    if world_name.is_empty() {
+        return Err("World name must not be empty".to_string());
+    }
+
+    // Guard against duplicate world coordinates
+    if ctx.db.world().iter().any(|w| w.world_coords == world_coords) {
+        log::warn!("Create world failed: ({},{},{}) already occupied",
+            world_coords.x, world_coords.y, world_coords.z);
+        return Err("A world already exists at these coordinates".to_string());
+    }
+
+    ctx.db.world().insert(World {
+        world_id: 0, // auto_inc
+        world_coords,
+        world_name: world_name.clone(),
+        world_type,
+        shell_level,
+        allowed_frequencies: Vec::new(),
+        radius_x: None,
+        radius_y: None,
+        radius_z: None,
+    });
+
+    if bootstrap {
+        spawn_all_26_spires(ctx, world_coords.x, world_coords.y, world_coords.z)?;
+        spawn_6_cardinal_circuits(ctx, world_coords.x, world_coords.y, world_coords.z)?;
+        log::info!("Bootstrapped spires and circuits for world '{}'", world_name);
+    }
+
+    log::info!("=== CREATE_WORLD END ===");
+    Ok(())
+}
+
+/// Lattice coordinates of a shell with their world type
+/// Shell 1 is the 6 face centers, shell 2 adds the 12 edges, shell 3+ adds the 8 corners,
+/// each scaled out by the shell level
+fn shell_lattice_coords(shell_level: u8) -> Vec<(WorldCoords, &'static str)> {
+    let n = shell_level as i32;
+    let mut coords = Vec::new();
+
+    for x in -1..=1i32 {
+        for y in -1..=1i32 {
+            for z in -1..=1i32 {
+                let world_type = match x.abs() + y.abs() + z.abs() {
+                    1 => "Prime",
+                    2 if shell_level >= 2 => "Planar",
+                    3 if shell_level >= 3 => "Corner",
+                    _ => continue,
+                };
+                coords.push((WorldCoords { x: x * n, y: y * n, z: z * n }, world_type));
+            }
+        }
+    }
+    coords
+}
+
+/// Generated world name from the lattice direction, e.g. "North-East 2" or "Upper Prime" on shell 1
+fn shell_world_name(coords: &WorldCoords, shell_level: u8) -> String {
+    let mut parts = Vec::new();
+    if coords.y > 0 { parts.push("North"); } else if coords.y < 0 { parts.push("South"); }
+    if coords.x > 0 { parts.push("East"); } else if coords.x < 0 { parts.push("West"); }
+    if coords.z > 0 { parts.push("Upper"); } else if coords.z < 0 { parts.push("Lower"); }
+
+    if shell_level == 1 {
+        format!("{} Prime", parts.join("-"))
+    } else {
+        format!("{} {}", parts.join("-"), shell_level)
+    }
+}
+
+/// Admin: create every world of a lattice shell, bootstrapping spires and circuits
+/// Coordinates that already hold a world are skipped, so re-running is a no-op
+#[spacetimedb::reducer]
+pub fn generate_shell(ctx: &ReducerContext, shell_level: u8) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== GENERATE_SHELL START ===");
+    log::info!("Shell: {}", shell_level);
+
+    if shell_level == 0 {
+        return Err(GameError::InvalidInput("Shell 0 is the Genesis world, created on init".to_string()).into());
+    }
+
+    let mut created = 0u32;
+    let mut skipped = 0u32;
+    for (world_coords, world_type) in shell_lattice_coords(shell_level) {
+        if ctx.db.world().iter().any(|w| w.world_coords == world_coords) {
+            skipped += 1;
+            continue;
+        }
+
+        let world_name = shell_world_name(&world_coords, shell_level);
+        ctx.db.world().insert(World {
+            world_id: 0, // auto_inc
+            world_coords,
+            world_name: world_name.clone(),
+            world_type: world_type.to_string(),
+            shell_level,
+            allowed_frequencies: Vec::new(),
+            radius_x: None,
+            radius_y: None,
+            radius_z: None,
+        });
+
+        spawn_all_26_spires(ctx, world_coords.x, world_coords.y, world_coords.z)?;
+        spawn_6_cardinal_circuits(ctx, world_coords.x, world_coords.y, world_coords.z)?;
+        created += 1;
+
+        log::info!("Created shell {} world: {} at ({},{},{})",
+            shell_level, world_name, world_coords.x, world_coords.y, world_coords.z);
+    }
+
+    log::info!("=== GENERATE_SHELL END - Created {} worlds, skipped {} existing ===", created, skipped);
+    Ok(())
+}
+
+/// Whether storage on a world accepts a frequency (empty allowed list or no world row = all allowed)
+fn world_allows_frequency(ctx: &ReducerContext, world_coords: &WorldCoords, frequency: f32) -> bool {
+    match ctx.db.world().iter().find(|w| w.world_coords == *world_coords) {
+        Some(world) => world.allowed_frequencies.is_empty() ||
+            world.allowed_frequencies.iter().any(|f| same_frequency(*f, frequency)),
+        None => true,
+    }
+}
+
+/// Reject a deposit into storage on a world that doesn't accept one of its frequencies
+fn check_world_allows_composition(ctx: &ReducerContext, world_coords: &WorldCoords, composition: &[WavePacketSample]) -> Result<(), String> {
+    if let Some(sample) = composition.iter().find(|s| !world_allows_frequency(ctx, world_coords, s.frequency)) {
+        return Err(GameError::InvalidInput(format!(
+            "Frequency {} cannot be stored on this world", sample.frequency)).into());
+    }
+    Ok(())
+}
+
+/// Admin: restrict which frequencies storage on a world accepts
+/// An empty list lifts the restriction
+#[spacetimedb::reducer]
+pub fn set_world_allowed_frequencies(ctx: &ReducerContext, world_id: u64, frequencies: Vec<f32>) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let world = ctx.db.world()
+        .world_id()
+        .find(&world_id)
+        .ok_or(GameError::NotFound("World".to_string()))?;
+
+    let mut allowed: Vec<f32> = Vec::new();
+    for frequency in frequencies {
+        let canonical = ALL_FREQUENCIES.iter()
+            .copied()
+            .find(|f| same_frequency(*f, frequency))
+            .ok_or(GameError::InvalidInput(format!("{} is not a canonical frequency", frequency)))?;
+        if !allowed.iter().any(|f| same_frequency(*f, canonical)) {
+            allowed.push(canonical);
+        }
+    }
+
+    log::info!("World '{}' allowed frequencies set to {:?}", world.world_name, allowed);
+
+    let mut updated = world.clone();
+    updated.allowed_frequencies = allowed;
+    ctx.db.world().delete(world);
+    ctx.db.world().insert(updated);
+
+    Ok(())
+}
+
+/// Admin: reshape a world into an axis-aligned ellipsoid
+/// A None axis falls back to the world_constants radius; objects already placed keep their positions
+#[spacetimedb::reducer]
+pub fn set_world_radii(
+    ctx: &ReducerContext,
+    world_id: u64,
+    radius_x: Option<f32>,
+    radius_y: Option<f32>,
+    radius_z: Option<f32>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let world = ctx.db.world()
+        .world_id()
+        .find(&world_id)
+        .ok_or(GameError::NotFound("World".to_string()))?;
+
+    for radius in [radius_x, radius_y, radius_z].into_iter().flatten() {
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(GameError::InvalidInput(format!("World radius must be positive, got {}", radius)).into());
+        }
+    }
+
+    log::info!("World '{}' radii set to ({:?}, {:?}, {:?})", world.world_name, radius_x, radius_y, radius_z);
+
+    let mut updated = world.clone();
+    updated.radius_x = radius_x;
+    updated.radius_y = radius_y;
+    updated.radius_z = radius_z;
+    ctx.db.world().delete(world);
+    ctx.db.world().insert(updated);
+
+    Ok(())
+}
+
+/// Admin: update a single tunable world constant by name
+/// Integer-valued constants (dissipation_interval_ms, dissipation_rate, crystal costs, visual_flight_time_ms) are truncated
+#[spacetimedb::reducer]
+pub fn set_world_constant(ctx: &ReducerContext, key: String, value: f32) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if !value.is_finite() || value < 0.0 {
+        return Err("World constant must be a non-negative number".to_string());
+    }
+
+    let mut constants = get_world_constants(ctx);
+    match key.as_str() {
+        "world_radius" => constants.world_radius = value,
+        "packet_speed" => constants.packet_speed = value,
+        "source_move_speed" => constants.source_move_speed = value,
+        "source_rise_speed" => constants.source_rise_speed = value,
+        "dissipation_interval_ms" => constants.dissipation_interval_ms = value as u64,
+        "dissipation_rate" => constants.dissipation_rate = value as u32,
+        "dissipation_probability" => constants.dissipation_probability = value.min(1.0),
+        "max_player_speed" => constants.max_player_speed = value,
+        "crystal_reset_cost" => constants.crystal_reset_cost = value as u32,
+        "crystal_slot_unlock_cost" => constants.crystal_slot_unlock_cost = value as u32,
+        "visual_flight_time_ms" => constants.visual_flight_time_ms = value as u64,
+        _ => return Err(format!("Unknown world constant: {}", key)),
+    }
+
+    if value == 0.0 && matches!(key.as_str(), "world_radius" | "packet_speed") {
+        return Err(format!("{} must be greater than zero", key));
+    }
+
+    ctx.db.world_constants().id().delete(&0);
+    ctx.db.world_constants().insert(constants);
+
+    log::info!("World constant '{}' set to {}", key, value);
+    Ok(())
+}
+
+/// Log filter for a set_log_level level name; "error"/"off" are refused so warnings stay on
+fn log_level_filter(level: &str) -> Option<log::LevelFilter> {
+    match level {
+        "debug" => Some(log::LevelFilter::Debug),
+        "info" => Some(log::LevelFilter::Info),
+        "warn" => Some(log::LevelFilter::Warn),
+        _ => None,
+    }
+}
+
+/// Set once this module instance has applied log_config - a fresh instance starts at Info
+static LOG_LEVEL_APPLIED: OnceLock<()> = OnceLock::new();
+
+/// Apply the log_config level to this module instance's logger
+/// Runs from set_log_level and __init__, and once per instance on its first game_loop tick
+fn apply_log_level(ctx: &ReducerContext) {
+    let filter = ctx.db.log_config()
+        .id()
+        .find(&0)
+        .and_then(|config| log_level_filter(&config.level))
+        .unwrap_or(log::LevelFilter::Info);
+    if log::max_level() != filter {
+        log::set_max_level(filter);
+    }
+}
+
+/// Admin: set log verbosity to "debug", "info" or "warn"
+/// "warn" silences the per-call info logging; warnings and errors are always kept
+#[spacetimedb::reducer]
+pub fn set_log_level(ctx: &ReducerContext, level: String) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let level = level.trim().to_ascii_lowercase();
+    if log_level_filter(&level).is_none() {
+        return Err(GameError::InvalidInput(format!(
+            "Unknown log level '{}' - expected debug, info or warn", level)).into());
+    }
+
+    log::info!("Log level set to '{}'", level);
+
+    ctx.db.log_config().id().delete(&0);
+    ctx.db.log_config().insert(LogConfig {
+        id: 0,
+        level,
+        updated_at: ctx.timestamp,
+    });
+    apply_log_level(ctx);
+
+    Ok(())
+}
+
+// ============================================================================
+// Disconnect Handler
+// ============================================================================
+
+/// Called automatically when a client disconnects from SpacetimeDB
+#[spacetimedb::reducer(client_disconnected)]
+pub fn __identity_disconnected__(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("=== CLIENT DISCONNECTED (AUTO) ===");
+    log::info!("Identity: {:?}", ctx.sender);
+    
+    // Move player to logged out table
+    if let Some(player) = ctx.db.player().identity().find(&ctx.sender) {
         log::info!("Moving player '{}' (ID: {}) to logged_out_player table", 
             player.name, player.player_id);
         
@@ -1670,6 +3217,8 @@ pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
 // Game Loop & Tick Processing
 // ============================================================================
 
+/// Legacy manual tick - maintenance only
+/// Circuit emission runs exclusively in game_loop
 #[spacetimedb::reducer]
 pub fn tick(ctx: &ReducerContext) -> Result<(), String> {
     let current_time = ctx.timestamp
@@ -1677,67 +3226,173 @@ pub fn tick(ctx: &ReducerContext) -> Result<(), String> {
         .expect("Valid timestamp")
         .as_millis() as u64;
     
-    // Get last cleanup time from state or default to 0
-    static LAST_CLEANUP: OnceLock<Mutex<u64>> = OnceLock::new();
-    let last_cleanup_mutex = LAST_CLEANUP.get_or_init(|| Mutex::new(0));
-    let mut last_cleanup = last_cleanup_mutex.lock().unwrap();
+    // Session and extraction cleanup on the shared maintenance_schedule cadence
+    run_scheduled_maintenance(ctx, current_time)?;
     
-    // Clean up sessions every hour
-    if current_time > *last_cleanup + (60 * 60 * 1000) {
-        cleanup_expired_sessions(ctx)?;
-        *last_cleanup = current_time;
+    // Circuit emission is owned by the game loop (two_second_pulse) - emitting
+    // here as well would double the emission rate while both are running
+    
+    // Process orb dissipation
+    let worlds: Vec<World> = ctx.db.world().iter().collect();
+    for world in worlds {
+        process_orb_dissipation(ctx, &world.world_coords)?;
+        cleanup_expired_wave_packet_sources(ctx, &world.world_coords);
     }
     
-    // Process circuits and emit wave packet orbs
-    for circuit in ctx.db.world_circuit().iter() {
-        if current_time >= circuit.last_emission_time + circuit.emission_interval_ms {
-            process_circuit_emission(ctx, &circuit)?;
-            
+    Ok(())
+}
+
+/// Spawn budget and circuit lookup shared by every world ticked in one game loop pass
+struct EmissionPass {
+    budget: u32,
+    circuits_by_world: Option<HashMap<WorldCoords, Vec<WorldCircuit>>>,
+}
+
+impl EmissionPass {
+    fn new() -> Self {
+        EmissionPass {
+            budget: CIRCUIT_SPAWN_BUDGET_PER_PULSE,
+            circuits_by_world: None,
+        }
+    }
+
+    /// A world's circuits, grouped from a single world_circuit scan the first time any world asks
+    fn take_circuits(&mut self, ctx: &ReducerContext, world_coords: &WorldCoords) -> Vec<WorldCircuit> {
+        let circuits_by_world = self.circuits_by_world.get_or_insert_with(|| {
+            let mut grouped: HashMap<WorldCoords, Vec<WorldCircuit>> = HashMap::new();
+            for circuit in ctx.db.world_circuit().iter() {
+                grouped.entry(circuit.world_coords).or_default().push(circuit);
+            }
+            grouped
+        });
+        circuits_by_world.remove(world_coords).unwrap_or_default()
+    }
+}
+
+/// Emit from every circuit in a world whose emission interval has elapsed
+/// Only called from two_second_pulse - the game loop is the single emission authority
+fn process_circuit_emissions(ctx: &ReducerContext, world: &World, current_time: u64, pass: &mut EmissionPass) -> Result<(), String> {
+    let world_coords = &world.world_coords;
+    let circuits = pass.take_circuits(ctx, world_coords);
+    if circuits.is_empty() {
+        return Ok(());
+    }
+    let radii = radii_of(Some(world), get_world_constants(ctx).world_radius);
+
+    // One source scan per world; spawns during this pass are counted on top
+    let world_sources: Vec<WavePacketSource> = ctx.db.wave_packet_source()
+        .iter()
+        .filter(|s| s.world_coords == *world_coords)
+        .collect();
+    let mut world_source_count = world_sources.len() as u32;
+
+    for circuit in circuits {
+        // Paused circuits keep their last_emission_time untouched
+        if !circuit.enabled {
+            continue;
+        }
+
+        if circuit_emission_due(&circuit, current_time) {
+            // Budget spent - leave last_emission_time alone so the circuit stays due next pulse
+            if pass.budget == 0 {
+                log::info!("[Emission] Spawn budget exhausted, deferring circuit {}", circuit.circuit_id);
+                continue;
+            }
+
+            let spawned = process_circuit_emission(ctx, &circuit, &radii, &world_sources, world_source_count, pass.budget)?;
+            pass.budget -= spawned;
+            world_source_count += spawned;
+
             // Update circuit emission time
             let mut updated_circuit = circuit.clone();
             updated_circuit.last_emission_time = current_time;
-            ctx.db.world_circuit().delete(circuit);
+            ctx.db.world_circuit().circuit_id().delete(&circuit.circuit_id);
             ctx.db.world_circuit().insert(updated_circuit);
         }
     }
-    
-    // Process orb dissipation
-    process_orb_dissipation(ctx)?;
-    
-    // Clean up expired wave packet orbs - DISABLED: sources should only be deleted when depleted
-    // cleanup_expired_wave_packet_sources(ctx)?;
-    
-    // Clean up old extraction notifications
-    cleanup_old_extractions(ctx)?;
-    
-    
+
     Ok(())
 }
 
-fn process_circuit_emission(ctx: &ReducerContext, circuit: &WorldCircuit) -> Result<(), String> {
+/// A circuit is due when a phase-shifted interval boundary
+/// (emission_phase_ms + k * emission_interval_ms) falls after its last emission
+fn circuit_emission_due(circuit: &WorldCircuit, current_time: u64) -> bool {
+    let interval = circuit.emission_interval_ms;
+    if interval == 0 {
+        return true;
+    }
+    let phase = circuit.emission_phase_ms % interval;
+    let slot = |t: u64| (t + interval - phase) / interval;
+    slot(current_time) > slot(circuit.last_emission_time)
+}
+
+/// Pick a pseudo-random emission phase in [0, interval) for a new circuit
+fn random_emission_phase(ctx: &ReducerContext, world_coords: &WorldCoords, direction: &str, interval_ms: u64) -> u64 {
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+
+    if interval_ms == 0 {
+        return 0;
+    }
+
+    let direction_hash = direction.bytes().fold(0u64, |h, b| h.wrapping_mul(31).wrapping_add(b as u64));
+    let seed = (ctx.timestamp.to_micros_since_unix_epoch() as u64)
+        ^ direction_hash.wrapping_mul(0x9E3779B97F4A7C15)
+        ^ ((world_coords.x as u64) << 42)
+        ^ ((world_coords.y as u64) << 21)
+        ^ (world_coords.z as u64);
+    StdRng::seed_from_u64(seed).gen_range(0..interval_ms)
+}
+
+/// Spawn up to `budget` sources for one circuit, returning how many were spawned
+/// `world_sources` is the world's sources before this pass, `world_source_count` includes this pass's spawns
+fn process_circuit_emission(
+    ctx: &ReducerContext,
+    circuit: &WorldCircuit,
+    radii: &DbVector3,
+    world_sources: &[WavePacketSource],
+    world_source_count: u32,
+    budget: u32,
+) -> Result<u32, String> {
     use rand::{Rng, SeedableRng};
     use rand::rngs::StdRng;
 
+    if !circuit.enabled {
+        return Ok(0);
+    }
+
     // Get circuit position on sphere surface based on cardinal direction
-    let circuit_position = get_cardinal_position(&circuit.cardinal_direction);
+    let circuit_position = direction_metadata(&circuit.cardinal_direction).0.ellipsoid_project(radii, 0.0);
+
+    // Enforce the world-wide source cap before anything else
+    if world_source_count >= MAX_SOURCES_PER_WORLD {
+        log::info!("Circuit {} skipped emission: world ({},{},{}) at source cap ({}/{})",
+            circuit.circuit_id, circuit.world_coords.x, circuit.world_coords.y, circuit.world_coords.z,
+            world_source_count, MAX_SOURCES_PER_WORLD);
+        return Ok(0);
+    }
 
-    // Count existing sources within CIRCUIT_CHECK_RADIUS of this circuit
-    let existing_count = ctx.db.wave_packet_source().iter()
+    // Count existing mineable (stationary) sources within CIRCUIT_CHECK_RADIUS of this circuit.
+    // Sources still moving or rising don't block new emissions.
+    let existing_count = world_sources.iter()
         .filter(|s| {
-            s.world_coords == circuit.world_coords &&
+            s.state == SOURCE_STATE_STATIONARY &&
             s.position.distance_to(&circuit_position) < CIRCUIT_CHECK_RADIUS
         })
         .count() as u32;
 
-    // Calculate how many sources we need to spawn
-    let needed = circuit.sources_per_emission.saturating_sub(existing_count);
+    // Calculate how many sources we need to spawn, limited by world headroom and pulse budget
+    let needed = circuit.sources_per_emission
+        .saturating_sub(existing_count)
+        .min(MAX_SOURCES_PER_WORLD - world_source_count)
+        .min(budget);
 
     if needed == 0 {
-        return Ok(());  // Already have enough sources nearby
+        return Ok(0);  // Already have enough sources nearby
     }
 
     // Create RNG with better entropy mixing
-    let emission_count = ctx.db.wave_packet_source().iter().count() as u64;
+    let emission_count = world_source_count as u64;
     let base_time = ctx.timestamp.to_micros_since_unix_epoch() as u64;
     let seed = base_time
         .wrapping_mul(0x9E3779B97F4A7C15)  // Golden ratio constant
@@ -1770,16 +3425,21 @@ fn process_circuit_emission(ctx: &ReducerContext, circuit: &WorldCircuit) -> Res
         let travel_distance = rng.gen_range(SOURCE_TRAVEL_MIN..SOURCE_TRAVEL_MAX);
 
         // Calculate destination position on sphere surface at height 0
-        let spawn_position = surface_normal.scale(WORLD_RADIUS + SOURCE_HEIGHT_0);
-        let destination = travel_on_sphere_surface(&spawn_position, &travel_direction, travel_distance);
+        let spawn_position = surface_normal.ellipsoid_project(radii, SOURCE_HEIGHT_0);
+        let destination = travel_on_sphere_surface(&spawn_position, &travel_direction, travel_distance)
+            .ellipsoid_project(radii, SOURCE_HEIGHT_0);
 
         // Get secondary color from travel direction (tangent vector, not absolute position)
         let travel_dir_name = closest_cardinal_direction(&travel_direction);
         let secondary_freq = get_direction_frequency(&travel_dir_name);
 
-        // Create 80/20 composition
+        // Circuit template if configured, otherwise 80/20 primary/secondary mix
         let total_packets = rng.gen_range(80..120);  // 80-120 packets per source
-        let composition = create_mixed_composition(primary_freq, secondary_freq, total_packets);
+        let composition = if circuit.emission_composition.is_empty() {
+            create_mixed_composition(primary_freq, secondary_freq, total_packets)
+        } else {
+            scale_composition(&circuit.emission_composition, total_packets)
+        };
 
         // Calculate velocity (tangent direction * speed)
         let velocity = travel_direction.scale(SOURCE_MOVE_SPEED);
@@ -1794,15 +3454,18 @@ fn process_circuit_emission(ctx: &ReducerContext, circuit: &WorldCircuit) -> Res
             state: SOURCE_STATE_MOVING_H,  // Start moving horizontally
             state_start_timestamp: current_time_micros,  // Track when this state began (microseconds)
             wave_packet_composition: composition.clone(),
-            total_wave_packets: composition.iter().map(|s| s.count).sum(),
+            total_wave_packets: composition::total_count(&composition),
             creation_time: current_time,
             lifetime_ms: 600_000,  // 10 minutes
             last_dissipation: current_time,
             active_miner_count: 0,
             last_depletion: current_time,
+            expiring_soon: false,
+            dissipates: true,
         };
 
-        ctx.db.wave_packet_source().insert(source);
+        let source = ctx.db.wave_packet_source().insert(source);
+        record_orb_spawn_event(ctx, &source, circuit.circuit_id);
 
         log::info!("[Emission] Circuit {} ({}) spawned moving source {} toward {} (dist={:.1})",
             circuit.circuit_id, circuit.cardinal_direction, i + 1, travel_dir_name, travel_distance);
@@ -1811,7 +3474,7 @@ fn process_circuit_emission(ctx: &ReducerContext, circuit: &WorldCircuit) -> Res
     log::info!("[Emission] Circuit {} ({}) emitted {} sources (had {} existing within {}u)",
         circuit.circuit_id, circuit.cardinal_direction, needed, existing_count, CIRCUIT_CHECK_RADIUS);
 
-    Ok(())
+    Ok(needed)
 }
 
 #[spacetimedb::reducer]
@@ -1823,11 +3486,17 @@ pub fn emit_wave_packet_source(
     use rand::{Rng, SeedableRng};
     use rand::rngs::StdRng;
     
-    // Create a deterministic RNG based on timestamp
-    let seed = ctx.timestamp
+    // Deterministic RNG from timestamp, position and spawn counter -
+    // two spawns in the same reducer call still get distinct orbs
+    let position_hash = (source_position.x.to_bits() as u64)
+        ^ (source_position.y.to_bits() as u64).rotate_left(21)
+        ^ (source_position.z.to_bits() as u64).rotate_left(42);
+    let seed = (ctx.timestamp
         .duration_since(Timestamp::UNIX_EPOCH)
         .expect("Valid timestamp")
-        .as_nanos() as u64;
+        .as_nanos() as u64)
+        ^ position_hash.wrapping_mul(0x517CC1B727220A95)
+        ^ next_spawn_counter(ctx).wrapping_mul(0x9E3779B97F4A7C15);
     let mut rng = StdRng::seed_from_u64(seed);
     
     // Random velocity
@@ -1879,6 +3548,8 @@ pub fn emit_wave_packet_source(
         last_dissipation: current_time,
         active_miner_count: 0,
         last_depletion: current_time,
+        expiring_soon: false,
+        dissipates: true,
     };
 
     ctx.db.wave_packet_source().insert(source);
@@ -1886,7 +3557,20 @@ pub fn emit_wave_packet_source(
     Ok(())
 }
 
-fn process_orb_dissipation(ctx: &ReducerContext) -> Result<(), String> {
+/// Increment and return the singleton spawn counter
+fn next_spawn_counter(ctx: &ReducerContext) -> u64 {
+    let count = match ctx.db.spawn_counter().id().find(&0) {
+        Some(counter) => {
+            ctx.db.spawn_counter().id().delete(&0);
+            counter.count + 1
+        }
+        None => 1,
+    };
+    ctx.db.spawn_counter().insert(SpawnCounter { id: 0, count });
+    count
+}
+
+fn process_orb_dissipation(ctx: &ReducerContext, world_coords: &WorldCoords) -> Result<(), String> {
     use rand::{Rng, SeedableRng};
     use rand::rngs::StdRng;
 
@@ -1895,15 +3579,15 @@ fn process_orb_dissipation(ctx: &ReducerContext) -> Result<(), String> {
         .expect("Valid timestamp")
         .as_millis() as u64;
 
-    const DISSIPATION_INTERVAL_MS: u64 = 10000; // Every 10 seconds
-    const DISSIPATION_RATE: u32 = 1; // Lose 1 packet per interval
-    const DISSIPATION_PROBABILITY: f32 = 0.5; // 50% chance to dissipate
+    let constants = get_world_constants(ctx);
 
     let sources_to_check: Vec<_> = ctx.db.wave_packet_source()
         .iter()
         .filter(|source| {
+            source.dissipates &&
+            source.world_coords == *world_coords &&
             source.total_wave_packets > 0 &&
-            current_time >= source.last_dissipation + DISSIPATION_INTERVAL_MS
+            current_time >= source.last_dissipation + constants.dissipation_interval_ms
         })
         .collect();
 
@@ -1919,7 +3603,7 @@ fn process_orb_dissipation(ctx: &ReducerContext) -> Result<(), String> {
         let mut rng = StdRng::seed_from_u64(seed);
 
         // 50% probability check FIRST - skip update entirely if roll fails
-        let should_dissipate = rng.gen::<f32>() < DISSIPATION_PROBABILITY;
+        let should_dissipate = rng.gen::<f32>() < constants.dissipation_probability;
         if !should_dissipate {
             // Don't update anything - no database write, no event fired
             continue;
@@ -1927,7 +3611,7 @@ fn process_orb_dissipation(ctx: &ReducerContext) -> Result<(), String> {
 
         // Roll passed - now we'll actually dissipate and update
         let mut updated_source = source.clone();
-        updated_source.total_wave_packets = updated_source.total_wave_packets.saturating_sub(DISSIPATION_RATE);
+        updated_source.total_wave_packets = updated_source.total_wave_packets.saturating_sub(constants.dissipation_rate);
         updated_source.last_dissipation = current_time;
 
         // Also reduce composition counts
@@ -1953,12 +3637,12 @@ fn process_orb_dissipation(ctx: &ReducerContext) -> Result<(), String> {
 
         let is_now_empty = updated_source.total_wave_packets == 0;
 
-        ctx.db.wave_packet_source().delete(source);
-
         if is_now_empty {
             // Don't re-insert - source is depleted, delete it
             log::info!("Source {} fully dissipated and removed", source_id);
+            remove_orb(ctx, &updated_source, None);
         } else {
+            ctx.db.wave_packet_source().delete(source);
             ctx.db.wave_packet_source().insert(updated_source);
         }
     }
@@ -1966,25 +3650,65 @@ fn process_orb_dissipation(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
-// DISABLED: Sources should only be deleted when depleted to 0 packets, not by time
-// fn cleanup_expired_wave_packet_sources(ctx: &ReducerContext) -> Result<(), String> {
-//     let current_time = ctx.timestamp
-//         .duration_since(Timestamp::UNIX_EPOCH)
-//         .expect("Valid timestamp")
-//         .as_millis() as u64;
-//
-//     let expired_sources: Vec<_> = ctx.db.wave_packet_source()
-//         .iter()
-//         .filter(|source| current_time >= source.creation_time + source.lifetime_ms as u64)
-//         .collect();
-//
-//     for source in expired_sources {
-//         log::info!("Removing expired orb {}", source.source_id);
-//         ctx.db.wave_packet_source().delete(source);
-//     }
-//
-//     Ok(())
-// }
+/// Remove sources in one world whose lifetime has run out
+/// Clients get SOURCE_EXPIRY_WARNING_MS of expiring_soon first to fade them out. An orb with
+/// transfers still in flight is kept until they land, so a failed transfer can refund to it
+fn cleanup_expired_wave_packet_sources(ctx: &ReducerContext, world_coords: &WorldCoords) {
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+
+    let expired_sources: Vec<_> = ctx.db.wave_packet_source()
+        .iter()
+        .filter(|source| source.world_coords == *world_coords)
+        .filter(|source| source_expired(source, current_time))
+        .collect();
+
+    for source in expired_sources {
+        let in_flight = ctx.db.packet_transfer().iter().filter(|t| {
+            !t.completed && t.source_object_type == "Orb" && t.source_object_id == source.source_id
+        }).count();
+        if in_flight > 0 {
+            log::debug!("Expired orb {} has {} transfers in flight - keeping it until they land",
+                source.source_id, in_flight);
+            continue;
+        }
+
+        log::info!("Removing expired orb {} ({} packets left)", source.source_id, source.total_wave_packets);
+        remove_orb(ctx, &source, None);
+    }
+}
+
+/// Take an orb out of the world: end its mining sessions, drop its orb_miners row and
+/// record an orb_depleted_event at its last position
+fn remove_orb(ctx: &ReducerContext, source: &WavePacketSource, last_miner_player_id: Option<u64>) {
+    let sessions: Vec<MiningSession> = ctx.db.mining_session()
+        .iter()
+        .filter(|s| s.source_id == source.source_id && s.is_active)
+        .collect();
+    for session in sessions {
+        let mut updated_session = session.clone();
+        updated_session.is_active = false;
+        ctx.db.mining_session().delete(session);
+        ctx.db.mining_session().insert(updated_session);
+    }
+
+    ctx.db.orb_miners().source_id().delete(&source.source_id);
+    ctx.db.wave_packet_source().source_id().delete(&source.source_id);
+    record_orb_depleted_event(ctx, source, last_miner_player_id);
+}
+
+/// Whether a source has lived past creation_time + lifetime_ms
+/// Frozen orbs (dissipates == false) never expire
+fn source_expired(source: &WavePacketSource, current_time: u64) -> bool {
+    source.dissipates && current_time >= source.creation_time + source.lifetime_ms as u64
+}
+
+/// Whether a source is within SOURCE_EXPIRY_WARNING_MS of its end of lifetime
+fn source_expiring_soon(source: &WavePacketSource, current_time: u64) -> bool {
+    source.dissipates && current_time + SOURCE_EXPIRY_WARNING_MS >= source.creation_time + source.lifetime_ms as u64
+}
 
 fn cleanup_old_extractions(ctx: &ReducerContext) -> Result<(), String> {
     let current_time = ctx.timestamp
@@ -2015,6 +3739,8 @@ pub fn debug_give_crystal(
     ctx: &ReducerContext,
     crystal_type: CrystalType,
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_GIVE_CRYSTAL START ===");
     log::info!("Crystal type: {:?}, Identity: {:?}", crystal_type, ctx.sender);
     
@@ -2037,6 +3763,7 @@ pub fn debug_give_crystal(
             .duration_since(Timestamp::UNIX_EPOCH)
             .expect("Valid timestamp")
             .as_millis() as u64,
+        extra_types: Vec::new(),
     };
     
     ctx.db.player_crystal().insert(crystal);
@@ -2056,6 +3783,8 @@ pub fn debug_give_crystal(
 
 #[spacetimedb::reducer]
 pub fn debug_mining_status(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_MINING_STATUS START ===");
     
     let sessions: Vec<_> = ctx.db.mining_session().iter().collect();
@@ -2090,6 +3819,8 @@ pub fn debug_mining_status(ctx: &ReducerContext) -> Result<(), String> {
 /// Debug command to list all active extraction records
 #[spacetimedb::reducer]
 pub fn debug_list_extractions(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_LIST_EXTRACTIONS START ===");
 
     let extractions: Vec<_> = ctx.db.wave_packet_extraction().iter().collect();
@@ -2126,6 +3857,8 @@ pub fn debug_list_extractions(ctx: &ReducerContext) -> Result<(), String> {
 
 #[spacetimedb::reducer]
 pub fn debug_reset_spawn_position(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_RESET_SPAWN_POSITION START ===");
     log::info!("Identity: {:?}", ctx.sender);
     
@@ -2139,7 +3872,7 @@ pub fn debug_reset_spawn_position(ctx: &ReducerContext) -> Result<(), String> {
     let old_position = player.position.clone();
     
     // Calculate new spawn position using the helper function
-    let new_position = calculate_spawn_position(&player.current_world);
+    let new_position = calculate_spawn_position(ctx, &player.current_world);
     
     log::info!("Resetting spawn position for player '{}'", player_name);
     log::info!("  Old position: ({:.2}, {:.2}, {:.2})", 
@@ -2164,8 +3897,49 @@ pub fn debug_reset_spawn_position(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Teleport the caller to stand directly below a distribution sphere on their current world
+#[spacetimedb::reducer]
+pub fn debug_teleport_to_spire(ctx: &ReducerContext, cardinal_direction: String) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== DEBUG_TELEPORT_TO_SPIRE START ===");
+    log::info!("Identity: {:?}, Direction: {}", ctx.sender, cardinal_direction);
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+
+    let sphere = ctx.db.distribution_sphere()
+        .iter()
+        .find(|s| s.world_coords == player.current_world && s.cardinal_direction == cardinal_direction)
+        .ok_or(format!("No distribution sphere '{}' on world ({},{},{})",
+            cardinal_direction, player.current_world.x, player.current_world.y, player.current_world.z))?;
+
+    // Project the sphere down onto the surface at object height
+    let new_position = sphere.sphere_position.ellipsoid_project(&world_radii(ctx, &sphere.world_coords), OBJECT_PACKET_HEIGHT);
+
+    log::info!("Teleporting '{}' below sphere {} to ({:.2}, {:.2}, {:.2})",
+        player.name, sphere.sphere_id, new_position.x, new_position.y, new_position.z);
+
+    let mut updated_player = player.clone();
+    updated_player.position = new_position;
+    updated_player.last_update = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+
+    ctx.db.player().delete(player);
+    ctx.db.player().insert(updated_player);
+
+    log::info!("=== DEBUG_TELEPORT_TO_SPIRE END ===");
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn debug_test_spawn_positions(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_TEST_SPAWN_POSITIONS START ===");
     
     // Test various world coordinates
@@ -2179,7 +3953,7 @@ pub fn debug_test_spawn_positions(ctx: &ReducerContext) -> Result<(), String> {
     
     log::info!("Testing spawn position calculations:");
     for world in test_worlds {
-        let spawn_pos = calculate_spawn_position(&world);
+        let spawn_pos = calculate_spawn_position(ctx, &world);
         log::info!("  World ({},{},{}) -> Spawn ({:.2}, {:.2}, {:.2})",
             world.x, world.y, world.z,
             spawn_pos.x, spawn_pos.y, spawn_pos.z);
@@ -2189,14 +3963,15 @@ pub fn debug_test_spawn_positions(ctx: &ReducerContext) -> Result<(), String> {
                         spawn_pos.y * spawn_pos.y + 
                         spawn_pos.z * spawn_pos.z).sqrt();
         
+        let radius_y = world_radii(ctx, &world).y;
         let expected_magnitude = if world.x == 0 && world.y == 0 && world.z == 0 {
-            WORLD_RADIUS + SURFACE_OFFSET
+            radius_y + SURFACE_OFFSET
         } else {
             // For other worlds, calculate expected based on world spacing
             let world_center_mag = ((world.x as f32 * 10000.0).powi(2) +
                                    (world.y as f32 * 10000.0).powi(2) +
                                    (world.z as f32 * 10000.0).powi(2)).sqrt();
-            world_center_mag + WORLD_RADIUS + SURFACE_OFFSET
+            world_center_mag + radius_y + SURFACE_OFFSET
         };
         
         let error = (magnitude - expected_magnitude).abs();
@@ -2213,6 +3988,8 @@ pub fn debug_test_spawn_positions(ctx: &ReducerContext) -> Result<(), String> {
 
 #[spacetimedb::reducer]
 pub fn debug_validate_all_players(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_VALIDATE_ALL_PLAYERS START ===");
     
     let mut invalid_count = 0;
@@ -2249,7 +4026,7 @@ pub fn debug_validate_all_players(ctx: &ReducerContext) -> Result<(), String> {
                 format!("({:.2}, {:.2}, {:.2})", position.x, position.y, position.z));
             
             // Correct the position
-            let corrected_position = calculate_spawn_position(&player.current_world);
+            let corrected_position = calculate_spawn_position(ctx, &player.current_world);
             
             let mut updated_player = player.clone();
             updated_player.position = corrected_position;
@@ -2266,7 +4043,9 @@ pub fn debug_validate_all_players(ctx: &ReducerContext) -> Result<(), String> {
                 corrected_position.x, corrected_position.y, corrected_position.z);
         } else {
             // Check if on correct surface
-            let expected_distance = WORLD_RADIUS + SURFACE_OFFSET;
+            let expected_distance = position
+                .ellipsoid_project(&world_radii(ctx, &player.current_world), SURFACE_OFFSET)
+                .magnitude();
             let distance_error = (magnitude - expected_distance).abs();
             
             if distance_error > 5.0 {
@@ -2287,30 +4066,90 @@ pub fn debug_validate_all_players(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
-// ============================================================================
-// NEW: Concurrent Mining System Reducers
-// ============================================================================
-
-/// TESTING REDUCER: Spawn a test orb at a specified position
-///
-/// # Arguments
-/// * `x`, `y`, `z` - Position coordinates
-/// * `frequency` - Frequency band (0=Red, 1=Yellow, 2=Green, 3=Cyan, 4=Blue, 5=Magenta)
-/// * `packet_count` - Number of wave packets in the orb
-///
-/// # Example
-/// ```bash
-/// spacetime call system spawn_test_orb 10.0 20.0 30.0 4 100
-/// ```
+/// Admin: check every orb's total_wave_packets against its composition and repair drift
+/// Orbs whose composition sums to zero are deleted and their mining sessions ended
 #[spacetimedb::reducer]
-pub fn spawn_test_orb(
-    ctx: &ReducerContext,
-    x: f32,
-    y: f32,
+pub fn debug_validate_orbs(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== DEBUG_VALIDATE_ORBS START ===");
+
+    let mut mismatch_count = 0;
+    let mut deleted_count = 0;
+
+    let sources: Vec<WavePacketSource> = ctx.db.wave_packet_source().iter().collect();
+    let total_orbs = sources.len();
+
+    for source in sources {
+        let composition_total = composition::total_count(&source.wave_packet_composition);
+
+        if composition_total == 0 {
+            log::warn!("Orb {} has an empty composition (total_wave_packets {}) - deleting",
+                source.source_id, source.total_wave_packets);
+
+            let sessions: Vec<MiningSession> = ctx.db.mining_session()
+                .iter()
+                .filter(|s| s.source_id == source.source_id && s.is_active)
+                .collect();
+            for session in sessions {
+                let mut updated_session = session.clone();
+                updated_session.is_active = false;
+                ctx.db.mining_session().delete(session);
+                ctx.db.mining_session().insert(updated_session);
+            }
+
+            ctx.db.wave_packet_source().delete(source);
+            deleted_count += 1;
+            continue;
+        }
+
+        if source.total_wave_packets != composition_total {
+            mismatch_count += 1;
+            log::warn!("Orb {} total_wave_packets {} != composition sum {} - correcting",
+                source.source_id, source.total_wave_packets, composition_total);
+
+            let mut updated = source.clone();
+            updated.total_wave_packets = composition_total;
+            ctx.db.wave_packet_source().delete(source);
+            ctx.db.wave_packet_source().insert(updated);
+        }
+    }
+
+    log::info!("Validation complete:");
+    log::info!("  Total orbs: {}", total_orbs);
+    log::info!("  Totals corrected: {}", mismatch_count);
+    log::info!("  Empty orbs deleted: {}", deleted_count);
+
+    log::info!("=== DEBUG_VALIDATE_ORBS END ===");
+    Ok(())
+}
+
+// ============================================================================
+// NEW: Concurrent Mining System Reducers
+// ============================================================================
+
+/// TESTING REDUCER: Spawn a test orb at a specified position
+///
+/// # Arguments
+/// * `x`, `y`, `z` - Position coordinates
+/// * `frequency` - Frequency band (0=Red, 1=Yellow, 2=Green, 3=Cyan, 4=Blue, 5=Magenta)
+/// * `packet_count` - Number of wave packets in the orb
+///
+/// # Example
+/// ```bash
+/// spacetime call system spawn_test_orb 10.0 20.0 30.0 4 100
+/// ```
+#[spacetimedb::reducer]
+pub fn spawn_test_orb(
+    ctx: &ReducerContext,
+    x: f32,
+    y: f32,
     z: f32,
     frequency: u8,
     packet_count: u32,
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_TEST_ORB START ===");
     log::info!("Position: ({}, {}, {}), Frequency: {}, Packets: {}", x, y, z, frequency, packet_count);
 
@@ -2356,6 +4195,8 @@ pub fn spawn_test_orb(
         last_dissipation: current_time,
         active_miner_count: 0,
         last_depletion: current_time,
+        expiring_soon: false,
+        dissipates: false,
     };
 
     // Insert into database
@@ -2386,6 +4227,8 @@ pub fn spawn_mixed_orb(
     green_packets: u32,
     blue_packets: u32,
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_MIXED_ORB DEBUG ===");
     log::info!("Position: ({}, {}, {})", x, y, z);
     log::info!("Composition: R:{}, G:{}, B:{}", red_packets, green_packets, blue_packets);
@@ -2449,6 +4292,8 @@ pub fn spawn_mixed_orb(
         last_dissipation: current_time,
         active_miner_count: 0,
         last_depletion: current_time,
+        expiring_soon: false,
+        dissipates: false,
     };
 
     ctx.db.wave_packet_source().insert(source);
@@ -2484,6 +4329,8 @@ pub fn spawn_full_spectrum_orb(
     blue_packets: u32,
     magenta_packets: u32,
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_FULL_SPECTRUM_ORB ===");
     log::info!("Position: ({}, {}, {})", x, y, z);
     log::info!("Composition: R:{} Y:{} G:{} C:{} B:{} M:{}",
@@ -2576,6 +4423,8 @@ pub fn spawn_full_spectrum_orb(
         last_dissipation: current_time,
         active_miner_count: 0,
         last_depletion: current_time,
+        expiring_soon: false,
+        dissipates: false,
     };
 
     ctx.db.wave_packet_source().insert(source);
@@ -2615,6 +4464,8 @@ pub fn spawn_debug_orbs(
     blue: u32,
     magenta: u32,
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_DEBUG_ORBS START ===");
     log::info!("Player: '{}', Count: {}, Height: {}", player_name, orb_count, height_from_surface);
     log::info!("Composition: R:{} Y:{} G:{} C:{} B:{} M:{}", red, yellow, green, cyan, blue, magenta);
@@ -2626,6 +4477,100 @@ pub fn spawn_debug_orbs(
     }
 
     // Build composition from packet counts
+    let composition = composition_from_counts(red, yellow, green, cyan, blue, magenta);
+
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+    let current_time_micros = ctx.timestamp.to_micros_since_unix_epoch() as u64;
+
+    // Debug orbs always go to the origin world
+    let radii = world_radii(ctx, &WorldCoords { x: 0, y: 0, z: 0 });
+
+    // Determine spawn origin
+    let spawn_origin = if player_name.is_empty() {
+        // Random spawn mode
+        None
+    } else {
+        // Find player
+        let player = ctx.db.player()
+            .iter()
+            .find(|p| p.name == player_name)
+            .ok_or(format!("Player '{}' not found", player_name))?;
+
+        log::info!("Found player at ({}, {}, {})",
+            player.position.x, player.position.y, player.position.z);
+        Some(player.position)
+    };
+
+    // Spawn orbs
+    for i in 0..orb_count {
+        let position = if let Some(origin) = spawn_origin {
+            // Spawn in circle around player
+            let angle = (i as f32 / orb_count as f32) * 2.0 * PI;
+            let radius = 20.0; // 20 units from player
+
+            // Calculate offset in tangent plane
+            let (tangent1, tangent2) = origin.tangent_basis();
+
+            // Calculate position in circle on tangent plane
+            let offset_x = angle.cos() * radius;
+            let offset_y = angle.sin() * radius;
+
+            // Calculate point on tangent plane
+            let tangent_point = DbVector3::new(
+                origin.x + tangent1.x * offset_x + tangent2.x * offset_y,
+                origin.y + tangent1.y * offset_x + tangent2.y * offset_y,
+                origin.z + tangent1.z * offset_x + tangent2.z * offset_y,
+            );
+
+            // Project onto the surface, raised by the requested height
+            tangent_point.ellipsoid_project(&radii, height_from_surface)
+        } else {
+            // Random position on sphere surface
+            // Use spherical coordinates
+            let theta = (i as f32 / orb_count as f32) * 2.0 * PI; // Azimuth
+            let phi = ((i as f32 * 0.618033988749895) % 1.0) * PI; // Polar angle (golden ratio for distribution)
+
+            DbVector3::new(
+                phi.sin() * theta.cos(),
+                phi.cos(),
+                phi.sin() * theta.sin(),
+            ).ellipsoid_project(&radii, height_from_surface)
+        };
+
+        // Create orb (stationary for debug spawns)
+        let source = WavePacketSource {
+            source_id: 0, // auto_inc
+            world_coords: WorldCoords { x: 0, y: 0, z: 0 },
+            position,
+            velocity: DbVector3::zero(),
+            destination: position,
+            state: SOURCE_STATE_STATIONARY,
+            state_start_timestamp: current_time_micros,
+            wave_packet_composition: composition.clone(),
+            total_wave_packets: total_packets,
+            creation_time: current_time,
+            lifetime_ms: 3600000, // 1 hour
+            last_dissipation: current_time,
+            active_miner_count: 0,
+            last_depletion: current_time,
+            expiring_soon: false,
+            dissipates: false,
+        };
+
+        ctx.db.wave_packet_source().insert(source);
+        log::info!("Spawned orb {} at ({:.2}, {:.2}, {:.2})",
+            i + 1, position.x, position.y, position.z);
+    }
+
+    log::info!("=== SPAWN_DEBUG_ORBS END - Created {} orbs ===", orb_count);
+    Ok(())
+}
+
+/// Build a wave packet composition from per-color packet counts (zero counts omitted)
+fn composition_from_counts(red: u32, yellow: u32, green: u32, cyan: u32, blue: u32, magenta: u32) -> Vec<WavePacketSample> {
     let mut composition = Vec::new();
     if red > 0 {
         composition.push(WavePacketSample {
@@ -2676,99 +4621,66 @@ pub fn spawn_debug_orbs(
         });
     }
 
-    let current_time = ctx.timestamp
-        .duration_since(Timestamp::UNIX_EPOCH)
-        .expect("Valid timestamp")
-        .as_millis() as u64;
-    let current_time_micros = ctx.timestamp.to_micros_since_unix_epoch() as u64;
-
-    // Determine spawn origin
-    let spawn_origin = if player_name.is_empty() {
-        // Random spawn mode
-        None
-    } else {
-        // Find player
-        let player = ctx.db.player()
-            .iter()
-            .find(|p| p.name == player_name)
-            .ok_or(format!("Player '{}' not found", player_name))?;
-
-        log::info!("Found player at ({}, {}, {})",
-            player.position.x, player.position.y, player.position.z);
-        Some(player.position)
-    };
-
-    // Spawn orbs
-    for i in 0..orb_count {
-        let position = if let Some(origin) = spawn_origin {
-            // Spawn in circle around player
-            let angle = (i as f32 / orb_count as f32) * 2.0 * PI;
-            let radius = 20.0; // 20 units from player
-
-            // Calculate offset in tangent plane
-            let origin_norm = (origin.x * origin.x + origin.y * origin.y + origin.z * origin.z).sqrt();
-            let up = DbVector3::new(origin.x / origin_norm, origin.y / origin_norm, origin.z / origin_norm);
+    composition
+}
 
-            // Find perpendicular vectors for tangent plane
-            let arbitrary = if up.y.abs() < 0.9 {
-                DbVector3::new(0.0, 1.0, 0.0)
-            } else {
-                DbVector3::new(1.0, 0.0, 0.0)
-            };
+/// Spawn a line of stationary orbs along the great circle between two directions' surface points
+/// Orbs include both endpoints and are evenly spaced by arc length
+#[spacetimedb::reducer]
+pub fn spawn_orb_line(
+    ctx: &ReducerContext,
+    from_direction: String,
+    to_direction: String,
+    count: u32,
+    red: u32,
+    yellow: u32,
+    green: u32,
+    cyan: u32,
+    blue: u32,
+    magenta: u32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
 
-            // Cross product for first tangent
-            let tangent1 = DbVector3::new(
-                arbitrary.y * up.z - arbitrary.z * up.y,
-                arbitrary.z * up.x - arbitrary.x * up.z,
-                arbitrary.x * up.y - arbitrary.y * up.x,
-            );
-            let t1_len = (tangent1.x * tangent1.x + tangent1.y * tangent1.y + tangent1.z * tangent1.z).sqrt();
-            let tangent1 = DbVector3::new(tangent1.x / t1_len, tangent1.y / t1_len, tangent1.z / t1_len);
-
-            // Cross product for second tangent
-            let tangent2 = DbVector3::new(
-                up.y * tangent1.z - up.z * tangent1.y,
-                up.z * tangent1.x - up.x * tangent1.z,
-                up.x * tangent1.y - up.y * tangent1.x,
-            );
+    log::info!("=== SPAWN_ORB_LINE START ===");
+    log::info!("From: {}, To: {}, Count: {}", from_direction, to_direction, count);
 
-            // Calculate position in circle on tangent plane
-            let offset_x = angle.cos() * radius;
-            let offset_y = angle.sin() * radius;
+    if !ALL_DIRECTIONS.contains(&from_direction.as_str()) || !ALL_DIRECTIONS.contains(&to_direction.as_str()) {
+        return Err("Unknown cardinal direction".to_string());
+    }
+    if count == 0 {
+        return Err("Count must be at least 1".to_string());
+    }
 
-            // Position at fixed radius from world center (WORLD_RADIUS + height)
-            let target_radius = WORLD_RADIUS + height_from_surface;
+    let total_packets = red + yellow + green + cyan + blue + magenta;
+    if total_packets == 0 {
+        return Err("Must specify at least one packet type".to_string());
+    }
+    let composition = composition_from_counts(red, yellow, green, cyan, blue, magenta);
 
-            // Calculate point on tangent plane
-            let tangent_point = DbVector3::new(
-                origin.x + tangent1.x * offset_x + tangent2.x * offset_y,
-                origin.y + tangent1.y * offset_x + tangent2.y * offset_y,
-                origin.z + tangent1.z * offset_x + tangent2.z * offset_y,
-            );
+    let start = direction_metadata(&from_direction).0.normalize();
+    let end = direction_metadata(&to_direction).0.normalize();
+    let radii = world_radii(ctx, &WorldCoords { x: 0, y: 0, z: 0 });
 
-            // Normalize and scale to target radius
-            let tp_len = (tangent_point.x * tangent_point.x + tangent_point.y * tangent_point.y + tangent_point.z * tangent_point.z).sqrt();
-            DbVector3::new(
-                tangent_point.x * target_radius / tp_len,
-                tangent_point.y * target_radius / tp_len,
-                tangent_point.z * target_radius / tp_len,
-            )
-        } else {
-            // Random position on sphere surface
-            // Use spherical coordinates
-            let theta = (i as f32 / orb_count as f32) * 2.0 * PI; // Azimuth
-            let phi = ((i as f32 * 0.618033988749895) % 1.0) * PI; // Polar angle (golden ratio for distribution)
+    // Great-circle arc: rotate start around (start × end) by a fraction of the total angle
+    let total_angle = start.arc_distance_to(&end, 1.0);
+    let mut axis = start.cross(&end);
+    if axis.magnitude() < 1e-6 {
+        // Same or antipodal points - any axis perpendicular to start works
+        axis = start.tangent_basis().0;
+    }
+    let axis = axis.normalize();
 
-            let radius_at_height = WORLD_RADIUS + height_from_surface;
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+    let current_time_micros = ctx.timestamp.to_micros_since_unix_epoch() as u64;
 
-            DbVector3::new(
-                radius_at_height * phi.sin() * theta.cos(),
-                radius_at_height * phi.cos(),
-                radius_at_height * phi.sin() * theta.sin(),
-            )
-        };
+    for i in 0..count {
+        let t = if count == 1 { 0.5 } else { i as f32 / (count - 1) as f32 };
+        let position = rotate_vector(&start, &axis, total_angle * t)
+            .ellipsoid_project(&radii, SOURCE_HEIGHT_1);
 
-        // Create orb (stationary for debug spawns)
         let source = WavePacketSource {
             source_id: 0, // auto_inc
             world_coords: WorldCoords { x: 0, y: 0, z: 0 },
@@ -2784,6 +4696,8 @@ pub fn spawn_debug_orbs(
             last_dissipation: current_time,
             active_miner_count: 0,
             last_depletion: current_time,
+            expiring_soon: false,
+            dissipates: false,
         };
 
         ctx.db.wave_packet_source().insert(source);
@@ -2791,7 +4705,7 @@ pub fn spawn_debug_orbs(
             i + 1, position.x, position.y, position.z);
     }
 
-    log::info!("=== SPAWN_DEBUG_ORBS END - Created {} orbs ===", orb_count);
+    log::info!("=== SPAWN_ORB_LINE END - Created {} orbs ===", count);
     Ok(())
 }
 
@@ -2824,18 +4738,24 @@ pub fn start_mining_v2(
 
     if existing_session.is_some() {
         log::warn!("Player already mining this orb");
-        return Err("You are already mining this orb".to_string());
+        return Err(GameError::InvalidState("You are already mining this orb".to_string()).into());
     }
 
     // Verify orb exists and has packets remaining
     let source = ctx.db.wave_packet_source()
         .source_id()
         .find(&source_id)
-        .ok_or("Orb not found")?;
+        .ok_or(GameError::NotFound("Orb".to_string()))?;
 
     if source.total_wave_packets == 0 {
         log::warn!("Orb is depleted");
-        return Err("Orb has no packets remaining".to_string());
+        return Err(GameError::InvalidState("Orb has no packets remaining".to_string()).into());
+    }
+
+    // Orbs still travelling or rising haven't visually arrived yet
+    if source.state != SOURCE_STATE_STATIONARY {
+        log::warn!("Orb {} is not stationary (state={})", source_id, source.state);
+        return Err(GameError::InvalidState("Orb is not yet mineable".to_string()).into());
     }
 
     let current_time = ctx.timestamp
@@ -2845,7 +4765,7 @@ pub fn start_mining_v2(
 
     // Validate crystal composition
     if crystal_composition.is_empty() {
-        return Err("Must provide at least one crystal".to_string());
+        return Err(GameError::InvalidInput("Must provide at least one crystal".to_string()).into());
     }
 
     // Create new mining session
@@ -2874,6 +4794,7 @@ pub fn start_mining_v2(
 
     log::info!("Mining session started successfully for source {} (active miners: {})",
         source_id, active_count);
+    wake_game_loop(ctx);
     log::info!("=== START_MINING_V2 END ===");
 
     Ok(())
@@ -2901,20 +4822,93 @@ pub fn extract_packets_v2(
         log::info!("  Requesting {} packets of frequency {:.2}", req.count, req.frequency);
     }
 
-    // Verify session exists and belongs to caller
+    // Verify session exists
     let session = ctx.db.mining_session()
         .session_id()
         .find(&session_id)
-        .ok_or("Session not found")?;
+        .ok_or(GameError::NotFound("Session".to_string()))?;
+
+    let (source, actual_extraction, current_time) = plan_orb_extraction(ctx, &session, &requested_frequencies)?;
+    let total_to_extract = composition::total_count(&actual_extraction);
+    let session_source_id = session.source_id;
+    let (updated_source, player) = commit_orb_extraction(ctx, session, source, &actual_extraction, current_time)?;
+
+    // Create visual extraction record with EXACT requested composition
+    let packet_id = (session_id << 32) | (current_time & 0xFFFFFFFF);
+    let flight_time = get_world_constants(ctx).visual_flight_time_ms;
+
+    let extraction = WavePacketExtraction {
+        extraction_id: 0, // auto_inc
+        player_id: player.player_id,
+        source_type: "orb".to_string(),
+        source_id: session_source_id,
+        packet_id,
+        composition: actual_extraction.clone(), // Exact composition extracted
+        total_count: total_to_extract,
+        departure_time: current_time,
+        expected_arrival: current_time + flight_time,
+    };
+
+    ctx.db.wave_packet_extraction().insert(extraction);
+    wake_game_loop(ctx);
+
+    log::info!("Created extraction record with {} total packets:", total_to_extract);
+    for sample in &actual_extraction {
+        log::info!("  Frequency {:.2}: {} packets", sample.frequency, sample.count);
+    }
+
+    log::info!("Extracted {} total packets (orb remaining: {})",
+        total_to_extract, updated_source.total_wave_packets);
+    log::info!("=== EXTRACT_PACKETS_V2 END ===");
+
+    Ok(())
+}
+
+/// Color-name variant of extract_packets_v2: colors[i] ("Red".."Magenta") is requested
+/// amounts[i] times. Same rules and cooldown apply
+#[spacetimedb::reducer]
+pub fn extract_by_color(
+    ctx: &ReducerContext,
+    session_id: u64,
+    colors: Vec<String>,
+    amounts: Vec<u32>,
+) -> Result<(), String> {
+    let requested_frequencies = color_extraction_requests(&colors, &amounts)?;
+    extract_packets_v2(ctx, session_id, requested_frequencies)
+}
 
+/// Pair color names with amounts as extraction requests at each color's canonical frequency
+fn color_extraction_requests(colors: &[String], amounts: &[u32]) -> Result<Vec<ExtractionRequest>, String> {
+    if colors.len() != amounts.len() {
+        return Err(GameError::InvalidInput(format!(
+            "Got {} colors but {} amounts", colors.len(), amounts.len())).into());
+    }
+
+    let mut requested_frequencies = Vec::new();
+    for (color, &count) in colors.iter().zip(amounts) {
+        let frequency = color_frequency(color)
+            .ok_or(GameError::InvalidInput(format!("Unknown color: {}", color)))?;
+        requested_frequencies.push(ExtractionRequest { frequency, count });
+    }
+    Ok(requested_frequencies)
+}
+
+/// Validate an extraction against the caller's session, cooldown, crystals and the orb
+/// Returns the orb, the samples that can be extracted and the current time in ms
+/// Nothing is written - commit_orb_extraction applies the result
+fn plan_orb_extraction(
+    ctx: &ReducerContext,
+    session: &MiningSession,
+    requested_frequencies: &[ExtractionRequest],
+) -> Result<(WavePacketSource, Vec<WavePacketSample>, u64), String> {
     if session.player_identity != ctx.sender {
         log::warn!("Session does not belong to caller");
-        return Err("Session does not belong to you".to_string());
+        return Err(GameError::Unauthorized("Session does not belong to you".to_string()).into());
     }
 
     if !session.is_active {
         log::warn!("Session is not active");
-        return Err("Session is not active".to_string());
+        return Err(GameError::InvalidState("Session is not active".to_string()).into());
     }
 
     let current_time = ctx.timestamp
@@ -2928,30 +4922,44 @@ pub fn extract_packets_v2(
 
     if time_since_last < EXTRACTION_COOLDOWN_MS {
         let remaining_ms = EXTRACTION_COOLDOWN_MS - time_since_last;
-        return Err(format!("Extraction on cooldown ({} ms remaining)", remaining_ms));
+        return Err(GameError::Cooldown(remaining_ms).into());
     }
 
     // Get the orb
     let source = ctx.db.wave_packet_source()
         .source_id()
         .find(&session.source_id)
-        .ok_or("Orb no longer exists")?;
+        .ok_or(GameError::NotFound("Orb".to_string()))?;
+
+    // Crystals the miner has equipped - players without a PlayerCrystal are limited
+    // only by the session's crystal composition
+    let owned_crystal = ctx.db.player()
+        .identity()
+        .find(&session.player_identity)
+        .and_then(|p| ctx.db.player_crystal().player_id().find(&p.player_id));
 
     // Validate request against orb composition AND crystal composition filtering
     let mut actual_extraction: Vec<WavePacketSample> = Vec::new();
-    let mut total_to_extract = 0u32;
 
-    for request in &requested_frequencies {
+    for request in requested_frequencies {
         // Check if crystal composition can extract this frequency
-        // Exact match (within 0.01 rad) required
+        // Exact match (within FREQUENCY_TOLERANCE) required
         let crystal_match = session.crystal_composition.iter()
-            .find(|crystal| (crystal.frequency - request.frequency).abs() < 0.01);
+            .find(|crystal| same_frequency(crystal.frequency, request.frequency));
 
         if crystal_match.is_none() {
             log::info!("  No crystal matches frequency {:.3} - skipping", request.frequency);
             continue;
         }
 
+        // Every equipped crystal slot widens the extractable spectrum
+        if let Some(owned) = &owned_crystal {
+            if !crystal_slots_cover(owned, request.frequency) {
+                log::info!("  No equipped crystal covers frequency {:.3} - skipping", request.frequency);
+                continue;
+            }
+        }
+
         let crystal = crystal_match.unwrap();
 
         // Crystal count determines extraction efficiency (10% per crystal)
@@ -2961,21 +4969,35 @@ pub fn extract_packets_v2(
 
         // Find matching frequency in orb
         let available_sample = source.wave_packet_composition.iter()
-            .find(|s| (s.frequency - request.frequency).abs() < 0.001);
+            .find(|s| same_frequency(s.frequency, request.frequency));
 
         if let Some(sample) = available_sample {
-            if sample.count >= request.count {
+            // High-amplitude orbs yield bonus packets (amplitude 1.5 = 50% more);
+            // amplitudes at or below 1.0 yield the requested count
+            let amplitude_multiplier = sample.amplitude.max(1.0);
+
+            // Phase-matched crystals extract more efficiently (phase wraps like frequency)
+            let phase_multiplier = if frequency_distance(crystal.phase, sample.phase) <= PHASE_MATCH_WINDOW {
+                log::info!("  Phase match: crystal {:.3} vs orb {:.3} - {:.0}% bonus",
+                    crystal.phase, sample.phase, (PHASE_MATCH_BONUS - 1.0) * 100.0);
+                PHASE_MATCH_BONUS
+            } else {
+                1.0
+            };
+
+            let wanted = (request.count as f32 * amplitude_multiplier * phase_multiplier).round() as u32;
+
+            if sample.count >= wanted {
                 // Can fulfill this request
                 actual_extraction.push(WavePacketSample {
                     frequency: request.frequency,
                     amplitude: sample.amplitude,
                     phase: sample.phase,
-                    count: request.count,
+                    count: wanted,
                 });
-                total_to_extract += request.count;
 
-                log::info!("  Can extract {} packets of frequency {:.2}",
-                    request.count, request.frequency);
+                log::info!("  Can extract {} packets of frequency {:.2} (requested {}, amplitude {:.2})",
+                    wanted, request.frequency, request.count, sample.amplitude);
             } else if sample.count > 0 {
                 // Partial fulfillment
                 actual_extraction.push(WavePacketSample {
@@ -2984,10 +5006,9 @@ pub fn extract_packets_v2(
                     phase: sample.phase,
                     count: sample.count, // Give what we have
                 });
-                total_to_extract += sample.count;
 
-                log::info!("  Partial: requested {} but only {} available for frequency {:.2}",
-                    request.count, sample.count, request.frequency);
+                log::info!("  Partial: wanted {} but only {} available for frequency {:.2}",
+                    wanted, sample.count, request.frequency);
             } else {
                 log::info!("  Cannot extract frequency {:.2} - none available", request.frequency);
             }
@@ -2997,29 +5018,42 @@ pub fn extract_packets_v2(
     }
 
     if actual_extraction.is_empty() {
-        return Err("Cannot fulfill extraction request - no matching frequencies available".to_string());
+        return Err(GameError::InvalidState("Cannot fulfill extraction request - no matching frequencies available".to_string()).into());
     }
 
+    Ok((source, actual_extraction, current_time))
+}
+
+/// Remove a planned extraction from the orb and record it on the session and miner's stats
+/// Depleted orbs are deleted and end the session. Returns the updated orb and the miner
+fn commit_orb_extraction(
+    ctx: &ReducerContext,
+    session: MiningSession,
+    source: WavePacketSource,
+    actual_extraction: &[WavePacketSample],
+    current_time: u64,
+) -> Result<(WavePacketSource, Player), String> {
+    let total_to_extract = composition::total_count(actual_extraction);
+
     // Deduct from orb composition
     let mut updated_composition = source.wave_packet_composition.clone();
 
-    for extracted in &actual_extraction {
+    for extracted in actual_extraction {
         for sample in &mut updated_composition {
-            if (sample.frequency - extracted.frequency).abs() < 0.001 {
+            if same_frequency(sample.frequency, extracted.frequency) {
                 sample.count = sample.count.saturating_sub(extracted.count);
                 break;
             }
         }
     }
 
-    // Update orb
+    // Update orb - total follows the composition so it matches what was actually removed
     let mut updated_source = source.clone();
+    updated_source.total_wave_packets = composition::total_count(&updated_composition);
     updated_source.wave_packet_composition = updated_composition;
-    updated_source.total_wave_packets = updated_source.total_wave_packets.saturating_sub(total_to_extract);
     updated_source.last_depletion = current_time;
 
     // Save values we need before moving session
-    let session_source_id = session.source_id;
     let session_player_identity = session.player_identity;
 
     // Update mining session (do this before modifying orb/session state)
@@ -3028,7 +5062,8 @@ pub fn extract_packets_v2(
     updated_session.total_extracted += total_to_extract;
 
     // Check if orb is now empty
-    if updated_source.total_wave_packets == 0 {
+    let orb_depleted = updated_source.total_wave_packets == 0;
+    if orb_depleted {
         log::info!("Orb depleted, removing from world");
         updated_session.is_active = false;
         // Delete the depleted orb instead of updating it
@@ -3042,42 +5077,91 @@ pub fn extract_packets_v2(
     ctx.db.mining_session().delete(session);
     ctx.db.mining_session().insert(updated_session);
 
-    // Get player for visual packet creation
+    // Get the miner
     let player = ctx.db.player()
         .identity()
         .find(&session_player_identity)
-        .ok_or("Player not found")?;
+        .ok_or(GameError::NotFound("Player".to_string()))?;
 
-    // Create visual extraction record with EXACT requested composition
-    if !actual_extraction.is_empty() {
-        let packet_id = (session_id << 32) | (current_time & 0xFFFFFFFF);
-        let flight_time = 3000u64; // 3 seconds
+    if orb_depleted {
+        record_orb_depleted_event(ctx, &updated_source, Some(player.player_id));
+    }
 
-        let extraction = WavePacketExtraction {
-            extraction_id: 0, // auto_inc
-            player_id: player.player_id,
-            source_type: "orb".to_string(),
-            source_id: session_source_id,
-            packet_id,
-            composition: actual_extraction.clone(), // Exact composition extracted
-            total_count: total_to_extract,
-            departure_time: current_time,
-            expected_arrival: current_time + flight_time,
-        };
+    update_player_stats(ctx, player.player_id, |stats| {
+        stats.total_mined += total_to_extract as u64;
+    });
 
-        ctx.db.wave_packet_extraction().insert(extraction);
+    Ok((updated_source, player))
+}
 
-        log::info!("Created extraction record with {} total packets:", total_to_extract);
-        for sample in &actual_extraction {
-            log::info!("  Frequency {:.2}: {} packets", sample.frequency, sample.count);
-        }
+/// Extract from an orb the caller is mining straight into one of their storage devices
+/// Same rules and cooldown as extract_packets_v2, but instead of flying to the player the
+/// packets leave the orb as Orb→StorageDevice transfers along the sphere routes
+///
+/// # Arguments
+/// * `source_id` - Orb the caller has an active mining session on
+/// * `device_id` - Caller's storage device on the orb's world
+/// * `requested_frequencies` - What player wants to extract
+#[spacetimedb::reducer]
+pub fn mine_to_storage(
+    ctx: &ReducerContext,
+    source_id: u64,
+    device_id: u64,
+    requested_frequencies: Vec<ExtractionRequest>,
+) -> Result<(), String> {
+    log::info!("=== MINE_TO_STORAGE START ===");
+    log::info!("Source ID: {}, Device ID: {}, Request: {} frequencies",
+        source_id, device_id, requested_frequencies.len());
+
+    let session = ctx.db.mining_session()
+        .iter()
+        .find(|s| s.player_identity == ctx.sender && s.source_id == source_id && s.is_active)
+        .ok_or(GameError::NotFound("Active mining session on orb".to_string()))?;
 
+    let storage = find_owned_storage_device(ctx, device_id)?;
+
+    let (source, actual_extraction, current_time) = plan_orb_extraction(ctx, &session, &requested_frequencies)?;
+
+    // Sphere routes only exist within a single world
+    if storage.world_coords != source.world_coords {
+        return Err(GameError::InvalidInput("Storage device is on a different world".to_string()).into());
     }
 
-    log::info!("Extracted {} total packets (orb remaining: {})",
-        total_to_extract, updated_source.total_wave_packets);
-    log::info!("=== EXTRACT_PACKETS_V2 END ===");
+    // Validate the destination and route before taking anything from the orb
+    check_world_allows_composition(ctx, &storage.world_coords, &actual_extraction)?;
+    check_storage_room(&storage, &actual_extraction)?;
+    let spire_ids = build_sphere_route(ctx, source.position, storage.position, source.world_coords)?;
+
+    let origin = source.position;
+    let (updated_source, player) = commit_orb_extraction(ctx, session, source, &actual_extraction, current_time)?;
+
+    let batches = create_transfer_batches(&actual_extraction);
+    for batch_composition in &batches {
+        let mut transfer = pending_device_transfer(ctx, &player, origin, &storage, &spire_ids, batch_composition, 0);
+        transfer.source_object_type = "Orb".to_string();
+        transfer.source_object_id = source_id;
+        ctx.db.packet_transfer().insert(transfer);
+    }
+    wake_game_loop(ctx);
 
+    log::info!("Sent {} packets from orb {} to storage {} in {} transfers (orb remaining: {})",
+        composition::total_count(&actual_extraction), source_id, device_id, batches.len(),
+        updated_source.total_wave_packets);
+    log::info!("=== MINE_TO_STORAGE END ===");
+
+    Ok(())
+}
+
+/// Reject with CapacityExceeded unless the device has room for every sample in full
+fn check_storage_room(storage: &StorageDevice, samples: &[WavePacketSample]) -> Result<(), String> {
+    for sample in samples {
+        let current = stored_at_frequency(storage, sample.frequency);
+        let capacity = storage_capacity_for(storage, sample.frequency);
+        if current + sample.count > capacity {
+            return Err(GameError::CapacityExceeded(format!("Storage full for frequency {}: capacity {}, current {}, transfer {}",
+                sample.frequency, capacity, current, sample.count)).into());
+        }
+    }
     Ok(())
 }
 
@@ -3102,50 +5186,48 @@ pub fn capture_extracted_packet_v2(
     let extraction = ctx.db.wave_packet_extraction()
         .iter()
         .find(|e| e.packet_id == packet_id)
-        .ok_or("Extraction record not found")?;
+        .ok_or(GameError::NotFound("Extraction record".to_string()))?;
 
     // Verify it belongs to the caller
     let player = ctx.db.player()
         .identity()
         .find(&ctx.sender)
-        .ok_or("Player not found")?;
+        .ok_or(GameError::NotFound("Player".to_string()))?;
 
     if extraction.player_id != player.player_id {
-        return Err("This packet doesn't belong to you".to_string());
+        return Err(GameError::Unauthorized("This packet doesn't belong to you".to_string()).into());
     }
 
+    credit_extraction(ctx, &extraction)?;
+    ctx.db.wave_packet_extraction().delete(extraction);
+
+    log::info!("Captured packet {} for player {}", packet_id, player.player_id);
+    log::info!("=== CAPTURE_EXTRACTED_PACKET_V2 END ===");
+
+    Ok(())
+}
+
+/// Add an extraction's packets to its player's inventory (the record itself is left in place)
+/// Fails without changes if the inventory would exceed MAX_INVENTORY_PACKETS
+fn credit_extraction(ctx: &ReducerContext, extraction: &WavePacketExtraction) -> Result<(), String> {
+    let player_id = extraction.player_id;
+
     // Add packet composition to player inventory
     let inventory = ctx.db.player_inventory()
         .player_id()
-        .find(&player.player_id);
+        .find(&player_id);
 
     if let Some(mut inv) = inventory.clone() {
         // Merge extracted composition into inventory
-        for extracted_sample in &extraction.composition {
-            let freq_int = (extracted_sample.frequency * 100.0).round() as i32;
-            let mut found = false;
-
-            for inv_sample in inv.inventory_composition.iter_mut() {
-                let inv_freq_int = (inv_sample.frequency * 100.0).round() as i32;
-                if inv_freq_int == freq_int {
-                    inv_sample.count += extracted_sample.count;
-                    found = true;
-                    break;
-                }
-            }
-
-            // If frequency not in inventory, add new sample
-            if !found {
-                inv.inventory_composition.push(extracted_sample.clone());
-            }
-        }
+        composition::merge_compositions(&mut inv.inventory_composition, &extraction.composition);
 
         inv.total_count += extraction.total_count;
         inv.last_updated = ctx.timestamp;
 
         // Check max capacity
-        if inv.total_count > 300 {
-            return Err("Inventory full (max 300 packets)".to_string());
+        if inv.total_count > MAX_INVENTORY_PACKETS {
+            return Err(GameError::CapacityExceeded(format!(
+                "Inventory full (max {} packets)", MAX_INVENTORY_PACKETS)).into());
         }
 
         let new_total = inv.total_count;
@@ -3154,28 +5236,70 @@ pub fn capture_extracted_packet_v2(
         ctx.db.player_inventory().insert(inv);
 
         log::info!("Added {} packets to player {} inventory (new total: {})",
-            extraction.total_count, player.player_id, new_total);
+            extraction.total_count, player_id, new_total);
     } else {
         // Create new inventory if doesn't exist
         let new_inv = PlayerInventory {
-            player_id: player.player_id,
-            inventory_composition: extraction.composition.clone(),
+            player_id,
+            inventory_composition: composition::normalized(&extraction.composition),
             total_count: extraction.total_count,
             last_updated: ctx.timestamp,
         };
         ctx.db.player_inventory().insert(new_inv);
         log::info!("Created inventory for player {} with {} packets",
-            player.player_id, extraction.total_count);
+            player_id, extraction.total_count);
     }
 
-    ctx.db.wave_packet_extraction().delete(extraction);
+    Ok(())
+}
 
-    log::info!("Captured packet {} for player {}", packet_id, player.player_id);
-    log::info!("=== CAPTURE_EXTRACTED_PACKET_V2 END ===");
+/// Opt in or out of server-side capture of extractions once they reach expected_arrival
+#[spacetimedb::reducer]
+pub fn set_auto_capture(ctx: &ReducerContext, enabled: bool) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    if let Some(existing) = ctx.db.player_settings().player_id().find(&player.player_id) {
+        ctx.db.player_settings().delete(existing);
+    }
+    ctx.db.player_settings().insert(PlayerSettings {
+        player_id: player.player_id,
+        auto_capture: enabled,
+        updated_at: ctx.timestamp,
+    });
+    if enabled {
+        wake_game_loop(ctx);
+    }
 
+    log::info!("Player {} auto-capture {}", player.player_id, if enabled { "enabled" } else { "disabled" });
     Ok(())
 }
 
+/// Credit and remove arrived extractions for players with auto_capture on
+/// Extractions that don't fit are dropped with a warning rather than retried every tick
+fn process_auto_captures(ctx: &ReducerContext, current_time: u64) {
+    let due: Vec<WavePacketExtraction> = ctx.db.wave_packet_extraction()
+        .iter()
+        .filter(|e| current_time >= e.expected_arrival)
+        .filter(|e| ctx.db.player_settings().player_id().find(&e.player_id).map_or(false, |s| s.auto_capture))
+        .collect();
+
+    for extraction in due {
+        // A failed capture keeps its row so it can still be captured by hand
+        // (e.g. once inventory space frees up) until cleanup_old_extractions expires it
+        match credit_extraction(ctx, &extraction) {
+            Ok(()) => {
+                log::info!("Auto-captured packet {} for player {}", extraction.packet_id, extraction.player_id);
+                ctx.db.wave_packet_extraction().delete(extraction);
+            }
+            Err(e) => log::warn!("Auto-capture of packet {} for player {} failed, leaving it pending: {}",
+                extraction.packet_id, extraction.player_id, e),
+        }
+    }
+}
+
 /// NEW CONCURRENT MINING: Stop mining
 ///
 /// # Arguments
@@ -3196,11 +5320,11 @@ pub fn stop_mining_v2(
     let session = ctx.db.mining_session()
         .session_id()
         .find(&session_id)
-        .ok_or("Session not found")?;
+        .ok_or(GameError::NotFound("Session".to_string()))?;
 
     if session.player_identity != ctx.sender {
         log::warn!("Session does not belong to caller");
-        return Err("Session does not belong to you".to_string());
+        return Err(GameError::Unauthorized("Session does not belong to you".to_string()).into());
     }
 
     // Mark session as inactive
@@ -3273,6 +5397,174 @@ pub fn cleanup_my_mining_sessions(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Report an orb's current composition to the caller
+/// Writes a snapshot into orb_composition_report so clients can inspect
+/// a single orb without subscribing to the whole wave_packet_source table
+#[spacetimedb::reducer]
+pub fn inspect_orb(ctx: &ReducerContext, source_id: u64) -> Result<(), String> {
+    let source = ctx.db.wave_packet_source()
+        .source_id()
+        .find(&source_id)
+        .ok_or("Orb not found")?;
+
+    // Replace any previous report for this requester
+    if let Some(existing) = ctx.db.orb_composition_report().requester().find(&ctx.sender) {
+        ctx.db.orb_composition_report().delete(existing);
+    }
+
+    ctx.db.orb_composition_report().insert(OrbCompositionReport {
+        requester: ctx.sender,
+        source_id,
+        composition: source.wave_packet_composition.clone(),
+        total_wave_packets: source.total_wave_packets,
+        active_miner_count: source.active_miner_count,
+        reported_at: ctx.timestamp,
+    });
+
+    log::info!("Orb {} inspected by {:?}: {} packets, {} active miners",
+        source_id, ctx.sender, source.total_wave_packets, source.active_miner_count);
+
+    Ok(())
+}
+
+/// Resolve an orb's active mining sessions to player IDs and write them to orb_miners
+/// Also corrects the orb's active_miner_count if it has drifted from the session count
+#[spacetimedb::reducer]
+pub fn inspect_orb_miners(ctx: &ReducerContext, source_id: u64) -> Result<(), String> {
+    let source = ctx.db.wave_packet_source()
+        .source_id()
+        .find(&source_id)
+        .ok_or(GameError::NotFound("Orb".to_string()))?;
+
+    let miner_player_ids: Vec<u64> = ctx.db.mining_session()
+        .iter()
+        .filter(|s| s.source_id == source_id && s.is_active)
+        .filter_map(|s| ctx.db.player().identity().find(&s.player_identity))
+        .map(|p| p.player_id)
+        .collect();
+    let count = miner_player_ids.len() as u32;
+
+    if source.active_miner_count != count {
+        log::warn!("Orb {} active_miner_count {} disagrees with {} active sessions - correcting",
+            source_id, source.active_miner_count, count);
+        let mut updated_source = source.clone();
+        updated_source.active_miner_count = count;
+        ctx.db.wave_packet_source().delete(source);
+        ctx.db.wave_packet_source().insert(updated_source);
+    }
+
+    ctx.db.orb_miners().source_id().delete(&source_id);
+    ctx.db.orb_miners().insert(OrbMiners {
+        source_id,
+        miner_player_ids,
+        count,
+        updated_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Find the closest stationary orb with packets remaining in the caller's world
+/// Result is written to nearest_mineable_orb for the caller
+#[spacetimedb::reducer]
+pub fn find_nearest_orb(ctx: &ReducerContext) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let nearest = ctx.db.wave_packet_source()
+        .iter()
+        .filter(|s| s.world_coords == player.current_world &&
+                    s.state == SOURCE_STATE_STATIONARY &&
+                    s.total_wave_packets > 0)
+        .map(|s| (s.position.distance_to(&player.position), s))
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.nearest_mineable_orb().requester().find(&ctx.sender) {
+        ctx.db.nearest_mineable_orb().delete(existing);
+    }
+
+    let result = match nearest {
+        Some((distance, source)) => NearestMineableOrb {
+            requester: ctx.sender,
+            source_id: Some(source.source_id),
+            distance,
+            composition: source.wave_packet_composition,
+            refreshed_at: ctx.timestamp,
+        },
+        None => NearestMineableOrb {
+            requester: ctx.sender,
+            source_id: None,
+            distance: 0.0,
+            composition: Vec::new(),
+            refreshed_at: ctx.timestamp,
+        },
+    };
+    ctx.db.nearest_mineable_orb().insert(result);
+
+    Ok(())
+}
+
+/// Report how much of a frequency the stationary orbs in the caller's world hold
+/// Result is written to frequency_availability for the caller
+#[spacetimedb::reducer]
+pub fn can_mine_frequency(ctx: &ReducerContext, frequency: f32) -> Result<(), String> {
+    if !frequency.is_finite() {
+        return Err(GameError::InvalidInput("Frequency must be finite".to_string()).into());
+    }
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let sources: Vec<WavePacketSource> = ctx.db.wave_packet_source()
+        .iter()
+        .filter(|s| s.world_coords == player.current_world)
+        .collect();
+    let (source_count, total_packets) = frequency_availability_in(&sources, &player.current_world, frequency);
+
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.frequency_availability().requester().find(&ctx.sender) {
+        ctx.db.frequency_availability().delete(existing);
+    }
+
+    ctx.db.frequency_availability().insert(FrequencyAvailability {
+        requester: ctx.sender,
+        world_coords: player.current_world,
+        frequency: canonical_frequency(frequency),
+        available: total_packets > 0,
+        source_count,
+        total_packets,
+        checked_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Stationary orbs in a world holding a frequency, and how many packets of it they hold
+fn frequency_availability_in(sources: &[WavePacketSource], world_coords: &WorldCoords, frequency: f32) -> (u32, u32) {
+    let mut source_count = 0u32;
+    let mut total_packets = 0u32;
+    for source in sources
+        .iter()
+        .filter(|s| s.world_coords == *world_coords && s.state == SOURCE_STATE_STATIONARY)
+    {
+        let count: u32 = source.wave_packet_composition
+            .iter()
+            .filter(|sample| same_frequency(sample.frequency, frequency))
+            .map(|sample| sample.count)
+            .sum();
+        if count > 0 {
+            source_count += 1;
+            total_packets += count;
+        }
+    }
+    (source_count, total_packets)
+}
+
 // ============================================================================
 // NEW: Test Utility Reducers
 // ============================================================================
@@ -3281,6 +5573,8 @@ pub fn cleanup_my_mining_sessions(ctx: &ReducerContext) -> Result<(), String> {
 /// WARNING: Test only - removes all wave packet sources
 #[spacetimedb::reducer]
 pub fn clear_all_sources(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== CLEAR_ALL_SOURCES START ===");
 
     let sources: Vec<_> = ctx.db.wave_packet_source().iter().collect();
@@ -3300,6 +5594,8 @@ pub fn clear_all_sources(ctx: &ReducerContext) -> Result<(), String> {
 /// Useful for testing and cleanup
 #[spacetimedb::reducer]
 pub fn clear_all_storage_devices(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== CLEAR_ALL_STORAGE_DEVICES START ===");
 
     let devices: Vec<_> = ctx.db.storage_device().iter().collect();
@@ -3315,6 +5611,85 @@ pub fn clear_all_storage_devices(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// TESTING: Save a world's spheres, tunnels, circuits and sources into world_snapshot
+#[spacetimedb::reducer]
+pub fn export_world_snapshot(ctx: &ReducerContext, world_coords: WorldCoords) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== EXPORT_WORLD_SNAPSHOT START ===");
+
+    let snapshot = ctx.db.world_snapshot().insert(WorldSnapshot {
+        snapshot_id: 0, // auto_inc
+        world_coords,
+        spheres: ctx.db.distribution_sphere().iter().filter(|s| s.world_coords == world_coords).collect(),
+        tunnels: ctx.db.quantum_tunnel().iter().filter(|t| t.world_coords == world_coords).collect(),
+        circuits: ctx.db.world_circuit().iter().filter(|c| c.world_coords == world_coords).collect(),
+        sources: ctx.db.wave_packet_source().iter().filter(|s| s.world_coords == world_coords).collect(),
+        created_at: ctx.timestamp,
+    });
+
+    log::info!("Snapshot {} of world ({},{},{}): {} spheres, {} tunnels, {} circuits, {} sources",
+        snapshot.snapshot_id, world_coords.x, world_coords.y, world_coords.z,
+        snapshot.spheres.len(), snapshot.tunnels.len(), snapshot.circuits.len(), snapshot.sources.len());
+    log::info!("=== EXPORT_WORLD_SNAPSHOT END ===");
+
+    Ok(())
+}
+
+/// TESTING: Replace a world's spheres, tunnels, circuits and sources with a saved snapshot
+/// Rows keep their original IDs so routes and tunnel links stay valid
+#[spacetimedb::reducer]
+pub fn import_world_snapshot(ctx: &ReducerContext, snapshot_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== IMPORT_WORLD_SNAPSHOT START ===");
+
+    let snapshot = ctx.db.world_snapshot()
+        .snapshot_id()
+        .find(&snapshot_id)
+        .ok_or(GameError::NotFound("World snapshot".to_string()))?;
+    let world_coords = snapshot.world_coords;
+
+    // Clear current state for the world
+    let spheres: Vec<_> = ctx.db.distribution_sphere().iter().filter(|s| s.world_coords == world_coords).collect();
+    for sphere in spheres {
+        ctx.db.distribution_sphere().delete(sphere);
+    }
+    let tunnels: Vec<_> = ctx.db.quantum_tunnel().iter().filter(|t| t.world_coords == world_coords).collect();
+    for tunnel in tunnels {
+        ctx.db.quantum_tunnel().delete(tunnel);
+    }
+    let circuits: Vec<_> = ctx.db.world_circuit().iter().filter(|c| c.world_coords == world_coords).collect();
+    for circuit in circuits {
+        ctx.db.world_circuit().delete(circuit);
+    }
+    let sources: Vec<_> = ctx.db.wave_packet_source().iter().filter(|s| s.world_coords == world_coords).collect();
+    for source in sources {
+        ctx.db.wave_packet_source().delete(source);
+    }
+
+    // Recreate from the snapshot
+    for sphere in snapshot.spheres.iter().cloned() {
+        ctx.db.distribution_sphere().insert(sphere);
+    }
+    for tunnel in snapshot.tunnels.iter().cloned() {
+        ctx.db.quantum_tunnel().insert(tunnel);
+    }
+    for circuit in snapshot.circuits.iter().cloned() {
+        ctx.db.world_circuit().insert(circuit);
+    }
+    for source in snapshot.sources.iter().cloned() {
+        ctx.db.wave_packet_source().insert(source);
+    }
+
+    log::info!("Restored snapshot {} to world ({},{},{}): {} spheres, {} tunnels, {} circuits, {} sources",
+        snapshot_id, world_coords.x, world_coords.y, world_coords.z,
+        snapshot.spheres.len(), snapshot.tunnels.len(), snapshot.circuits.len(), snapshot.sources.len());
+    log::info!("=== IMPORT_WORLD_SNAPSHOT END ===");
+
+    Ok(())
+}
+
 /// TESTING: Set an orb's packet count instantly
 /// Useful for testing depletion scenarios
 #[spacetimedb::reducer]
@@ -3323,6 +5698,8 @@ pub fn set_orb_packets(
     source_id: u64,
     new_count: u32,
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SET_ORB_PACKETS START ===");
     log::info!("Orb ID: {}, New count: {}", source_id, new_count);
 
@@ -3348,6 +5725,116 @@ pub fn set_orb_packets(
     Ok(())
 }
 
+/// ADMIN: Change an orb's lifetime, optionally restarting its clock from now
+/// expiring_soon is recomputed so the client fade-out follows the new end of life
+#[spacetimedb::reducer]
+pub fn set_orb_lifetime(
+    ctx: &ReducerContext,
+    source_id: u64,
+    lifetime_ms: u32,
+    reset_clock: bool,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== SET_ORB_LIFETIME START ===");
+    log::info!("Orb ID: {}, Lifetime: {}ms, Reset clock: {}", source_id, lifetime_ms, reset_clock);
+
+    let source = ctx.db.wave_packet_source()
+        .source_id()
+        .find(&source_id)
+        .ok_or(GameError::NotFound("Orb".to_string()))?;
+
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+
+    let mut updated = source.clone();
+    updated.lifetime_ms = lifetime_ms;
+    if reset_clock {
+        updated.creation_time = current_time;
+    }
+    let expires_at = updated.creation_time + lifetime_ms as u64;
+    updated.expiring_soon = source_expiring_soon(&updated, current_time);
+
+    ctx.db.wave_packet_source().delete(source);
+    ctx.db.wave_packet_source().insert(updated);
+
+    log::info!("Orb {} now expires at {} ({}ms from now)",
+        source_id, expires_at, expires_at.saturating_sub(current_time));
+    log::info!("=== SET_ORB_LIFETIME END ===");
+
+    Ok(())
+}
+
+/// TESTING: Replace an orb's whole composition
+/// total_wave_packets is recomputed from the sample counts so the two stay consistent
+#[spacetimedb::reducer]
+pub fn set_orb_composition(
+    ctx: &ReducerContext,
+    source_id: u64,
+    composition: Vec<WavePacketSample>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== SET_ORB_COMPOSITION START ===");
+    log::info!("Orb ID: {}, Composition: {:?}", source_id, composition);
+
+    if composition.iter().any(|s| !s.frequency.is_finite()) {
+        return Err(GameError::InvalidInput("Composition frequencies must be finite".to_string()).into());
+    }
+
+    let source = ctx.db.wave_packet_source()
+        .source_id()
+        .find(&source_id)
+        .ok_or(GameError::NotFound("Orb".to_string()))?;
+
+    // Merge duplicate frequencies and drop empty samples
+    let mut merged = Vec::new();
+    composition::merge_compositions(&mut merged, &composition);
+    merged.retain(|s| s.count > 0);
+
+    let mut updated = source.clone();
+    updated.total_wave_packets = composition::total_count(&merged);
+    updated.wave_packet_composition = merged;
+
+    log::info!("Set orb {} to {} packets across {} frequencies",
+        source_id, updated.total_wave_packets, updated.wave_packet_composition.len());
+
+    ctx.db.wave_packet_source().delete(source);
+    ctx.db.wave_packet_source().insert(updated);
+
+    log::info!("=== SET_ORB_COMPOSITION END ===");
+
+    Ok(())
+}
+
+/// ADMIN: Re-project an orb onto its world's surface at the height for its current state
+/// Corrects accumulated floating-point drift off the sphere surface
+#[spacetimedb::reducer]
+pub fn resurface_orb(ctx: &ReducerContext, source_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let source = ctx.db.wave_packet_source()
+        .source_id()
+        .find(&source_id)
+        .ok_or(GameError::NotFound("Orb".to_string()))?;
+
+    let radii = world_radii(ctx, &source.world_coords);
+    match resurface_source(&source, &radii, 0.0) {
+        Some(updated) => {
+            log::info!("Resurfaced orb {}: radius {:.4} -> {:.4}",
+                source_id, source.position.magnitude(), updated.position.magnitude());
+            ctx.db.wave_packet_source().delete(source);
+            ctx.db.wave_packet_source().insert(updated);
+        }
+        None => log::info!("Orb {} already on surface (radius {:.4})",
+            source_id, source.position.magnitude()),
+    }
+
+    Ok(())
+}
+
 /// TESTING: List all active mining sessions
 /// Debug reducer to see who is mining what
 #[spacetimedb::reducer]
@@ -3410,36 +5897,11 @@ fn deduct_composition_from_inventory(ctx: &ReducerContext, player_id: u64, compo
         .find(&player_id)
         .ok_or("Player inventory not found")?;
 
-    let mut new_composition = inventory.inventory_composition.clone();
-    let mut new_total = inventory.total_count;
-    
     // Deduct each requested frequency/count from inventory composition
-    for requested in composition {
-        let freq_int = (requested.frequency * 100.0).round() as i32;
-        let mut found = false;
-        
-        for inv_sample in new_composition.iter_mut() {
-            let inv_freq_int = (inv_sample.frequency * 100.0).round() as i32;
-            if inv_freq_int == freq_int {
-                if inv_sample.count < requested.count {
-                    return Err(format!("Insufficient inventory for frequency {}: have {}, need {}", 
-                        requested.frequency, inv_sample.count, requested.count));
-                }
-                inv_sample.count -= requested.count;
-                new_total -= requested.count;
-                found = true;
-                break;
-            }
-        }
-        
-        if !found {
-            return Err(format!("Frequency {} not found in inventory", requested.frequency));
-        }
-    }
-    
-    // Remove samples with zero count
-    new_composition.retain(|s| s.count > 0);
-    
+    let mut new_composition = inventory.inventory_composition.clone();
+    composition::subtract_composition(&mut new_composition, composition)?;
+    let new_total = inventory.total_count - composition::total_count(composition);
+
     let updated = PlayerInventory {
         player_id,
         inventory_composition: new_composition,
@@ -3454,9 +5916,10 @@ fn deduct_composition_from_inventory(ctx: &ReducerContext, player_id: u64, compo
     Ok(())
 }
 
-/// Find nearest energy spire to a position on a world
-fn find_nearest_spire(ctx: &ReducerContext, world_coords: WorldCoords, position: DbVector3) -> Result<DistributionSphere, String> {
-    let spires: Vec<_> = ctx.db.distribution_sphere()
+/// All distribution spheres on a world, collected in one table scan
+/// Callers resolving several positions should scan once and reuse the result
+fn world_spires(ctx: &ReducerContext, world_coords: WorldCoords) -> Result<Vec<DistributionSphere>, String> {
+    let spires: Vec<DistributionSphere> = ctx.db.distribution_sphere()
         .iter()
         .filter(|s| s.world_coords == world_coords)
         .collect();
@@ -3464,23 +5927,50 @@ fn find_nearest_spire(ctx: &ReducerContext, world_coords: WorldCoords, position:
     if spires.is_empty() {
         return Err(format!("No distribution spheres found on world ({}, {}, {})", world_coords.x, world_coords.y, world_coords.z));
     }
+    Ok(spires)
+}
+
+/// Nearest sphere to a position among an already-scanned set
+/// Compares squared distances; a single sphere is returned without comparing
+fn nearest_spire_in(spires: &[DistributionSphere], position: DbVector3) -> Option<&DistributionSphere> {
+    if spires.len() == 1 {
+        return spires.first();
+    }
 
-    let mut nearest: Option<DistributionSphere> = None;
-    let mut nearest_dist = f32::MAX;
+    let dist_sq = |s: &DistributionSphere| {
+        let dx = s.sphere_position.x - position.x;
+        let dy = s.sphere_position.y - position.y;
+        let dz = s.sphere_position.z - position.z;
+        dx*dx + dy*dy + dz*dz
+    };
+
+    spires.iter().min_by(|a, b| dist_sq(a).total_cmp(&dist_sq(b)))
+}
 
-    for spire in spires {
-        let dx = spire.sphere_position.x - position.x;
-        let dy = spire.sphere_position.y - position.y;
-        let dz = spire.sphere_position.z - position.z;
-        let dist = (dx*dx + dy*dy + dz*dz).sqrt();
+/// Ordered sphere IDs from the sphere nearest start_pos to the sphere nearest end_pos
+/// Intermediate spheres come from the routing table, so every SphereToSphere hop
+/// is between neighbors (within MAX_NEIGHBOR_DISTANCE)
+fn build_sphere_route(ctx: &ReducerContext, start_pos: DbVector3, end_pos: DbVector3, world: WorldCoords) -> Result<Vec<u64>, String> {
+    // One scan of the world's spheres serves both endpoints
+    let spires = world_spires(ctx, world)?;
+    let start_sphere = nearest_spire_in(&spires, start_pos).ok_or("Failed to find nearest sphere".to_string())?;
+    let end_sphere = nearest_spire_in(&spires, end_pos).ok_or("Failed to find nearest sphere".to_string())?;
 
-        if dist < nearest_dist {
-            nearest_dist = dist;
-            nearest = Some(spire.clone());
-        }
+    if start_sphere.sphere_id == end_sphere.sphere_id {
+        // Same sphere - just one hop
+        return Ok(vec![start_sphere.sphere_id]);
     }
 
-    nearest.ok_or("Failed to find nearest sphere".to_string())
+    let route = get_sphere_route(ctx, world, start_sphere.sphere_id, end_sphere.sphere_id);
+    if route.last() != Some(&end_sphere.sphere_id) {
+        return Err(GameError::InvalidState(format!("No sphere route from {} to {}",
+            start_sphere.sphere_id, end_sphere.sphere_id)).into());
+    }
+
+    log::info!("[Routing] Route from sphere {} to sphere {}: {:?} ({} hops)",
+        start_sphere.sphere_id, end_sphere.sphere_id, route, route.len());
+
+    Ok(route)
 }
 
 // ============================================================================
@@ -3518,9 +6008,27 @@ pub fn initialize_player_inventory(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Create an empty inventory for a player unless one already exists
+/// Returns true if a new inventory row was inserted
+fn create_inventory_if_missing(ctx: &ReducerContext, player_id: u64) -> bool {
+    if ctx.db.player_inventory().player_id().find(&player_id).is_some() {
+        return false;
+    }
+
+    ctx.db.player_inventory().insert(PlayerInventory {
+        player_id,
+        inventory_composition: Vec::new(),
+        total_count: 0,
+        last_updated: ctx.timestamp,
+    });
+    log::info!("Auto-created empty inventory for player {}", player_id);
+    true
+}
+
 /// Ensure player has an inventory, creating one if it doesn't exist
 /// This is a safe version that doesn't error if inventory already exists
-/// EXCEPTION USE ONLY: Should be called by UI as a fallback, not during normal gameplay
+/// EXCEPTION USE ONLY: create_player now creates the inventory, so this is only a
+/// fallback for players created before that
 #[spacetimedb::reducer]
 pub fn ensure_player_inventory(ctx: &ReducerContext) -> Result<(), String> {
     let player = ctx.db.player()
@@ -3528,22 +6036,7 @@ pub fn ensure_player_inventory(ctx: &ReducerContext) -> Result<(), String> {
         .find(&ctx.sender)
         .ok_or("Player not found")?;
 
-    // Check if inventory already exists
-    if ctx.db.player_inventory().player_id().find(&player.player_id).is_some() {
-        // Inventory exists, nothing to do
-        return Ok(());
-    }
-
-    // Create empty inventory
-    let inventory = PlayerInventory {
-        player_id: player.player_id,
-        inventory_composition: Vec::new(),
-        total_count: 0,
-        last_updated: ctx.timestamp,
-    };
-
-    ctx.db.player_inventory().insert(inventory);
-    log::info!("Auto-created empty inventory for player {}", player.player_id);
+    create_inventory_if_missing(ctx, player.player_id);
 
     Ok(())
 }
@@ -3559,6 +6052,8 @@ pub fn debug_add_test_packets(
     blue: u32,
     magenta: u32
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_ADD_TEST_PACKETS START ===");
 
     let player = ctx.db.player()
@@ -3625,24 +6120,7 @@ pub fn debug_add_test_packets(
 
     if let Some(mut inv) = inventory_opt.clone() {
         // Merge composition into inventory
-        for new_sample in &composition {
-            let freq_int = (new_sample.frequency * 100.0).round() as i32;
-            let mut found = false;
-
-            for inv_sample in inv.inventory_composition.iter_mut() {
-                let inv_freq_int = (inv_sample.frequency * 100.0).round() as i32;
-                if inv_freq_int == freq_int {
-                    inv_sample.count += new_sample.count;
-                    found = true;
-                    break;
-                }
-            }
-
-            // If frequency not in inventory, add new sample
-            if !found {
-                inv.inventory_composition.push(new_sample.clone());
-            }
-        }
+        composition::merge_compositions(&mut inv.inventory_composition, &composition);
 
         inv.total_count += total_count;
         inv.last_updated = ctx.timestamp;
@@ -3663,7 +6141,7 @@ pub fn debug_add_test_packets(
         // Create new inventory
         let new_inv = PlayerInventory {
             player_id: player.player_id,
-            inventory_composition: composition,
+            inventory_composition: composition::normalized(&composition),
             total_count,
             last_updated: ctx.timestamp,
         };
@@ -3679,6 +6157,7 @@ pub fn debug_add_test_packets(
 
 /// Helper function to split large transfer compositions into batches
 /// Each batch has max 5 packets per frequency and max 30 packets total
+/// Input is normalized first, so near-duplicate frequencies count against one limit
 fn create_transfer_batches(composition: &[WavePacketSample]) -> Vec<Vec<WavePacketSample>> {
     const MAX_PER_FREQUENCY: u32 = 5;
     const MAX_TOTAL_PER_BATCH: u32 = 30;
@@ -3686,15 +6165,16 @@ fn create_transfer_batches(composition: &[WavePacketSample]) -> Vec<Vec<WavePack
     let mut batches: Vec<Vec<WavePacketSample>> = Vec::new();
     let mut current_batch: Vec<WavePacketSample> = Vec::new();
     let mut current_batch_total: u32 = 0;
-    let mut freq_count_in_batch: std::collections::HashMap<i32, u32> = std::collections::HashMap::new();
 
-    for sample in composition {
+    for sample in &composition::normalized(composition) {
         let mut remaining = sample.count;
 
         while remaining > 0 {
             // Check how much of this frequency is already in the current batch
-            let freq_int = (sample.frequency * 100.0).round() as i32;
-            let freq_in_batch = freq_count_in_batch.get(&freq_int).copied().unwrap_or(0);
+            let freq_in_batch: u32 = current_batch.iter()
+                .filter(|s| same_frequency(s.frequency, sample.frequency))
+                .map(|s| s.count)
+                .sum();
             
             // Calculate how much we can add (respecting per-frequency limit)
             let can_add_by_frequency = MAX_PER_FREQUENCY.saturating_sub(freq_in_batch).min(remaining);
@@ -3705,7 +6185,6 @@ fn create_transfer_batches(composition: &[WavePacketSample]) -> Vec<Vec<WavePack
                 batches.push(current_batch);
                 current_batch = Vec::new();
                 current_batch_total = 0;
-                freq_count_in_batch.clear();
                 continue;
             }
 
@@ -3717,7 +6196,6 @@ fn create_transfer_batches(composition: &[WavePacketSample]) -> Vec<Vec<WavePack
             });
 
             current_batch_total += to_add;
-            *freq_count_in_batch.entry(freq_int).or_insert(0) += to_add;
             remaining -= to_add;
         }
     }
@@ -3732,160 +6210,347 @@ fn create_transfer_batches(composition: &[WavePacketSample]) -> Vec<Vec<WavePack
 /// Initiate energy packet transfer from player to storage device
 /// Routes through nearest energy spires
 /// AUTO-BATCHES large requests: max 5 per frequency, 30 total per batch
+/// Optional priority (default 0): higher-priority transfers depart a queued source first
+/// Optional partial_fill (default false): send what fits per frequency instead of rejecting,
+/// keeping the rest in inventory and reporting it in transfer_remainder
 #[spacetimedb::reducer]
-pub fn initiate_transfer(ctx: &ReducerContext, composition: Vec<WavePacketSample>, destination_device_id: u64) -> Result<(), String> {
+pub fn initiate_transfer(ctx: &ReducerContext, composition: Vec<WavePacketSample>, destination_device_id: u64, priority: Option<u8>, partial_fill: Option<bool>) -> Result<(), String> {
     log::info!("=== INITIATE_TRANSFER START ===");
-    log::info!("Composition: {:?}, Destination: {}", composition, destination_device_id);
-
-    // Calculate total for logging
-    let total_requested: u32 = composition.iter().map(|s| s.count).sum();
-
-    // AUTO-BATCH: Split large requests into multiple transfers
-    let batches = create_transfer_batches(&composition);
-    log::info!("Total packets: {}, split into {} batches", total_requested, batches.len());
+    log::info!("Composition: {:?}, Destination: {}, Priority: {:?}, Partial fill: {:?}",
+        composition, destination_device_id, priority, partial_fill);
+    let priority = priority.unwrap_or(0);
+    let partial_fill = partial_fill.unwrap_or(false);
 
     // Get player
         let player = ctx.db.player()
             .identity()
             .find(&ctx.sender)
-            .ok_or("Player not found")?;
+            .ok_or(GameError::NotFound("Player".to_string()))?;
+
+        // Validate the whole request before creating any batch - all or nothing,
+        // unless partial_fill trims it down to what the device can hold
+        let (storage, spire_ids, accepted) = check_device_transfer(ctx, &player, &composition, destination_device_id, partial_fill)?;
 
-        // Get storage device
-        let storage = ctx.db.storage_device()
-            .device_id()
-            .find(&destination_device_id)
-            .ok_or("Storage device not found")?;
+        if partial_fill {
+            let mut remainder = composition::normalized(&composition);
+            composition::subtract_composition(&mut remainder, &accepted)?;
+            log::info!("Partial fill: sending {} packets, {} left in inventory",
+                composition::total_count(&accepted), composition::total_count(&remainder));
 
-        // Verify ownership
-        if storage.owner_player_id != player.player_id {
-            return Err("Not your storage device".to_string());
+            // Replace any previous remainder for this requester
+            if let Some(existing) = ctx.db.transfer_remainder().requester().find(&ctx.sender) {
+                ctx.db.transfer_remainder().delete(existing);
+            }
+            ctx.db.transfer_remainder().insert(TransferRemainder {
+                requester: ctx.sender,
+                destination_device_id,
+                remainder,
+                reported_at: ctx.timestamp,
+            });
         }
 
+        // Calculate total for logging
+        let total_requested = composition::total_count(&accepted);
+
+        // AUTO-BATCH: Split large requests into multiple transfers
+        let batches = create_transfer_batches(&accepted);
+        log::info!("Total packets: {}, split into {} batches", total_requested, batches.len());
+
         // Process each batch as a separate transfer
         let mut transfers_created = 0u32;
         for (batch_index, batch_composition) in batches.iter().enumerate() {
-            let batch_total: u32 = batch_composition.iter().map(|s| s.count).sum();
+            let batch_total = composition::total_count(batch_composition);
             log::info!("Processing batch {}/{}: {} packets", batch_index + 1, batches.len(), batch_total);
 
-            // Check inventory has enough of each frequency in this batch
-            let inventory = ctx.db.player_inventory()
-                .player_id()
-                .find(&player.player_id)
-                .ok_or("Player inventory not found")?;
-
-            for sample in batch_composition {
-                let freq_int = (sample.frequency * 100.0).round() as i32;
-                let mut found = false;
-                for inv_sample in &inventory.inventory_composition {
-                    let inv_freq_int = (inv_sample.frequency * 100.0).round() as i32;
-                    if inv_freq_int == freq_int {
-                        if inv_sample.count < sample.count {
-                            return Err(format!("Insufficient inventory for frequency {}: have {}, need {}", 
-                                sample.frequency, inv_sample.count, sample.count));
-                        }
-                        found = true;
-                        break;
-                    }
-                }
-                if !found {
-                    return Err(format!("Frequency {} not found in inventory", sample.frequency));
-                }
-            }
+            queue_device_transfer(ctx, &player, &storage, &spire_ids, batch_composition, priority)?;
+            transfers_created += 1;
 
-            // Check storage capacity
-            let mut storage_totals: std::collections::HashMap<i32, u32> = std::collections::HashMap::new();
-            for sample in &storage.stored_composition {
-                let freq_int = (sample.frequency * 100.0).round() as i32;
-                *storage_totals.entry(freq_int).or_insert(0) += sample.count;
-            }
-            for sample in batch_composition {
-                let freq_int = (sample.frequency * 100.0).round() as i32;
-                let current = storage_totals.get(&freq_int).copied().unwrap_or(0);
-                if current + sample.count > storage.capacity_per_frequency {
-                    return Err(format!("Storage full for frequency {}: capacity {}, current {}, transfer {}", 
-                        sample.frequency, storage.capacity_per_frequency, current, sample.count));
-                }
+            log::info!("Batch {} transfer created: {} packets routed through {} spires",
+                batch_index + 1, batch_total, spire_ids.len());
+        }
+
+        log::info!("Transfer complete: {} total packets in {} transfer records", total_requested, transfers_created);
+        log::info!("=== INITIATE_TRANSFER END ===");
+
+        Ok(())
+}
+
+/// Every check initiate_transfer makes before deducting anything: device ownership and world,
+/// sphere route, inventory, allowed frequencies and storage capacity
+/// Returns the destination device, the route and the composition to send on success.
+/// With partial_fill each frequency is capped at the device's free space instead of
+/// failing; it still fails if nothing fits at all.
+fn check_device_transfer(
+    ctx: &ReducerContext,
+    player: &Player,
+    composition: &[WavePacketSample],
+    destination_device_id: u64,
+    partial_fill: bool,
+) -> Result<(StorageDevice, Vec<u64>, Vec<WavePacketSample>), String> {
+    // Get storage device
+    let storage = ctx.db.storage_device()
+        .device_id()
+        .find(&destination_device_id)
+        .ok_or(GameError::NotFound("Storage device".to_string()))?;
+
+    // Verify ownership
+    if storage.owner_player_id != player.player_id {
+        return Err(GameError::Unauthorized("Not your storage device".to_string()).into());
+    }
+
+    // Sphere routes only exist within a single world
+    if storage.world_coords != player.current_world {
+        return Err(GameError::InvalidInput("Storage device is on a different world".to_string()).into());
+    }
+
+    // Resolve the route before touching inventory - a world without spheres
+    // fails here and leaves the player's inventory untouched
+    let spire_ids = build_sphere_route(ctx, player.position, storage.position, player.current_world)?;
+
+    let mut requested = Vec::new();
+    composition::merge_compositions(&mut requested, composition);
+
+    let inventory = ctx.db.player_inventory()
+        .player_id()
+        .find(&player.player_id)
+        .ok_or(GameError::NotFound("Player inventory".to_string()))?;
+
+    composition::subtract_composition(&mut inventory.inventory_composition.clone(), &requested)
+        .map_err(GameError::InvalidInput)?;
+
+    check_world_allows_composition(ctx, &storage.world_coords, &requested)?;
+
+    let mut accepted = Vec::new();
+    for sample in &requested {
+        let current = stored_at_frequency(&storage, sample.frequency);
+        let capacity = storage_capacity_for(&storage, sample.frequency);
+        if current + sample.count <= capacity {
+            accepted.push(sample.clone());
+        } else if partial_fill {
+            let fits = capacity.saturating_sub(current);
+            if fits > 0 {
+                accepted.push(WavePacketSample { count: fits, ..sample.clone() });
             }
+        } else {
+            return Err(GameError::CapacityExceeded(format!("Storage full for frequency {}: capacity {}, current {}, transfer {}",
+                sample.frequency, capacity, current, sample.count)).into());
+        }
+    }
 
-            // Find nearest spires to player and storage
-            let player_spire = find_nearest_spire(ctx, player.current_world, player.position)?;
-            let storage_spire = find_nearest_spire(ctx, storage.world_coords, storage.position)?;
+    if partial_fill && accepted.is_empty() {
+        return Err(GameError::CapacityExceeded("Storage full for every requested frequency".to_string()).into());
+    }
 
-            // Build full route through sphere network using Floyd-Warshall precomputed paths
-            let mut waypoints = vec![player.position.clone()];
-            let spire_ids: Vec<u64>;
+    Ok((storage, spire_ids, accepted))
+}
 
-            if player_spire.sphere_id == storage_spire.sphere_id {
-                // Same sphere - just one hop
-                spire_ids = vec![player_spire.sphere_id];
-                waypoints.push(player_spire.sphere_position.clone());
-            } else {
-                // Get full route through network
-                spire_ids = get_sphere_route(ctx, player.current_world, player_spire.sphere_id, storage_spire.sphere_id);
+/// Run initiate_transfer's checks without deducting or queueing anything
+/// Pass/fail and the failure reason are written to transfer_validation for the caller
+#[spacetimedb::reducer]
+pub fn validate_transfer(ctx: &ReducerContext, composition: Vec<WavePacketSample>, destination_device_id: u64) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
 
-                log::info!("[Routing] Route from sphere {} to sphere {}: {:?} ({} hops)",
-                    player_spire.sphere_id, storage_spire.sphere_id, spire_ids, spire_ids.len());
+    let (valid, reason) = match check_device_transfer(ctx, &player, &composition, destination_device_id, false) {
+        Ok(_) => (true, String::new()),
+        Err(reason) => (false, reason),
+    };
 
-                // Add waypoint positions for each sphere in route
-                for &sphere_id in &spire_ids {
-                    if let Some(pos) = get_sphere_position_from_table(ctx, player.current_world, sphere_id) {
-                        waypoints.push(pos);
-                    } else {
-                        // Fallback to DB lookup if not in table
-                        if let Some(sphere) = ctx.db.distribution_sphere().sphere_id().find(&sphere_id) {
-                            waypoints.push(sphere.sphere_position.clone());
-                        } else {
-                            log::warn!("[Routing] Sphere {} not found, skipping", sphere_id);
-                        }
-                    }
-                }
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.transfer_validation().requester().find(&ctx.sender) {
+        ctx.db.transfer_validation().delete(existing);
+    }
+
+    ctx.db.transfer_validation().insert(TransferValidation {
+        requester: ctx.sender,
+        destination_device_id,
+        composition,
+        valid,
+        reason,
+        validated_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Deduct a batch from the player's inventory and queue a Player→StorageDevice transfer for it
+/// along a route from build_sphere_route. Capacity checks are the caller's job.
+fn queue_device_transfer(
+    ctx: &ReducerContext,
+    player: &Player,
+    storage: &StorageDevice,
+    spire_ids: &[u64],
+    batch_composition: &Vec<WavePacketSample>,
+    priority: u8,
+) -> Result<(), String> {
+    let transfer = pending_device_transfer(ctx, player, player.position, storage, spire_ids, batch_composition, priority);
+
+    // Deduct from inventory
+    deduct_composition_from_inventory(ctx, player.player_id, batch_composition)?;
+
+    ctx.db.packet_transfer().insert(transfer);
+    wake_game_loop(ctx);
+
+    Ok(())
+}
+
+/// Pending Player→StorageDevice transfer record leaving from origin, not yet inserted
+/// Callers sending from another object overwrite the source_object fields
+fn pending_device_transfer(
+    ctx: &ReducerContext,
+    player: &Player,
+    origin: DbVector3,
+    storage: &StorageDevice,
+    spire_ids: &[u64],
+    batch_composition: &[WavePacketSample],
+    priority: u8,
+) -> PacketTransfer {
+    let mut waypoints = vec![origin];
+    for &sphere_id in spire_ids {
+        if let Some(pos) = get_sphere_position_from_table(ctx, storage.world_coords, sphere_id) {
+            waypoints.push(pos);
+        } else {
+            // Fallback to DB lookup if not in table
+            if let Some(sphere) = ctx.db.distribution_sphere().sphere_id().find(&sphere_id) {
+                waypoints.push(sphere.sphere_position.clone());
+            } else {
+                log::warn!("[Routing] Sphere {} not found, skipping", sphere_id);
             }
+        }
+    }
 
-            // Add final destination
-            waypoints.push(storage.position.clone());
+    // Add final destination
+    waypoints.push(storage.position.clone());
 
-            // Deduct from inventory
-            deduct_composition_from_inventory(ctx, player.player_id, &batch_composition)?;
+    // Transfer record in pending state (will be departed by two_second_pulse)
+    PacketTransfer {
+        transfer_id: 0,
+        player_id: player.player_id,
+        composition: batch_composition.to_vec(),
+        packet_count: composition::total_count(batch_composition),
+        route_waypoints: waypoints,
+        route_spire_ids: spire_ids.to_vec(),
+        destination_device_id: storage.device_id,
+        initiated_at: ctx.timestamp,
+        completed: false,
+        current_leg: 0,
+        leg_start_time: ctx.timestamp,
+        state: "PlayerPulse".to_string(),
+        source_object_type: "Player".to_string(),
+        source_object_id: player.player_id,
+        destination_object_type: "StorageDevice".to_string(),
+        destination_object_id: storage.device_id,
+        current_leg_type: "PendingAtObject".to_string(),
+        predicted_arrival_time: Timestamp::UNIX_EPOCH,
+        priority,
+        world_coords: storage.world_coords,
+    }
+}
+
+/// Spread a composition across several of the caller's storage devices,
+/// filling each device (in the given order) before moving to the next
+/// Rejects the whole request if the devices can't hold it all
+#[spacetimedb::reducer]
+pub fn distribute_to_devices(ctx: &ReducerContext, composition: Vec<WavePacketSample>, device_ids: Vec<u64>) -> Result<(), String> {
+    log::info!("=== DISTRIBUTE_TO_DEVICES START ===");
+    log::info!("Composition: {:?}, Devices: {:?}", composition, device_ids);
 
-            // Create transfer record in pending state (will be departed by two_second_pulse)
-            let transfer = PacketTransfer {
-                transfer_id: 0,
-                player_id: player.player_id,
-                composition: batch_composition.clone(),
-                packet_count: batch_total,
-                route_waypoints: waypoints.clone(),
-                route_spire_ids: spire_ids.clone(),
-                destination_device_id,
-                initiated_at: ctx.timestamp,
-                completed: false,
-                current_leg: 0,
-                leg_start_time: ctx.timestamp,
-                state: "PlayerPulse".to_string(),
-                source_object_type: "Player".to_string(),
-                source_object_id: player.player_id,
-                destination_object_type: "StorageDevice".to_string(),
-                destination_object_id: destination_device_id,
-                current_leg_type: "PendingAtObject".to_string(),
-                predicted_arrival_time: Timestamp::UNIX_EPOCH,
-            };
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
 
-            ctx.db.packet_transfer().insert(transfer);
-            transfers_created += 1;
+    if device_ids.is_empty() {
+        return Err(GameError::InvalidInput("No storage devices given".to_string()).into());
+    }
 
-            log::info!("Batch {} transfer created: {} packets routed through {} spires",
-                batch_index + 1, batch_total, spire_ids.len());
+    let mut devices: Vec<StorageDevice> = Vec::new();
+    for device_id in device_ids {
+        if devices.iter().any(|d| d.device_id == device_id) {
+            continue;
+        }
+        let device = find_owned_storage_device(ctx, device_id)?;
+        if device.world_coords != player.current_world {
+            return Err(GameError::InvalidInput(format!("Storage device {} is on a different world", device_id)).into());
         }
+        devices.push(device);
+    }
 
-        log::info!("Transfer complete: {} total packets in {} transfer records", total_requested, transfers_created);
-        log::info!("=== INITIATE_TRANSFER END ===");
+    let requested = normalize_trade_composition(&composition)?;
+    if requested.is_empty() {
+        return Err(GameError::InvalidInput("Composition is empty".to_string()).into());
+    }
+    if !inventory_covers(ctx, player.player_id, &requested) {
+        return Err(GameError::InvalidInput("Insufficient inventory for distribution".to_string()).into());
+    }
+    check_world_allows_composition(ctx, &player.current_world, &requested)?;
 
-        Ok(())
+    let allocations = allocate_to_devices(&devices, &requested)?;
+
+    // Resolve every route before touching inventory
+    let mut routes = Vec::new();
+    for (device, allocation) in devices.iter().zip(allocations.iter()) {
+        if !allocation.is_empty() {
+            routes.push(build_sphere_route(ctx, player.position, device.position, player.current_world)?);
+        } else {
+            routes.push(Vec::new());
+        }
+    }
+
+    let mut transfers_created = 0u32;
+    for ((device, allocation), spire_ids) in devices.iter().zip(allocations.iter()).zip(routes.iter()) {
+        if allocation.is_empty() {
+            continue;
+        }
+        for batch_composition in create_transfer_batches(allocation) {
+            queue_device_transfer(ctx, &player, device, spire_ids, &batch_composition, 0)?;
+            transfers_created += 1;
+        }
+        log::info!("Storage device {} allocated {} packets", device.device_id, composition::total_count(allocation));
+    }
+
+    log::info!("Distributed {} packets across {} devices in {} transfers",
+        composition::total_count(&requested), devices.len(), transfers_created);
+    log::info!("=== DISTRIBUTE_TO_DEVICES END ===");
+
+    Ok(())
+}
+
+/// Split a composition across devices in order, filling each device's free room
+/// before moving to the next. Returns one allocation per device (possibly empty),
+/// or CapacityExceeded if the devices can't hold it all
+fn allocate_to_devices(devices: &[StorageDevice], requested: &[WavePacketSample]) -> Result<Vec<Vec<WavePacketSample>>, String> {
+    let mut allocations: Vec<Vec<WavePacketSample>> = vec![Vec::new(); devices.len()];
+    for sample in requested {
+        let mut remaining = sample.count;
+        for (device, allocation) in devices.iter().zip(allocations.iter_mut()) {
+            if remaining == 0 {
+                break;
+            }
+            let room = storage_capacity_for(device, sample.frequency)
+                .saturating_sub(stored_at_frequency(device, sample.frequency));
+            let take = remaining.min(room);
+            if take > 0 {
+                allocation.push(WavePacketSample { count: take, ..sample.clone() });
+                remaining -= take;
+            }
+        }
+        if remaining > 0 {
+            return Err(GameError::CapacityExceeded(format!(
+                "Devices can't hold {} more packets of frequency {}", remaining, sample.frequency)).into());
+        }
+    }
+    Ok(allocations)
 }
 
-/// Complete energy packet transfer
+/// Complete energy packet transfer (admin only)
 /// Charges spires and adds packets to storage
 #[spacetimedb::reducer]
 pub fn complete_transfer(ctx: &ReducerContext, transfer_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== COMPLETE_TRANSFER START ===");
     log::info!("Transfer ID: {}", transfer_id);
 
@@ -3893,18 +6558,21 @@ pub fn complete_transfer(ctx: &ReducerContext, transfer_id: u64) -> Result<(), S
     let transfer = ctx.db.packet_transfer()
         .transfer_id()
         .find(&transfer_id)
-        .ok_or("Transfer not found")?;
+        .ok_or(GameError::NotFound("Transfer".to_string()))?;
 
     if transfer.completed {
-        return Err("Transfer already completed".to_string());
+        return Err(GameError::InvalidState("Transfer already completed".to_string()).into());
     }
 
+    // Packets waiting at a sphere leave its buffer
+    release_from_sphere_buffer(ctx, &transfer);
+
     // Update each sphere in the route
     for sphere_id in &transfer.route_spire_ids {
         let sphere = ctx.db.distribution_sphere()
             .sphere_id()
             .find(sphere_id)
-            .ok_or(format!("Distribution sphere {} not found", sphere_id))?;
+            .ok_or(GameError::NotFound(format!("Distribution sphere {}", sphere_id)))?;
 
         // Update sphere statistics
         let mut updated_sphere = sphere.clone();
@@ -3919,7 +6587,7 @@ pub fn complete_transfer(ctx: &ReducerContext, transfer_id: u64) -> Result<(), S
             .iter()
             .find(|t| t.world_coords == updated_sphere.world_coords &&
                      t.cardinal_direction == updated_sphere.cardinal_direction)
-            .ok_or(format!("Quantum tunnel not found for sphere {}", sphere_id))?;
+            .ok_or(GameError::NotFound(format!("Quantum tunnel for sphere {}", sphere_id)))?;
 
         let mut updated_tunnel = tunnel.clone();
         updated_tunnel.ring_charge = (updated_tunnel.ring_charge + 1.0).min(100.0);
@@ -3941,24 +6609,12 @@ pub fn complete_transfer(ctx: &ReducerContext, transfer_id: u64) -> Result<(), S
     let storage = ctx.db.storage_device()
         .device_id()
         .find(&transfer.destination_device_id)
-        .ok_or("Storage device not found")?;
+        .ok_or(GameError::NotFound("Storage device".to_string()))?;
 
     let mut updated_storage = storage.clone();
     
     // Add packets to storage composition
-    for sample in &transfer.composition {
-        let mut found = false;
-        for existing in &mut updated_storage.stored_composition {
-            if (existing.frequency - sample.frequency).abs() < 0.01 {
-                existing.count += sample.count;
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            updated_storage.stored_composition.push(sample.clone());
-        }
-    }
+    composition::merge_compositions(&mut updated_storage.stored_composition, &transfer.composition);
 
     ctx.db.storage_device().delete(storage);
     ctx.db.storage_device().insert(updated_storage);
@@ -3972,12 +6628,102 @@ pub fn complete_transfer(ctx: &ReducerContext, transfer_id: u64) -> Result<(), S
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(updated_transfer);
 
+    update_player_stats(ctx, transfer.player_id, |stats| {
+        stats.total_transferred += packet_count as u64;
+    });
+
     log::info!("Transfer complete: {} packets added to storage {}", packet_count, device_id);
     log::info!("=== COMPLETE_TRANSFER END ===");
 
     Ok(())
 }
 
+/// Refund and delete transfers stuck on a route whose spheres no longer exist
+/// Only transfers whose current leg started more than `max_age_ms` ago are considered
+#[spacetimedb::reducer]
+pub fn reclaim_stuck_transfers(ctx: &ReducerContext, max_age_ms: u64) -> Result<(), String> {
+    log::info!("=== RECLAIM_STUCK_TRANSFERS START ===");
+
+    let max_age = Duration::from_millis(max_age_ms);
+    let stuck: Vec<PacketTransfer> = ctx.db.packet_transfer()
+        .iter()
+        .filter(|t| !t.completed)
+        .filter(|t| ctx.timestamp.duration_since(t.leg_start_time).map_or(false, |age| age > max_age))
+        .filter(|t| route_has_missing_sphere(&t.route_spire_ids, |id| ctx.db.distribution_sphere().sphere_id().find(id).is_some()))
+        .collect();
+
+    for transfer in &stuck {
+        let packet_count = composition::total_count(&transfer.composition);
+        let refunded = refund_to_transfer_source(ctx, transfer, &transfer.composition);
+        if refunded < packet_count {
+            log::warn!("Dropped {} packets refunding transfer {}", packet_count - refunded, transfer.transfer_id);
+        }
+
+        release_from_sphere_buffer(ctx, transfer);
+        ctx.db.packet_transfer().transfer_id().delete(&transfer.transfer_id);
+        log::info!("Reclaimed stuck transfer {} ({} packets) back to {} {}",
+            transfer.transfer_id, transfer.packet_count, transfer.source_object_type, transfer.source_object_id);
+    }
+
+    log::info!("=== RECLAIM_STUCK_TRANSFERS END - Reclaimed {} transfers ===", stuck.len());
+    Ok(())
+}
+
+/// Whether any sphere on a transfer's route has been deleted
+fn route_has_missing_sphere(route_spire_ids: &[u64], sphere_exists: impl Fn(&u64) -> bool) -> bool {
+    route_spire_ids.iter().any(|id| !sphere_exists(id))
+}
+
+/// First route index a transfer hasn't arrived at yet (legs advance on departure)
+fn first_unreached_sphere(transfer: &PacketTransfer) -> usize {
+    match transfer.current_leg_type.as_str() {
+        "PendingAtObject" | "ObjectToSphere" => 0,
+        "SphereToSphere" => transfer.current_leg as usize,
+        "ArrivedAtSphere" => transfer.current_leg as usize + 1,
+        _ => transfer.route_spire_ids.len(),
+    }
+}
+
+/// Admin: deliver an in-flight transfer immediately, ignoring predicted_arrival_time
+/// Spheres the transfer hasn't reached yet are charged, then the normal final arrival runs
+#[spacetimedb::reducer]
+pub fn debug_force_complete_transfer(ctx: &ReducerContext, transfer_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    log::info!("=== DEBUG_FORCE_COMPLETE_TRANSFER START ===");
+    log::info!("Transfer ID: {}", transfer_id);
+
+    let transfer = ctx.db.packet_transfer()
+        .transfer_id()
+        .find(&transfer_id)
+        .ok_or(GameError::NotFound("Transfer".to_string()))?;
+
+    if transfer.completed {
+        return Err(GameError::InvalidState("Transfer already completed".to_string()).into());
+    }
+
+    for sphere_id in transfer.route_spire_ids.iter().skip(first_unreached_sphere(&transfer)) {
+        match ctx.db.distribution_sphere().sphere_id().find(sphere_id) {
+            Some(sphere) => {
+                let mut updated_sphere = sphere.clone();
+                updated_sphere.packets_routed += transfer.packet_count as u64;
+                updated_sphere.last_packet_time = ctx.timestamp;
+                ctx.db.distribution_sphere().delete(sphere);
+                ctx.db.distribution_sphere().insert(updated_sphere);
+            }
+            None => log::warn!("Sphere {} on transfer {} route no longer exists", sphere_id, transfer_id),
+        }
+    }
+
+    // A transfer waiting at a sphere still has packets in that sphere's buffer
+    release_from_sphere_buffer(ctx, &transfer);
+    process_sphere_to_object_arrival(ctx, &transfer)?;
+
+    log::info!("Force-completed transfer {} from leg '{}'", transfer_id, transfer.current_leg_type);
+    log::info!("=== DEBUG_FORCE_COMPLETE_TRANSFER END ===");
+    Ok(())
+}
+
 
 /// Tick player transfer pulses (2-second intervals)
 /// Moves packets from player to first sphere
@@ -4010,29 +6756,23 @@ pub fn tick_player_transfers(ctx: &ReducerContext) -> Result<(), String> {
             .ok_or("First sphere not found")?;
         
         // Add packets to sphere's transit buffer
+        let mut updated_transfer = transfer.clone();
         let mut updated_sphere = sphere.clone();
-        for sample in &transfer.composition {
-            // Check if frequency already exists in buffer
-            let mut found = false;
-            for existing in &mut updated_sphere.transit_buffer {
-                if (existing.frequency - sample.frequency).abs() < 0.01 {
-                    existing.count += sample.count;
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                updated_sphere.transit_buffer.push(sample.clone());
-            }
-        }
-        updated_sphere.packets_routed += transfer.packet_count as u64;
+        add_to_sphere_buffer(&mut updated_sphere, &mut updated_transfer.composition);
+        updated_transfer.packet_count = composition::total_count(&updated_transfer.composition);
+        updated_sphere.packets_routed += updated_transfer.packet_count as u64;
         updated_sphere.last_packet_time = now;
+        let sphere_position = updated_sphere.sphere_position;
         
         ctx.db.distribution_sphere().delete(sphere);
         ctx.db.distribution_sphere().insert(updated_sphere);
+
+        if updated_transfer.composition.is_empty() {
+            finish_dropped_transfer(ctx, &transfer, sphere_position);
+            continue;
+        }
         
         // Update transfer state
-        let mut updated_transfer = transfer.clone();
         updated_transfer.current_leg = 1;
         updated_transfer.state = "InTransit".to_string();
         updated_transfer.leg_start_time = now;
@@ -4056,20 +6796,11 @@ pub fn world_sphere_pulse(ctx: &ReducerContext, world_x: i32, world_y: i32, worl
     let now = ctx.timestamp;
     
     // Collect all transfers in this world that are in InTransit state
-    let mut active_transfers: Vec<PacketTransfer> = Vec::new();
-    for transfer in ctx.db.packet_transfer().iter() {
-        if transfer.state == "InTransit" && !transfer.completed {
-            // Check if transfer involves this world
-            let player = ctx.db.player()
-                .player_id()
-                .find(&transfer.player_id);
-            if let Some(p) = player {
-                if p.current_world == world_coords {
-                    active_transfers.push(transfer.clone());
-                }
-            }
-        }
-    }
+    // Matched on the transfer's own world so they keep moving if the sender logs out
+    let active_transfers: Vec<PacketTransfer> = ctx.db.packet_transfer()
+        .iter()
+        .filter(|t| t.state == "InTransit" && !t.completed && t.world_coords == world_coords)
+        .collect();
     
     // Process each active transfer
     for transfer in &active_transfers {
@@ -4083,22 +6814,12 @@ pub fn world_sphere_pulse(ctx: &ReducerContext, world_x: i32, world_y: i32, worl
                 .find(&transfer.destination_device_id);
             
             if let Some(storage) = storage {
+                release_from_sphere_buffer(ctx, transfer);
+
                 let mut updated_storage = storage.clone();
                 
                 // Add packets to storage composition
-                for sample in &transfer.composition {
-                    let mut found = false;
-                    for existing in &mut updated_storage.stored_composition {
-                        if (existing.frequency - sample.frequency).abs() < 0.01 {
-                            existing.count += sample.count;
-                            found = true;
-                            break;
-                        }
-                    }
-                    if !found {
-                        updated_storage.stored_composition.push(sample.clone());
-                    }
-                }
+                composition::merge_compositions(&mut updated_storage.stored_composition, &transfer.composition);
                 
                 ctx.db.storage_device().delete(storage);
                 ctx.db.storage_device().insert(updated_storage);
@@ -4112,6 +6833,7 @@ pub fn world_sphere_pulse(ctx: &ReducerContext, world_x: i32, world_y: i32, worl
                 let device_id_log = transfer.destination_device_id;
                 ctx.db.packet_transfer().delete(transfer.clone());
                 ctx.db.packet_transfer().insert(updated_transfer);
+                record_transfer_log(ctx, transfer);
                 
                 log::info!("Transfer {} completed - packets delivered to storage {}", 
                     transfer_id_log, device_id_log);
@@ -4124,30 +6846,29 @@ pub fn world_sphere_pulse(ctx: &ReducerContext, world_x: i32, world_y: i32, worl
                 .find(&next_sphere_id);
             
             if let Some(sphere) = sphere {
+                release_from_sphere_buffer(ctx, transfer);
+                // Re-read in case the transfer was released from this same sphere
+                let sphere = ctx.db.distribution_sphere().sphere_id().find(&next_sphere_id).unwrap_or(sphere);
+
+                let mut updated_transfer = transfer.clone();
                 let mut updated_sphere = sphere.clone();
                 
                 // Add packets to sphere's transit buffer
-                for sample in &transfer.composition {
-                    let mut found = false;
-                    for existing in &mut updated_sphere.transit_buffer {
-                        if (existing.frequency - sample.frequency).abs() < 0.01 {
-                            existing.count += sample.count;
-                            found = true;
-                            break;
-                        }
-                    }
-                    if !found {
-                        updated_sphere.transit_buffer.push(sample.clone());
-                    }
-                }
-                updated_sphere.packets_routed += transfer.packet_count as u64;
+                add_to_sphere_buffer(&mut updated_sphere, &mut updated_transfer.composition);
+                updated_transfer.packet_count = composition::total_count(&updated_transfer.composition);
+                updated_sphere.packets_routed += updated_transfer.packet_count as u64;
                 updated_sphere.last_packet_time = now;
+                let sphere_position = updated_sphere.sphere_position;
                 
                 ctx.db.distribution_sphere().delete(sphere);
                 ctx.db.distribution_sphere().insert(updated_sphere);
+
+                if updated_transfer.composition.is_empty() {
+                    finish_dropped_transfer(ctx, transfer, sphere_position);
+                    continue;
+                }
                 
                 // Update transfer to next leg
-                let mut updated_transfer = transfer.clone();
                 updated_transfer.current_leg += 1;
                 updated_transfer.leg_start_time = now;
                 
@@ -4167,7 +6888,7 @@ pub fn world_sphere_pulse(ctx: &ReducerContext, world_x: i32, world_y: i32, worl
     
     Ok(())
 }
-/// Debug reducer for testing transfer system
+/// Debug reducer for testing transfer system (admin only)
 #[spacetimedb::reducer]
 pub fn add_test_inventory(
     ctx: &ReducerContext,
@@ -4179,6 +6900,8 @@ pub fn add_test_inventory(
     blue: u32,
     magenta: u32
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== ADD_TEST_INVENTORY START ===");
 
     let total = red + yellow + green + cyan + blue + magenta;
@@ -4239,74 +6962,886 @@ pub fn add_test_inventory(
         });
     }
 
-    // Check if inventory exists
-    let existing = ctx.db.player_inventory().player_id().find(&player_id);
+    // Check if inventory exists
+    let existing = ctx.db.player_inventory().player_id().find(&player_id);
+
+    if let Some(inv) = existing {
+        // Update existing
+        let updated = PlayerInventory {
+            player_id,
+            inventory_composition: composition,
+            total_count: total,
+            last_updated: ctx.timestamp,
+        };
+
+        ctx.db.player_inventory().delete(inv);
+        ctx.db.player_inventory().insert(updated);
+    } else {
+        // Create new
+        let inventory = PlayerInventory {
+            player_id,
+            inventory_composition: composition,
+            total_count: total,
+            last_updated: ctx.timestamp,
+        };
+
+        ctx.db.player_inventory().insert(inventory);
+    }
+
+    log::info!("Set inventory for player {}: R={} Y={} G={} C={} B={} M={} (Total: {})",
+        player_id, red, yellow, green, cyan, blue, magenta, total);
+    log::info!("=== ADD_TEST_INVENTORY END ===");
+
+    Ok(())
+}
+
+/// Create storage device for player
+/// Limited to 1 device per player
+#[spacetimedb::reducer]
+pub fn create_storage_device(ctx: &ReducerContext, x: f32, y: f32, z: f32, device_name: String) -> Result<(), String> {
+    log::info!("=== CREATE_STORAGE_DEVICE START ===");
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+
+    // Devices must sit on the surface so spire routing can reach them (admins may bypass)
+    let position = DbVector3 { x, y, z };
+    let surface_radius = position
+        .ellipsoid_project(&world_radii(ctx, &player.current_world), OBJECT_PACKET_HEIGHT)
+        .magnitude();
+    if !is_admin(ctx) && (position.magnitude() - surface_radius).abs() > DEVICE_PLACEMENT_TOLERANCE {
+        log::warn!("Rejected device placement at radius {:.2} (surface {:.2})", position.magnitude(), surface_radius);
+        return Err("Device must be placed on the surface".to_string());
+    }
+
+    // Check 1 device limit - players can only have one storage device
+    for device in ctx.db.storage_device().iter() {
+        if device.owner_player_id == player.player_id {
+            log::warn!("Player {} already has a storage device (ID={})", player.player_id, device.device_id);
+            return Err("You already have a storage device. Only 1 allowed per player.".to_string());
+        }
+    }
+
+    // Rate-limit creation so delete/create loops can't spam devices
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+    let last_created = ctx.db.player_stats()
+        .player_id()
+        .find(&player.player_id)
+        .map(|stats| stats.last_device_created_at)
+        .unwrap_or(0);
+    let since_last = current_time.saturating_sub(last_created);
+    if last_created > 0 && since_last < DEVICE_CREATE_COOLDOWN_MS {
+        let remaining_ms = DEVICE_CREATE_COOLDOWN_MS - since_last;
+        log::warn!("Player {} device creation on cooldown ({}ms remaining)", player.player_id, remaining_ms);
+        return Err(GameError::Cooldown(remaining_ms).into());
+    }
+
+    // Keep devices apart so routing and the UI never see two at the same spot
+    if let Some(conflict) = ctx.db.storage_device().iter().find(|d| {
+        d.world_coords == player.current_world
+            && d.position.distance_to(&position) < MIN_DEVICE_SEPARATION
+    }) {
+        log::warn!("Rejected device placement {:.2} units from device {} ('{}')",
+            conflict.position.distance_to(&position), conflict.device_id, conflict.device_name);
+        return Err(GameError::InvalidInput(format!(
+            "Too close to storage device '{}' (minimum separation {} units)",
+            conflict.device_name, MIN_DEVICE_SEPARATION)).into());
+    }
+
+    let device = StorageDevice {
+        device_id: 0, // auto_inc
+        owner_player_id: player.player_id,
+        world_coords: player.current_world,
+        position,
+        device_name,
+        capacity_per_frequency: DEFAULT_STORAGE_CAPACITY_PER_FREQUENCY,
+        stored_composition: Vec::new(),  // Empty on creation
+        created_at: ctx.timestamp,
+        per_frequency_capacity: Vec::new(),
+    };
+
+    ctx.db.storage_device().insert(device);
+    update_player_stats(ctx, player.player_id, |stats| {
+        stats.last_device_created_at = current_time;
+    });
+
+    log::info!("Created storage device at ({}, {}, {}) for player {}",
+        x, y, z, player.player_id);
+    log::info!("=== CREATE_STORAGE_DEVICE END ===");
+
+    Ok(())
+}
+
+/// Find a storage device owned by the calling player
+fn find_owned_storage_device(ctx: &ReducerContext, device_id: u64) -> Result<StorageDevice, String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let device = ctx.db.storage_device()
+        .device_id()
+        .find(&device_id)
+        .ok_or(GameError::NotFound("Storage device".to_string()))?;
+
+    if device.owner_player_id != player.player_id {
+        return Err(GameError::Unauthorized("Not your storage device".to_string()).into());
+    }
+
+    Ok(device)
+}
+
+/// Rename a storage device owned by the caller
+#[spacetimedb::reducer]
+pub fn rename_storage_device(ctx: &ReducerContext, device_id: u64, new_name: String) -> Result<(), String> {
+    if new_name.is_empty() {
+        return Err(GameError::InvalidInput("Device name must not be empty".to_string()).into());
+    }
+
+    let device = find_owned_storage_device(ctx, device_id)?;
+
+    let mut updated = device.clone();
+    updated.device_name = new_name;
+
+    log::info!("Renamed storage device {} from '{}' to '{}'", device_id, device.device_name, updated.device_name);
+
+    ctx.db.storage_device().delete(device);
+    ctx.db.storage_device().insert(updated);
+
+    Ok(())
+}
+
+/// Move a storage device owned by the caller to a new surface position
+/// Rejected while any transfer to or from the device is in flight, since their
+/// waypoints end at the old position
+#[spacetimedb::reducer]
+pub fn move_storage_device(ctx: &ReducerContext, device_id: u64, x: f32, y: f32, z: f32) -> Result<(), String> {
+    let device = find_owned_storage_device(ctx, device_id)?;
+
+    if !x.is_finite() || !y.is_finite() || !z.is_finite() {
+        return Err(GameError::InvalidInput("Position contains NaN or infinite values".to_string()).into());
+    }
+    let position = DbVector3 { x, y, z };
+
+    // Same surface rule as create_storage_device (admins may bypass)
+    let surface_radius = position
+        .ellipsoid_project(&world_radii(ctx, &device.world_coords), OBJECT_PACKET_HEIGHT)
+        .magnitude();
+    if !is_admin(ctx) && (position.magnitude() - surface_radius).abs() > DEVICE_PLACEMENT_TOLERANCE {
+        log::warn!("Rejected device move to radius {:.2} (surface {:.2})", position.magnitude(), surface_radius);
+        return Err(GameError::InvalidInput("Device must be placed on the surface".to_string()).into());
+    }
+
+    if let Some(conflict) = ctx.db.storage_device().iter().find(|d| {
+        d.device_id != device_id
+            && d.world_coords == device.world_coords
+            && d.position.distance_to(&position) < MIN_DEVICE_SEPARATION
+    }) {
+        return Err(GameError::InvalidInput(format!(
+            "Too close to storage device '{}' (minimum separation {} units)",
+            conflict.device_name, MIN_DEVICE_SEPARATION)).into());
+    }
+
+    check_no_device_transfers_in_flight(ctx, device_id)?;
+
+    let mut updated = device.clone();
+    updated.position = position;
+
+    log::info!("Moved storage device {} from ({:.2}, {:.2}, {:.2}) to ({:.2}, {:.2}, {:.2})",
+        device_id, device.position.x, device.position.y, device.position.z, x, y, z);
+
+    ctx.db.storage_device().delete(device);
+    ctx.db.storage_device().insert(updated);
+
+    Ok(())
+}
+
+/// Change the per-frequency capacity of a storage device owned by the caller
+/// Rejects capacities below the largest amount currently stored at any frequency, or that
+/// would take the device past MAX_STORAGE_CAPACITY in total
+#[spacetimedb::reducer]
+pub fn set_storage_capacity(ctx: &ReducerContext, device_id: u64, capacity_per_frequency: u32) -> Result<(), String> {
+    let device = find_owned_storage_device(ctx, device_id)?;
+
+    let mut updated = device.clone();
+    updated.capacity_per_frequency = capacity_per_frequency;
+
+    // Frequencies with their own override keep it - only the rest take the new default
+    check_storage_capacity(&updated)?;
+
+    log::info!("Storage device {} capacity changed from {} to {} per frequency",
+        device_id, device.capacity_per_frequency, capacity_per_frequency);
+
+    ctx.db.storage_device().delete(device);
+    ctx.db.storage_device().insert(updated);
+
+    Ok(())
+}
+
+/// Override the capacity of one frequency in a storage device owned by the caller
+/// (e.g. a red silo). Other frequencies keep using capacity_per_frequency.
+/// The device total still can't exceed MAX_STORAGE_CAPACITY, so a silo needs room made
+/// elsewhere (lower capacity_per_frequency or other overrides)
+#[spacetimedb::reducer]
+pub fn set_frequency_capacity(ctx: &ReducerContext, device_id: u64, frequency: f32, capacity: u32) -> Result<(), String> {
+    let device = find_owned_storage_device(ctx, device_id)?;
+
+    let frequency = ALL_FREQUENCIES.iter()
+        .copied()
+        .find(|f| same_frequency(*f, frequency))
+        .ok_or(GameError::InvalidInput(format!("{} is not a canonical frequency", frequency)))?;
+
+    if capacity > MAX_STORAGE_CAPACITY {
+        return Err(GameError::InvalidInput(format!(
+            "Capacity {} exceeds device limit {}", capacity, MAX_STORAGE_CAPACITY)).into());
+    }
+
+    let mut updated = device.clone();
+    updated.per_frequency_capacity.retain(|c| !same_frequency(c.frequency, frequency));
+    updated.per_frequency_capacity.push(FrequencyCapacity { frequency, capacity });
+
+    check_storage_capacity(&updated)?;
+
+    log::info!("Storage device {} capacity for frequency {} set to {}", device_id, frequency, capacity);
+
+    ctx.db.storage_device().delete(device);
+    ctx.db.storage_device().insert(updated);
+
+    Ok(())
+}
+
+/// Add packets to a player's inventory up to MAX_INVENTORY_PACKETS, creating it if needed
+/// Returns how many packets were added - anything beyond the cap is not added
+fn return_to_inventory(ctx: &ReducerContext, player_id: u64, samples: &[WavePacketSample]) -> u32 {
+    let existing = ctx.db.player_inventory().player_id().find(&player_id);
+    let mut inventory = existing.clone().unwrap_or(PlayerInventory {
+        player_id,
+        inventory_composition: Vec::new(),
+        total_count: 0,
+        last_updated: ctx.timestamp,
+    });
+
+    let returned_total = add_to_inventory_capped(&mut inventory, samples);
+    inventory.last_updated = ctx.timestamp;
+
+    if existing.is_some() {
+        ctx.db.player_inventory().player_id().delete(&player_id);
+    }
+    ctx.db.player_inventory().insert(inventory);
+
+    returned_total
+}
+
+/// Merge packets into an inventory until it reaches MAX_INVENTORY_PACKETS
+/// Returns how many packets were added
+fn add_to_inventory_capped(inventory: &mut PlayerInventory, samples: &[WavePacketSample]) -> u32 {
+    let mut space = MAX_INVENTORY_PACKETS.saturating_sub(inventory.total_count);
+    let mut returned = Vec::new();
+    for sample in samples {
+        let take = sample.count.min(space);
+        if take == 0 {
+            continue;
+        }
+        let mut portion = sample.clone();
+        portion.count = take;
+        returned.push(portion);
+        space -= take;
+    }
+
+    let returned_total = composition::total_count(&returned);
+    composition::merge_compositions(&mut inventory.inventory_composition, &returned);
+    inventory.total_count += returned_total;
+    returned_total
+}
+
+/// Hand packets back to a transfer's source: the player's inventory (up to MAX_INVENTORY_PACKETS),
+/// the storage device (up to its per-frequency capacity) or the orb they were mined from
+/// Returns how many packets were refunded - the rest are dropped
+fn refund_to_transfer_source(ctx: &ReducerContext, transfer: &PacketTransfer, samples: &[WavePacketSample]) -> u32 {
+    let source_id = transfer.source_object_id;
+    match transfer.source_object_type.as_str() {
+        "Player" => return_to_inventory(ctx, source_id, samples),
+        "StorageDevice" => {
+            let storage = match ctx.db.storage_device().device_id().find(&source_id) {
+                Some(storage) => storage,
+                None => {
+                    log::warn!("Source storage device {} gone - can't refund transfer {}", source_id, transfer.transfer_id);
+                    return 0;
+                }
+            };
+
+            let refund: Vec<WavePacketSample> = samples.iter()
+                .map(|sample| {
+                    let room = storage_capacity_for(&storage, sample.frequency)
+                        .saturating_sub(stored_at_frequency(&storage, sample.frequency));
+                    WavePacketSample { count: sample.count.min(room), ..sample.clone() }
+                })
+                .filter(|sample| sample.count > 0)
+                .collect();
+
+            let mut updated_storage = storage.clone();
+            composition::merge_compositions(&mut updated_storage.stored_composition, &refund);
+            ctx.db.storage_device().delete(storage);
+            ctx.db.storage_device().insert(updated_storage);
+
+            composition::total_count(&refund)
+        }
+        "Orb" => {
+            let source = match ctx.db.wave_packet_source().source_id().find(&source_id) {
+                Some(source) => source,
+                None => {
+                    log::warn!("Source orb {} gone - can't refund transfer {}", source_id, transfer.transfer_id);
+                    return 0;
+                }
+            };
+
+            let refunded = composition::total_count(samples);
+            let mut updated_source = source.clone();
+            composition::merge_compositions(&mut updated_source.wave_packet_composition, samples);
+            updated_source.total_wave_packets += refunded;
+            ctx.db.wave_packet_source().delete(source);
+            ctx.db.wave_packet_source().insert(updated_source);
+
+            refunded
+        }
+        other => {
+            log::warn!("Cannot refund to source type '{}' for transfer {}", other, transfer.transfer_id);
+            0
+        }
+    }
+}
+
+/// Write an inventory_snapshot row for any player, resolving their display name through the account
+#[spacetimedb::reducer]
+pub fn get_inventory_snapshot(ctx: &ReducerContext, player_id: u64) -> Result<(), String> {
+    let player = ctx.db.player()
+        .player_id()
+        .find(&player_id)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let display_name = player.account_id
+        .and_then(|account_id| ctx.db.account().account_id().find(&account_id))
+        .map(|account| account.display_name)
+        .unwrap_or(player.name);
+
+    let (composition, total_count) = match ctx.db.player_inventory().player_id().find(&player_id) {
+        Some(inventory) => (inventory.inventory_composition, inventory.total_count),
+        None => (Vec::new(), 0),
+    };
+
+    let count_of = |frequency: f32| composition.iter()
+        .filter(|s| same_frequency(s.frequency, frequency))
+        .map(|s| s.count)
+        .sum::<u32>();
+
+    let snapshot = InventorySnapshot {
+        player_id,
+        display_name,
+        red_count: count_of(FREQ_RED),
+        yellow_count: count_of(FREQ_YELLOW),
+        green_count: count_of(FREQ_GREEN),
+        cyan_count: count_of(FREQ_CYAN),
+        blue_count: count_of(FREQ_BLUE),
+        magenta_count: count_of(FREQ_MAGENTA),
+        composition: composition.clone(),
+        total_count,
+        snapshot_at: ctx.timestamp,
+    };
+
+    ctx.db.inventory_snapshot().player_id().delete(&player_id);
+    ctx.db.inventory_snapshot().insert(snapshot);
+
+    Ok(())
+}
+
+/// Packets that survive tuning `amount` packets (TUNE_LOSS_PERCENT lost, rounded down)
+/// Computed in u64 so it can't overflow for any u32 amount
+fn tuned_amount(amount: u32) -> u32 {
+    (amount as u64 * (100 - TUNE_LOSS_PERCENT) as u64 / 100) as u32
+}
+
+/// Convert inventory packets to an adjacent color on the frequency wheel (e.g. Red↔Yellow)
+/// TUNE_LOSS_PERCENT of the packets are lost in the conversion
+#[spacetimedb::reducer]
+pub fn tune_frequency(ctx: &ReducerContext, from_frequency: f32, to_frequency: f32, amount: u32) -> Result<(), String> {
+    log::info!("=== TUNE_FREQUENCY START ===");
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    if amount == 0 {
+        return Err(GameError::InvalidInput("Amount must be greater than 0".to_string()).into());
+    }
+    // No inventory holds more than this, so larger amounts can only fail the deduction
+    if amount > MAX_INVENTORY_PACKETS {
+        return Err(GameError::InvalidInput(format!(
+            "Amount {} exceeds inventory capacity {}", amount, MAX_INVENTORY_PACKETS)).into());
+    }
+
+    let from_index = ALL_FREQUENCIES.iter().position(|f| same_frequency(*f, from_frequency))
+        .ok_or(GameError::InvalidInput(format!("{} is not a canonical frequency", from_frequency)))?;
+    let to_index = ALL_FREQUENCIES.iter().position(|f| same_frequency(*f, to_frequency))
+        .ok_or(GameError::InvalidInput(format!("{} is not a canonical frequency", to_frequency)))?;
+
+    // Adjacent on the 6-color wheel (wraps Magenta↔Red)
+    let steps = (to_index + ALL_FREQUENCIES.len() - from_index) % ALL_FREQUENCIES.len();
+    if steps != 1 && steps != ALL_FREQUENCIES.len() - 1 {
+        return Err(GameError::InvalidInput(format!(
+            "Can only tune between adjacent frequencies ({} -> {} is not adjacent)", from_frequency, to_frequency)).into());
+    }
+
+    let tuned_amount = tuned_amount(amount);
+    if tuned_amount == 0 {
+        return Err(GameError::InvalidInput(format!("Amount {} too small to survive tuning loss", amount)).into());
+    }
+
+    let from_sample = WavePacketSample {
+        frequency: ALL_FREQUENCIES[from_index],
+        amplitude: 1.0,
+        phase: 0.0,
+        count: amount,
+    };
+    deduct_composition_from_inventory(ctx, player.player_id, &vec![from_sample])?;
+
+    // Inventory only shrinks here, so the full tuned amount always fits
+    let to_sample = WavePacketSample {
+        frequency: ALL_FREQUENCIES[to_index],
+        amplitude: 1.0,
+        phase: 0.0,
+        count: tuned_amount,
+    };
+    return_to_inventory(ctx, player.player_id, &[to_sample]);
+
+    log::info!("Player {} tuned {} packets of {} into {} packets of {}",
+        player.name, amount, ALL_FREQUENCIES[from_index], tuned_amount, ALL_FREQUENCIES[to_index]);
+    log::info!("=== TUNE_FREQUENCY END ===");
+
+    Ok(())
+}
+
+/// Merge duplicate frequencies and reject empty or non-finite samples
+fn normalize_trade_composition(composition: &[WavePacketSample]) -> Result<Vec<WavePacketSample>, String> {
+    if composition.iter().any(|s| !s.frequency.is_finite()) {
+        return Err(GameError::InvalidInput("Composition frequencies must be finite".to_string()).into());
+    }
+    let mut merged = Vec::new();
+    composition::merge_compositions(&mut merged, composition);
+    merged.retain(|s| s.count > 0);
+    Ok(merged)
+}
+
+/// Check that a player's inventory holds at least the given composition
+fn inventory_covers(ctx: &ReducerContext, player_id: u64, composition: &[WavePacketSample]) -> bool {
+    let mut held = ctx.db.player_inventory()
+        .player_id()
+        .find(&player_id)
+        .map(|inv| inv.inventory_composition)
+        .unwrap_or_default();
+
+    composition::subtract_composition(&mut held, composition).is_ok()
+}
+
+fn inventory_total(ctx: &ReducerContext, player_id: u64) -> u32 {
+    ctx.db.player_inventory()
+        .player_id()
+        .find(&player_id)
+        .map_or(0, |inv| inv.total_count)
+}
+
+/// Offer packets to another player in exchange for packets from them
+#[spacetimedb::reducer]
+pub fn create_trade_offer(
+    ctx: &ReducerContext,
+    to_player_id: u64,
+    offered: Vec<WavePacketSample>,
+    requested: Vec<WavePacketSample>,
+) -> Result<(), String> {
+    log::info!("=== CREATE_TRADE_OFFER START ===");
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    if to_player_id == player.player_id {
+        return Err(GameError::InvalidInput("Cannot trade with yourself".to_string()).into());
+    }
+    ctx.db.player()
+        .player_id()
+        .find(&to_player_id)
+        .ok_or(GameError::NotFound("Trade partner".to_string()))?;
+
+    let offered = normalize_trade_composition(&offered)?;
+    let requested = normalize_trade_composition(&requested)?;
+    if offered.is_empty() && requested.is_empty() {
+        return Err(GameError::InvalidInput("Trade offer is empty".to_string()).into());
+    }
+
+    if !inventory_covers(ctx, player.player_id, &offered) {
+        return Err(GameError::InvalidInput("Insufficient inventory for offered packets".to_string()).into());
+    }
+
+    let offer = ctx.db.trade_offer().insert(TradeOffer {
+        offer_id: 0, // auto_inc
+        from_player_id: player.player_id,
+        to_player_id,
+        offered,
+        requested,
+        status: "Pending".to_string(),
+        created_at: ctx.timestamp,
+        resolved_at: None,
+    });
+
+    log::info!("Trade offer {} created: player {} -> player {}", offer.offer_id, player.player_id, to_player_id);
+    log::info!("=== CREATE_TRADE_OFFER END ===");
+
+    Ok(())
+}
+
+/// Accept a pending trade offer addressed to the caller
+/// Both sides are verified before any packets move; the swap is all-or-nothing
+#[spacetimedb::reducer]
+pub fn accept_trade_offer(ctx: &ReducerContext, offer_id: u64) -> Result<(), String> {
+    log::info!("=== ACCEPT_TRADE_OFFER START ===");
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let offer = ctx.db.trade_offer()
+        .offer_id()
+        .find(&offer_id)
+        .ok_or(GameError::NotFound("Trade offer".to_string()))?;
+
+    if offer.to_player_id != player.player_id {
+        return Err(GameError::Unauthorized("Trade offer is not addressed to you".to_string()).into());
+    }
+    if offer.status != "Pending" {
+        return Err(GameError::InvalidState(format!("Trade offer is {}", offer.status)).into());
+    }
+
+    if !inventory_covers(ctx, offer.from_player_id, &offer.offered) {
+        return Err(GameError::InvalidState("Offering player no longer has the offered packets".to_string()).into());
+    }
+    if !inventory_covers(ctx, offer.to_player_id, &offer.requested) {
+        return Err(GameError::InvalidInput("Insufficient inventory for requested packets".to_string()).into());
+    }
+
+    // Both inventories must fit what they receive after giving their side
+    let offered_total = composition::total_count(&offer.offered);
+    let requested_total = composition::total_count(&offer.requested);
+    let from_after = inventory_total(ctx, offer.from_player_id) - offered_total + requested_total;
+    let to_after = inventory_total(ctx, offer.to_player_id) - requested_total + offered_total;
+    if from_after > MAX_INVENTORY_PACKETS || to_after > MAX_INVENTORY_PACKETS {
+        return Err(GameError::CapacityExceeded(format!(
+            "Trade would exceed inventory capacity of {}", MAX_INVENTORY_PACKETS)).into());
+    }
+
+    if !offer.offered.is_empty() {
+        deduct_composition_from_inventory(ctx, offer.from_player_id, &offer.offered)?;
+    }
+    if !offer.requested.is_empty() {
+        deduct_composition_from_inventory(ctx, offer.to_player_id, &offer.requested)?;
+    }
+    return_to_inventory(ctx, offer.to_player_id, &offer.offered);
+    return_to_inventory(ctx, offer.from_player_id, &offer.requested);
+
+    let mut updated = offer.clone();
+    updated.status = "Completed".to_string();
+    updated.resolved_at = Some(ctx.timestamp);
+    ctx.db.trade_offer().delete(offer);
+    ctx.db.trade_offer().insert(updated);
+
+    log::info!("Trade offer {} completed: {} packets <-> {} packets", offer_id, offered_total, requested_total);
+    log::info!("=== ACCEPT_TRADE_OFFER END ===");
+
+    Ok(())
+}
+
+/// Give packets to another player in the same world
+/// The whole gift is refused if it would push the recipient past MAX_INVENTORY_PACKETS
+#[spacetimedb::reducer]
+pub fn gift_packets(ctx: &ReducerContext, to_player_id: u64, composition: Vec<WavePacketSample>) -> Result<(), String> {
+    log::info!("=== GIFT_PACKETS START ===");
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    if to_player_id == player.player_id {
+        return Err(GameError::InvalidInput("Cannot gift packets to yourself".to_string()).into());
+    }
+    let recipient = ctx.db.player()
+        .player_id()
+        .find(&to_player_id)
+        .ok_or(GameError::NotFound("Recipient".to_string()))?;
+
+    if recipient.current_world != player.current_world {
+        return Err(GameError::InvalidInput("Recipient is on a different world".to_string()).into());
+    }
+
+    let composition = normalize_trade_composition(&composition)?;
+    let gift_total = composition::total_count(&composition);
+    if gift_total == 0 {
+        return Err(GameError::InvalidInput("Gift is empty".to_string()).into());
+    }
+
+    if !inventory_covers(ctx, player.player_id, &composition) {
+        return Err(GameError::InvalidInput("Insufficient inventory for gift".to_string()).into());
+    }
+
+    let recipient_total = inventory_total(ctx, to_player_id);
+    if recipient_total + gift_total > MAX_INVENTORY_PACKETS {
+        return Err(GameError::CapacityExceeded(format!(
+            "Recipient has room for {} packets, gift is {}",
+            MAX_INVENTORY_PACKETS.saturating_sub(recipient_total), gift_total)).into());
+    }
+
+    deduct_composition_from_inventory(ctx, player.player_id, &composition)?;
+    return_to_inventory(ctx, to_player_id, &composition);
+
+    log::info!("Player {} gifted {} packets to player {}", player.player_id, gift_total, to_player_id);
+    log::info!("=== GIFT_PACKETS END ===");
+
+    Ok(())
+}
+
+/// Cancel (or decline) a pending trade offer - either party may call this
+#[spacetimedb::reducer]
+pub fn cancel_trade_offer(ctx: &ReducerContext, offer_id: u64) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let offer = ctx.db.trade_offer()
+        .offer_id()
+        .find(&offer_id)
+        .ok_or(GameError::NotFound("Trade offer".to_string()))?;
+
+    if offer.from_player_id != player.player_id && offer.to_player_id != player.player_id {
+        return Err(GameError::Unauthorized("Not a party to this trade offer".to_string()).into());
+    }
+    if offer.status != "Pending" {
+        return Err(GameError::InvalidState(format!("Trade offer is {}", offer.status)).into());
+    }
+
+    let mut updated = offer.clone();
+    updated.status = "Cancelled".to_string();
+    updated.resolved_at = Some(ctx.timestamp);
+    ctx.db.trade_offer().delete(offer);
+    ctx.db.trade_offer().insert(updated);
+
+    log::info!("Trade offer {} cancelled by player {}", offer_id, player.player_id);
+
+    Ok(())
+}
+
+/// Delete a storage device owned by the caller
+/// Contents return to the owner's inventory up to MAX_INVENTORY_PACKETS; overflow is dropped.
+/// If the owner is on a different world the contents are dropped entirely.
+/// Rejected while transfers to or from the device are still in flight.
+#[spacetimedb::reducer]
+pub fn delete_storage_device(ctx: &ReducerContext, device_id: u64) -> Result<(), String> {
+    log::info!("=== DELETE_STORAGE_DEVICE START ===");
+
+    let device = find_owned_storage_device(ctx, device_id)?;
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    // Transfers to or from the device would have nowhere to deliver or refund to
+    check_no_device_transfers_in_flight(ctx, device_id)?;
+
+    let stored_total = composition::total_count(&device.stored_composition);
+
+    if player.current_world != device.world_coords {
+        if stored_total > 0 {
+            log::warn!("Owner not on device world - dropping {} stored packets from device {}",
+                stored_total, device_id);
+        }
+    } else if stored_total > 0 {
+        let returned_total = return_to_inventory(ctx, player.player_id, &device.stored_composition);
+
+        log::info!("Returned {} packets to player {} inventory", returned_total, player.player_id);
+        if returned_total < stored_total {
+            log::warn!("Inventory full - dropped {} packets from device {}",
+                stored_total - returned_total, device_id);
+        }
+    }
+
+    ctx.db.storage_device().delete(device);
+    ctx.db.storage_device_fullness().device_id().delete(&device_id);
+
+    log::info!("Deleted storage device {}", device_id);
+    log::info!("=== DELETE_STORAGE_DEVICE END ===");
+    Ok(())
+}
+
+/// Reject with InvalidState while any uncompleted transfer starts or ends at the device
+fn check_no_device_transfers_in_flight(ctx: &ReducerContext, device_id: u64) -> Result<(), String> {
+    let in_flight = ctx.db.packet_transfer().iter().filter(|t| {
+        !t.completed && (
+            (t.destination_object_type == "StorageDevice" && t.destination_object_id == device_id) ||
+            (t.source_object_type == "StorageDevice" && t.source_object_id == device_id)
+        )
+    }).count();
+    if in_flight > 0 {
+        return Err(GameError::InvalidState(format!(
+            "Device has {} transfers in flight - wait for them to complete", in_flight)).into());
+    }
+    Ok(())
+}
+
+/// Recompute the storage_device_fullness row for a device
+#[spacetimedb::reducer]
+pub fn refresh_storage_fullness(ctx: &ReducerContext, device_id: u64) -> Result<(), String> {
+    let device = ctx.db.storage_device()
+        .device_id()
+        .find(&device_id)
+        .ok_or(GameError::NotFound("Storage device".to_string()))?;
+
+    let per_frequency_breakdown: Vec<FrequencyFullness> = device.stored_composition
+        .iter()
+        .map(|s| {
+            let capacity = storage_capacity_for(&device, s.frequency);
+            FrequencyFullness {
+                frequency: s.frequency,
+                stored: s.count,
+                fraction_full: if capacity > 0 { s.count as f32 / capacity as f32 } else { 0.0 },
+            }
+        })
+        .collect();
+
+    let total_stored = composition::total_count(&device.stored_composition);
+    let total_capacity = u32::try_from(total_storage_capacity(&device)).unwrap_or(u32::MAX);
+    let fraction_full = if total_capacity > 0 { total_stored as f32 / total_capacity as f32 } else { 0.0 };
+
+    ctx.db.storage_device_fullness().device_id().delete(&device_id);
+    ctx.db.storage_device_fullness().insert(StorageDeviceFullness {
+        device_id,
+        total_stored,
+        total_capacity,
+        fraction_full,
+        per_frequency_breakdown,
+        updated_at: ctx.timestamp,
+    });
+
+    log::info!("Storage device {} fullness: {}/{} ({:.1}%)",
+        device_id, total_stored, total_capacity, fraction_full * 100.0);
+
+    Ok(())
+}
+
+/// Sum the caller's inventory, storage devices and packets in flight into net_worth
+/// Miners hold nothing of their own: mined packets are either still in flight (counted
+/// through their extraction or transfer) or already in inventory/storage
+#[spacetimedb::reducer]
+pub fn compute_net_worth(ctx: &ReducerContext) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let inventory = ctx.db.player_inventory()
+        .player_id()
+        .find(&player.player_id)
+        .map(|inv| inv.inventory_composition)
+        .unwrap_or_default();
+
+    let devices: Vec<StorageDevice> = ctx.db.storage_device()
+        .iter()
+        .filter(|d| d.owner_player_id == player.player_id)
+        .collect();
+    let storage = merged_compositions(devices.iter().map(|d| &d.stored_composition));
+
+    let transfers: Vec<PacketTransfer> = ctx.db.packet_transfer()
+        .iter()
+        .filter(|t| !t.completed && t.player_id == player.player_id)
+        .collect();
+    let extractions: Vec<WavePacketExtraction> = ctx.db.wave_packet_extraction()
+        .iter()
+        .filter(|e| e.player_id == player.player_id)
+        .collect();
+    let in_flight = merged_compositions(transfers.iter().map(|t| &t.composition)
+        .chain(extractions.iter().map(|e| &e.composition)));
+
+    let holdings = merged_compositions([&inventory, &storage, &in_flight]);
 
-    if let Some(inv) = existing {
-        // Update existing
-        let updated = PlayerInventory {
-            player_id,
-            inventory_composition: composition,
-            total_count: total,
-            last_updated: ctx.timestamp,
-        };
+    let result = NetWorth {
+        requester: ctx.sender,
+        player_id: player.player_id,
+        total: composition::total_count(&holdings),
+        composition: holdings,
+        inventory_total: composition::total_count(&inventory),
+        storage_total: composition::total_count(&storage),
+        in_flight_total: composition::total_count(&in_flight),
+        computed_at: ctx.timestamp,
+    };
 
-        ctx.db.player_inventory().delete(inv);
-        ctx.db.player_inventory().insert(updated);
-    } else {
-        // Create new
-        let inventory = PlayerInventory {
-            player_id,
-            inventory_composition: composition,
-            total_count: total,
-            last_updated: ctx.timestamp,
-        };
+    log::info!("Player {} net worth: {} packets ({} inventory, {} storage, {} in flight)",
+        player.player_id, result.total, result.inventory_total, result.storage_total, result.in_flight_total);
 
-        ctx.db.player_inventory().insert(inventory);
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.net_worth().requester().find(&ctx.sender) {
+        ctx.db.net_worth().delete(existing);
     }
-
-    log::info!("Set inventory for player {}: R={} Y={} G={} C={} B={} M={} (Total: {})",
-        player_id, red, yellow, green, cyan, blue, magenta, total);
-    log::info!("=== ADD_TEST_INVENTORY END ===");
+    ctx.db.net_worth().insert(result);
 
     Ok(())
 }
 
-/// Create storage device for player
-/// Limited to 1 device per player
-#[spacetimedb::reducer]
-pub fn create_storage_device(ctx: &ReducerContext, x: f32, y: f32, z: f32, device_name: String) -> Result<(), String> {
-    log::info!("=== CREATE_STORAGE_DEVICE START ===");
+/// Merge any number of compositions into one
+fn merged_compositions<'a>(compositions: impl IntoIterator<Item = &'a Vec<WavePacketSample>>) -> Vec<WavePacketSample> {
+    let mut merged = Vec::new();
+    for part in compositions {
+        composition::merge_compositions(&mut merged, part);
+    }
+    merged
+}
 
+/// Write the caller's storage devices and their contents to my_storage_list
+#[spacetimedb::reducer]
+pub fn list_my_storage_devices(ctx: &ReducerContext) -> Result<(), String> {
     let player = ctx.db.player()
         .identity()
         .find(&ctx.sender)
-        .ok_or("Player not found")?;
-
-    // Check 1 device limit - players can only have one storage device
-    for device in ctx.db.storage_device().iter() {
-        if device.owner_player_id == player.player_id {
-            log::warn!("Player {} already has a storage device (ID={})", player.player_id, device.device_id);
-            return Err("You already have a storage device. Only 1 allowed per player.".to_string());
-        }
-    }
+        .ok_or(GameError::NotFound("Player".to_string()))?;
 
-    let device = StorageDevice {
-        device_id: 0, // auto_inc
-        owner_player_id: player.player_id,
-        world_coords: player.current_world,
-        position: DbVector3 { x, y, z },
-        device_name,
-        capacity_per_frequency: 1000,  // 1000 per frequency, 6000 total
-        stored_composition: Vec::new(),  // Empty on creation
-        created_at: ctx.timestamp,
-    };
+    let mut devices: Vec<StorageListEntry> = ctx.db.storage_device()
+        .iter()
+        .filter(|d| d.owner_player_id == player.player_id)
+        .map(|d| StorageListEntry {
+            device_id: d.device_id,
+            total_stored: composition::total_count(&d.stored_composition),
+            device_name: d.device_name,
+            world_coords: d.world_coords,
+            position: d.position,
+            stored_composition: d.stored_composition,
+        })
+        .collect();
+    devices.sort_by_key(|d| d.device_id);
 
-    ctx.db.storage_device().insert(device);
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.my_storage_list().requester().find(&ctx.sender) {
+        ctx.db.my_storage_list().delete(existing);
+    }
 
-    log::info!("Created storage device at ({}, {}, {}) for player {}",
-        x, y, z, player.player_id);
-    log::info!("=== CREATE_STORAGE_DEVICE END ===");
+    ctx.db.my_storage_list().insert(MyStorageList {
+        requester: ctx.sender,
+        devices,
+        refreshed_at: ctx.timestamp,
+    });
 
     Ok(())
 }
@@ -4334,6 +7869,7 @@ pub fn create_energy_spire(
         packets_routed: 0,
         last_packet_time: ctx.timestamp,
         transit_buffer: Vec::new(),
+        buffer_capacity: SPHERE_BUFFER_CAPACITY,
     };
 
     ctx.db.distribution_sphere().insert(sphere);
@@ -4349,40 +7885,70 @@ pub fn create_energy_spire(
 // Energy Spire System - Helper Functions
 // ============================================================================
 
-/// Calculate cardinal direction position on world sphere
-/// World radius R = 300 units (from CLAUDE.md spec)
-fn get_cardinal_position(direction: &str) -> DbVector3 {
-    const R: f32 = 300.0;
-    match direction {
-        "North" => DbVector3 { x: 0.0, y: R, z: 0.0 },          // +Y (north pole)
-        "South" => DbVector3 { x: 0.0, y: -R, z: 0.0 },         // -Y (south pole)
-        "East" => DbVector3 { x: R, y: 0.0, z: 0.0 },           // +X (east)
-        "West" => DbVector3 { x: -R, y: 0.0, z: 0.0 },          // -X (west)
-        "Forward" => DbVector3 { x: 0.0, y: 0.0, z: R },        // +Z (forward)
-        "Back" => DbVector3 { x: 0.0, y: 0.0, z: -R },          // -Z (back)
-        _ => DbVector3 { x: 0.0, y: R, z: 0.0 }, // Default to North
-    }
-}
+/// All 26 spire directions (FCC lattice): 6 cardinal + 12 edge + 8 vertex
+const ALL_DIRECTIONS: [&str; 26] = [
+    // 6 Cardinal (face centers)
+    "North", "South", "East", "West", "Forward", "Back",
+    // 12 Edge centers (between two cardinals)
+    "NorthEast", "NorthWest", "SouthEast", "SouthWest",
+    "NorthForward", "NorthBack", "SouthForward", "SouthBack",
+    "EastForward", "EastBack", "WestForward", "WestBack",
+    // 8 Vertex (corners - between three cardinals)
+    "NorthEastForward", "NorthEastBack", "NorthWestForward", "NorthWestBack",
+    "SouthEastForward", "SouthEastBack", "SouthWestForward", "SouthWestBack",
+];
+
+/// Single source of truth for direction -> (surface normal, tunnel color, frequency)
+/// The normal is built from the axes named in the direction (East/West = ±X,
+/// North/South = ±Y, Forward/Back = ±Z). Color and frequency follow the tier:
+/// - Cardinal (1 axis): X = Red, Y = Green, Z = Blue
+/// - Edge (2 axes): XY = Yellow, YZ = Cyan, XZ = Magenta
+/// - Vertex (3 axes): White - carries no single frequency, emits as Red
+/// Unknown directions resolve to North's normal with a Grey color
+fn direction_metadata(direction: &str) -> (DbVector3, String, f32) {
+    if !ALL_DIRECTIONS.contains(&direction) {
+        return (DbVector3::new(0.0, 1.0, 0.0), "Grey".to_string(), FREQ_RED);
+    }
+
+    let mut axis = DbVector3::zero();
+    if direction.contains("East") { axis.x += 1.0; }
+    if direction.contains("West") { axis.x -= 1.0; }
+    if direction.contains("North") { axis.y += 1.0; }
+    if direction.contains("South") { axis.y -= 1.0; }
+    if direction.contains("Forward") { axis.z += 1.0; }
+    if direction.contains("Back") { axis.z -= 1.0; }
+
+    let (color, frequency) = match (axis.x != 0.0, axis.y != 0.0, axis.z != 0.0) {
+        (true, false, false) => ("Red", FREQ_RED),
+        (false, true, false) => ("Green", FREQ_GREEN),
+        (false, false, true) => ("Blue", FREQ_BLUE),
+        (true, true, false) => ("Yellow", FREQ_YELLOW),
+        (false, true, true) => ("Cyan", FREQ_CYAN),
+        (true, false, true) => ("Magenta", FREQ_MAGENTA),
+        _ => ("White", FREQ_RED),
+    };
 
-/// Get tunnel color based on cardinal direction (tier-based)
-fn get_tunnel_color(direction: &str) -> String {
-    match direction {
-        // Cardinal (6) - Primary colors
-        "North" | "South" => "Green".to_string(),    // ±Y axis (Green tunnels)
-        "East" | "West" => "Red".to_string(),        // ±X axis (Red tunnels)
-        "Forward" | "Back" => "Blue".to_string(),    // ±Z axis (Blue tunnels)
+    (axis.normalize(), color.to_string(), frequency)
+}
 
-        // Edge centers (12) - Secondary colors
-        "NorthEast" | "NorthWest" | "SouthEast" | "SouthWest" => "Yellow".to_string(),  // XY plane
-        "NorthForward" | "NorthBack" | "SouthForward" | "SouthBack" => "Cyan".to_string(),  // YZ plane
-        "EastForward" | "EastBack" | "WestForward" | "WestBack" => "Magenta".to_string(),  // XZ plane
+/// Offset to the neighboring world a direction's tunnel points at
+/// e.g. "NorthEast" -> (1, 1, 0), "Back" -> (0, 0, -1)
+fn direction_world_offset(direction: &str) -> WorldCoords {
+    let normal = direction_metadata(direction).0;
+    let step = |v: f32| if v > 0.0 { 1 } else if v < 0.0 { -1 } else { 0 };
+    WorldCoords { x: step(normal.x), y: step(normal.y), z: step(normal.z) }
+}
 
-        // Vertex corners (8) - White
-        "NorthEastForward" | "NorthEastBack" | "NorthWestForward" | "NorthWestBack" |
-        "SouthEastForward" | "SouthEastBack" | "SouthWestForward" | "SouthWestBack" => "White".to_string(),
+/// Calculate cardinal direction position on world sphere surface
+/// Unknown directions default to North
+fn get_cardinal_position(ctx: &ReducerContext, world_coords: &WorldCoords, direction: &str) -> DbVector3 {
+    let radii = world_radii(ctx, world_coords);
+    direction_metadata(direction).0.ellipsoid_project(&radii, 0.0)
+}
 
-        _ => "Grey".to_string(),
-    }
+/// Get tunnel color based on cardinal direction (tier-based)
+fn get_tunnel_color(direction: &str) -> String {
+    direction_metadata(direction).1
 }
 
 // ============================================================================
@@ -4398,6 +7964,8 @@ pub fn spawn_main_spires(
     world_y: i32,
     world_z: i32
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_MAIN_SPIRES START ===");
     log::info!("World: ({}, {}, {})", world_x, world_y, world_z);
 
@@ -4405,7 +7973,7 @@ pub fn spawn_main_spires(
     let directions = vec!["North", "South", "East", "West", "Forward", "Back"];
 
     for direction in directions {
-        let position = get_cardinal_position(direction);
+        let position = get_cardinal_position(ctx, &world_coords, direction);
         let color = get_tunnel_color(direction);
 
         // Create DistributionSphere
@@ -4418,6 +7986,7 @@ pub fn spawn_main_spires(
             packets_routed: 0,
             last_packet_time: ctx.timestamp,
             transit_buffer: Vec::new(),
+            buffer_capacity: SPHERE_BUFFER_CAPACITY,
         };
         ctx.db.distribution_sphere().insert(sphere);
 
@@ -4457,6 +8026,8 @@ pub fn spawn_circuit_at_spire(
     sources_per_emission: u32,
     emission_interval_ms: u64
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_CIRCUIT_AT_SPIRE START ===");
 
     let world_coords = WorldCoords { x: world_x, y: world_y, z: world_z };
@@ -4481,6 +8052,9 @@ pub fn spawn_circuit_at_spire(
         sources_per_emission,
         emission_interval_ms,
         last_emission_time: 0, // Not yet emitted
+        emission_composition: Vec::new(),
+        enabled: true,
+        emission_phase_ms: random_emission_phase(ctx, &world_coords, &cardinal_direction, emission_interval_ms),
     };
 
     ctx.db.world_circuit().insert(circuit);
@@ -4491,6 +8065,56 @@ pub fn spawn_circuit_at_spire(
     Ok(())
 }
 
+/// Admin: set (or clear with an empty list) a circuit's emission composition template
+/// Template counts are relative weights - each emission is scaled to a random total
+#[spacetimedb::reducer]
+pub fn set_circuit_emission_composition(
+    ctx: &ReducerContext,
+    circuit_id: u64,
+    composition: Vec<WavePacketSample>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if !composition.is_empty() && composition.iter().all(|s| s.count == 0) {
+        return Err(GameError::InvalidInput("Template must have at least one non-zero count".to_string()).into());
+    }
+
+    let circuit = ctx.db.world_circuit()
+        .circuit_id()
+        .find(&circuit_id)
+        .ok_or(GameError::NotFound("Circuit".to_string()))?;
+
+    let mut updated = circuit.clone();
+    updated.emission_composition = composition;
+
+    log::info!("Circuit {} emission template set to {} frequencies", circuit_id, updated.emission_composition.len());
+
+    ctx.db.world_circuit().delete(circuit);
+    ctx.db.world_circuit().insert(updated);
+
+    Ok(())
+}
+
+/// Admin: pause or resume emission from a single circuit
+#[spacetimedb::reducer]
+pub fn set_circuit_enabled(ctx: &ReducerContext, circuit_id: u64, enabled: bool) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let circuit = ctx.db.world_circuit()
+        .circuit_id()
+        .find(&circuit_id)
+        .ok_or(GameError::NotFound("Circuit".to_string()))?;
+
+    let mut updated = circuit.clone();
+    updated.enabled = enabled;
+
+    ctx.db.world_circuit().delete(circuit);
+    ctx.db.world_circuit().insert(updated);
+
+    log::info!("Circuit {} {}", circuit_id, if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
 /// Spawn circuits at the 6 main cardinal directions (North, South, East, West, Forward, Back)
 /// Creates WorldCircuit components at each cardinal spire location
 #[spacetimedb::reducer]
@@ -4500,6 +8124,8 @@ pub fn spawn_6_cardinal_circuits(
     world_y: i32,
     world_z: i32
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_6_CARDINAL_CIRCUITS START ===");
     log::info!("World: ({}, {}, {})", world_x, world_y, world_z);
 
@@ -4540,6 +8166,9 @@ pub fn spawn_6_cardinal_circuits(
             sources_per_emission: sources,
             emission_interval_ms: 10000, // Every 10 seconds
             last_emission_time: 0, // Not yet emitted
+            emission_composition: Vec::new(),
+            enabled: true,
+            emission_phase_ms: random_emission_phase(ctx, &world_coords, direction, 10000),
         };
 
         ctx.db.world_circuit().insert(circuit);
@@ -4564,66 +8193,24 @@ pub fn spawn_all_26_spires(
     world_y: i32,
     world_z: i32
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== SPAWN_ALL_26_SPIRES START ===");
     log::info!("World: ({}, {}, {})", world_x, world_y, world_z);
 
     let world_coords = WorldCoords { x: world_x, y: world_y, z: world_z };
-    const R: f32 = 300.0;
-    const SQRT2: f32 = 1.414213562373095;
-    const SQRT3: f32 = 1.732050807568877;
-
-    // All 26 spire positions with their names
-    let all_spires = vec![
-        // 6 Cardinal (face centers)
-        ("North", 0.0, R, 0.0, "Green"),
-        ("South", 0.0, -R, 0.0, "Green"),
-        ("East", R, 0.0, 0.0, "Red"),
-        ("West", -R, 0.0, 0.0, "Red"),
-        ("Forward", 0.0, 0.0, R, "Blue"),
-        ("Back", 0.0, 0.0, -R, "Blue"),
-        
-        // 12 Edge centers (between two cardinals)
-        ("NorthEast", R/SQRT2, R/SQRT2, 0.0, "Yellow"),
-        ("NorthWest", -R/SQRT2, R/SQRT2, 0.0, "Yellow"),
-        ("SouthEast", R/SQRT2, -R/SQRT2, 0.0, "Yellow"),
-        ("SouthWest", -R/SQRT2, -R/SQRT2, 0.0, "Yellow"),
-        ("NorthForward", 0.0, R/SQRT2, R/SQRT2, "Cyan"),
-        ("NorthBack", 0.0, R/SQRT2, -R/SQRT2, "Cyan"),
-        ("SouthForward", 0.0, -R/SQRT2, R/SQRT2, "Cyan"),
-        ("SouthBack", 0.0, -R/SQRT2, -R/SQRT2, "Cyan"),
-        ("EastForward", R/SQRT2, 0.0, R/SQRT2, "Magenta"),
-        ("EastBack", R/SQRT2, 0.0, -R/SQRT2, "Magenta"),
-        ("WestForward", -R/SQRT2, 0.0, R/SQRT2, "Magenta"),
-        ("WestBack", -R/SQRT2, 0.0, -R/SQRT2, "Magenta"),
-        
-        // 8 Vertex (corners - between three cardinals)
-        ("NorthEastForward", R/SQRT3, R/SQRT3, R/SQRT3, "White"),
-        ("NorthEastBack", R/SQRT3, R/SQRT3, -R/SQRT3, "White"),
-        ("NorthWestForward", -R/SQRT3, R/SQRT3, R/SQRT3, "White"),
-        ("NorthWestBack", -R/SQRT3, R/SQRT3, -R/SQRT3, "White"),
-        ("SouthEastForward", R/SQRT3, -R/SQRT3, R/SQRT3, "White"),
-        ("SouthEastBack", R/SQRT3, -R/SQRT3, -R/SQRT3, "White"),
-        ("SouthWestForward", -R/SQRT3, -R/SQRT3, R/SQRT3, "White"),
-        ("SouthWestBack", -R/SQRT3, -R/SQRT3, -R/SQRT3, "White"),
-    ];
+    let radii = world_radii(ctx, &world_coords);
 
     // Height constants for energy infrastructure
     const DISTRIBUTION_SPHERE_HEIGHT: f32 = 10.0;  // Distribution spheres at height 10
     const QUANTUM_TUNNEL_HEIGHT: f32 = 20.0;        // Quantum tunnels at height 20 (client-side positioning)
 
-    for (direction, x, y, z, color) in all_spires {
-        // Calculate surface normal (normalized direction from world center)
-        let length = (x * x + y * y + z * z).sqrt();
-        let normal_x = x / length;
-        let normal_y = y / length;
-        let normal_z = z / length;
+    for direction in ALL_DIRECTIONS {
+        // Surface normal and tier color from the shared direction table
+        let (normal, color, _) = direction_metadata(direction);
 
         // Distribution sphere position: surface + 10 units along normal
-        let sphere_position = DbVector3 {
-            x: x + normal_x * DISTRIBUTION_SPHERE_HEIGHT,
-            y: y + normal_y * DISTRIBUTION_SPHERE_HEIGHT,
-            z: z + normal_z * DISTRIBUTION_SPHERE_HEIGHT,
-        };
+        let sphere_position = normal.ellipsoid_project(&radii, DISTRIBUTION_SPHERE_HEIGHT);
 
         // Create DistributionSphere at height 10
         let sphere = DistributionSphere {
@@ -4635,6 +8222,7 @@ pub fn spawn_all_26_spires(
             packets_routed: 0,
             last_packet_time: ctx.timestamp,
             transit_buffer: Vec::new(),  // Start with empty buffer
+            buffer_capacity: SPHERE_BUFFER_CAPACITY,
         };
         ctx.db.distribution_sphere().insert(sphere);
 
@@ -4647,7 +8235,7 @@ pub fn spawn_all_26_spires(
             tunnel_status: "Inactive".to_string(),
             connected_to_world: None,
             connected_to_sphere_id: None,
-            tunnel_color: color.to_string(),
+            tunnel_color: color.clone(),
             formed_at: None,
         };
         ctx.db.quantum_tunnel().insert(tunnel);
@@ -4660,6 +8248,227 @@ pub fn spawn_all_26_spires(
     Ok(())
 }
 
+/// Recompute the world_summary row for a world
+/// Counts spheres, tunnels (total and active), circuits, and lifetime packets routed
+#[spacetimedb::reducer]
+pub fn refresh_world_summary(ctx: &ReducerContext, world_coords: WorldCoords) -> Result<(), String> {
+    let mut distribution_sphere_count = 0u32;
+    let mut total_packets_routed = 0u64;
+    for sphere in ctx.db.distribution_sphere().iter().filter(|s| s.world_coords == world_coords) {
+        distribution_sphere_count += 1;
+        total_packets_routed += sphere.packets_routed;
+    }
+
+    let mut quantum_tunnel_count = 0u32;
+    let mut active_tunnel_count = 0u32;
+    for tunnel in ctx.db.quantum_tunnel().iter().filter(|t| t.world_coords == world_coords) {
+        quantum_tunnel_count += 1;
+        if tunnel.tunnel_status == "Active" {
+            active_tunnel_count += 1;
+        }
+    }
+
+    let circuit_count = ctx.db.world_circuit()
+        .iter()
+        .filter(|c| c.world_coords == world_coords)
+        .count() as u32;
+
+    // Replace existing summary for this world
+    let existing: Vec<WorldSummary> = ctx.db.world_summary()
+        .iter()
+        .filter(|s| s.world_coords == world_coords)
+        .collect();
+    for summary in existing {
+        ctx.db.world_summary().delete(summary);
+    }
+
+    ctx.db.world_summary().insert(WorldSummary {
+        summary_id: 0, // auto_inc
+        world_coords,
+        distribution_sphere_count,
+        quantum_tunnel_count,
+        circuit_count,
+        active_tunnel_count,
+        total_packets_routed,
+        updated_at: ctx.timestamp,
+    });
+
+    log::info!("World ({},{},{}) summary: {} spheres, {} tunnels ({} active), {} circuits, {} packets routed",
+        world_coords.x, world_coords.y, world_coords.z,
+        distribution_sphere_count, quantum_tunnel_count, active_tunnel_count,
+        circuit_count, total_packets_routed);
+
+    Ok(())
+}
+
+/// Recompute the packet_economy singleton from all packet-holding tables
+#[spacetimedb::reducer]
+pub fn refresh_packet_economy(ctx: &ReducerContext) -> Result<(), String> {
+    let sum_samples = |samples: &[WavePacketSample]| samples.iter().map(|s| s.count as u64).sum::<u64>();
+
+    let total_in_inventories: u64 = ctx.db.player_inventory()
+        .iter()
+        .map(|inv| inv.total_count as u64)
+        .sum();
+    let total_in_storage: u64 = ctx.db.storage_device()
+        .iter()
+        .map(|d| sum_samples(&d.stored_composition))
+        .sum();
+
+    // Each open transfer is either waiting in a sphere buffer or travelling, never both
+    let mut total_in_flight: u64 = 0;
+    let mut total_in_sphere_buffers: u64 = 0;
+    for transfer in ctx.db.packet_transfer().iter().filter(|t| !t.completed) {
+        if buffered_at_sphere(&transfer).is_some() {
+            total_in_sphere_buffers += transfer.packet_count as u64;
+        } else {
+            total_in_flight += transfer.packet_count as u64;
+        }
+    }
+
+    let total_in_orbs: u64 = ctx.db.wave_packet_source()
+        .iter()
+        .map(|s| s.total_wave_packets as u64)
+        .sum();
+
+    ctx.db.packet_economy().id().delete(&0);
+    ctx.db.packet_economy().insert(PacketEconomy {
+        id: 0,
+        total_in_inventories,
+        total_in_storage,
+        total_in_flight,
+        total_in_orbs,
+        total_in_sphere_buffers,
+        updated_at: ctx.timestamp,
+    });
+
+    log::info!("Packet economy: inventories={}, storage={}, in flight={}, orbs={}, sphere buffers={}",
+        total_in_inventories, total_in_storage, total_in_flight, total_in_orbs, total_in_sphere_buffers);
+
+    Ok(())
+}
+
+/// Tear down a formed quantum tunnel and its partner
+/// Caller must own a storage device on one of the two linked worlds
+#[spacetimedb::reducer]
+pub fn disconnect_tunnel(ctx: &ReducerContext, tunnel_id: u64, drain_charge: bool) -> Result<(), String> {
+    log::info!("=== DISCONNECT_TUNNEL START ===");
+
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+
+    let tunnel = ctx.db.quantum_tunnel()
+        .tunnel_id()
+        .find(&tunnel_id)
+        .ok_or("Quantum tunnel not found")?;
+
+    let partner_world = tunnel.connected_to_world.ok_or("Tunnel is not connected")?;
+
+    // Partner is the tunnel behind connected_to_sphere_id, or failing that,
+    // whichever tunnel in the partner world links back to this one
+    let partner_direction = tunnel.connected_to_sphere_id
+        .and_then(|id| ctx.db.distribution_sphere().sphere_id().find(&id))
+        .map(|s| s.cardinal_direction);
+    let partner = ctx.db.quantum_tunnel().iter().find(|t| {
+        t.world_coords == partner_world &&
+            match &partner_direction {
+                Some(direction) => &t.cardinal_direction == direction,
+                None => t.connected_to_world == Some(tunnel.world_coords),
+            }
+    });
+
+    let owns_device = ctx.db.storage_device().iter().any(|d| {
+        d.owner_player_id == player.player_id &&
+            (d.world_coords == tunnel.world_coords || d.world_coords == partner_world)
+    });
+    if !owns_device {
+        return Err("You need a storage device on one of the linked worlds".to_string());
+    }
+
+    let reset = |t: &QuantumTunnel| {
+        let mut updated = t.clone();
+        updated.tunnel_status = "Inactive".to_string();
+        updated.connected_to_world = None;
+        updated.connected_to_sphere_id = None;
+        updated.formed_at = None;
+        if drain_charge {
+            updated.ring_charge = 0.0;
+        }
+        updated
+    };
+
+    ctx.db.quantum_tunnel().delete(tunnel.clone());
+    ctx.db.quantum_tunnel().insert(reset(&tunnel));
+
+    match partner {
+        Some(partner) => {
+            ctx.db.quantum_tunnel().delete(partner.clone());
+            ctx.db.quantum_tunnel().insert(reset(&partner));
+            log::info!("Disconnected tunnel {} from partner tunnel {}", tunnel.tunnel_id, partner.tunnel_id);
+        }
+        None => {
+            log::warn!("Tunnel {} had no partner in world ({},{},{})",
+                tunnel.tunnel_id, partner_world.x, partner_world.y, partner_world.z);
+        }
+    }
+
+    log::info!("=== DISCONNECT_TUNNEL END ===");
+    Ok(())
+}
+
+/// Admin: rebuild a sphere's transit buffer from the transfers waiting at it
+/// Oldest transfers are admitted first; packets past buffer_capacity are cut from the
+/// newest ones. Also clears packets left behind by transfers that already departed.
+#[spacetimedb::reducer]
+pub fn flush_sphere_buffer(ctx: &ReducerContext, sphere_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let sphere = ctx.db.distribution_sphere()
+        .sphere_id()
+        .find(&sphere_id)
+        .ok_or("Distribution sphere not found")?;
+
+    let mut waiting: Vec<PacketTransfer> = ctx.db.packet_transfer()
+        .iter()
+        .filter(|t| buffered_at_sphere(t) == Some(sphere_id))
+        .collect();
+    waiting.sort_by_key(|t| t.transfer_id);
+
+    let mut updated_sphere = sphere.clone();
+    updated_sphere.transit_buffer = Vec::new();
+    let mut dropped = 0u32;
+
+    for transfer in &waiting {
+        let mut updated_transfer = transfer.clone();
+        let cut = add_to_sphere_buffer(&mut updated_sphere, &mut updated_transfer.composition);
+        if cut == 0 {
+            continue;
+        }
+        dropped += cut;
+
+        if updated_transfer.composition.is_empty() {
+            finish_dropped_transfer(ctx, transfer, sphere.sphere_position);
+        } else {
+            updated_transfer.packet_count = composition::total_count(&updated_transfer.composition);
+            ctx.db.packet_transfer().delete(transfer.clone());
+            ctx.db.packet_transfer().insert(updated_transfer);
+        }
+    }
+
+    log::info!("Sphere {} buffer rebuilt from {} waiting transfers: {} packets held (was {}), {} dropped, capacity {}",
+        sphere_id, waiting.len(),
+        composition::total_count(&updated_sphere.transit_buffer),
+        composition::total_count(&sphere.transit_buffer),
+        dropped, sphere.buffer_capacity);
+
+    ctx.db.distribution_sphere().delete(sphere);
+    ctx.db.distribution_sphere().insert(updated_sphere);
+
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn debug_create_storage_device(
     ctx: &ReducerContext,
@@ -4669,6 +8478,8 @@ pub fn debug_create_storage_device(
     z: f32,
     device_name: String
 ) -> Result<(), String> {
+    require_admin(ctx)?;
+
     log::info!("=== DEBUG_CREATE_STORAGE_DEVICE START ===");
     log::info!("Creating storage device for player {}: {} at ({}, {}, {})", 
         player_id, device_name, x, y, z);
@@ -4685,9 +8496,10 @@ pub fn debug_create_storage_device(
         world_coords: player.current_world,
         position: DbVector3 { x, y, z },
         device_name,
-        capacity_per_frequency: 1000,  // Default 1000 per frequency, 6000 total
+        capacity_per_frequency: DEFAULT_STORAGE_CAPACITY_PER_FREQUENCY,
         stored_composition: Vec::new(),
         created_at: ctx.timestamp,
+        per_frequency_capacity: Vec::new(),
     };
 
     ctx.db.storage_device().insert(device);
@@ -4709,6 +8521,39 @@ pub struct GameLoopSchedule {
     pub scheduled_at: ScheduleAt,
 }
 
+/// Last run times (ms since epoch) of periodic cleanup jobs
+/// Stored in a table so the cadence survives module republish
+#[spacetimedb::table(name = maintenance_schedule, public)]
+#[derive(Debug, Clone)]
+pub struct MaintenanceSchedule {
+    #[primary_key]
+    pub id: u32,  // Always 0 for singleton
+    pub last_session_cleanup: u64,
+    pub last_extraction_cleanup: u64,
+    pub last_logged_out_cleanup: u64,
+    pub last_mining_session_cleanup: u64,
+}
+
+/// Runtime log verbosity (singleton, id 0), edited via set_log_level
+/// Missing row means "info"
+#[spacetimedb::table(name = log_config, public)]
+#[derive(Debug, Clone)]
+pub struct LogConfig {
+    #[primary_key]
+    pub id: u32,  // Always 0 for singleton
+    pub level: String,  // "debug", "info" or "warn" - warnings and errors are always logged
+    pub updated_at: Timestamp,
+}
+
+/// Monotonic counter mixed into spawn RNG seeds so spawns in the same instant differ
+#[spacetimedb::table(name = spawn_counter, public)]
+#[derive(Debug, Clone)]
+pub struct SpawnCounter {
+    #[primary_key]
+    pub id: u32,  // Always 0 for singleton counter
+    pub count: u64,
+}
+
 /// Game tick counter for multi-clock timing
 #[spacetimedb::table(name = game_tick_counter, public)]
 #[derive(Debug, Clone)]
@@ -4716,57 +8561,331 @@ pub struct GameTickCounter {
     #[primary_key]
     pub id: u32,  // Always 0 for singleton counter
     pub tick_count: u64,
+    pub idle_ticks: u32,   // Consecutive ticks with nothing to process
+    pub idle_mode: bool,   // Loop is running at GAME_LOOP_IDLE_INTERVAL_MS
+}
+
+/// Per-world tick counter - each world runs its pulses off its own count
+#[spacetimedb::table(name = world_tick_counter, public)]
+#[derive(Debug, Clone)]
+pub struct WorldTickCounter {
+    #[primary_key]
+    pub world_id: u64,
+    pub world_coords: WorldCoords,
+    pub tick_count: u64,
+}
+
+/// Dedicated schedule for a world that ticks independently of the global game loop
+/// Worlds without a row here are ticked by game_loop's fan-out
+#[spacetimedb::table(name = world_game_loop_schedule, public, scheduled(world_game_loop))]
+#[derive(Debug, Clone)]
+pub struct WorldGameLoopSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub world_id: u64,
+}
+
+/// Main game loop reducer - runs at 10Hz (100ms intervals), 1Hz while idle
+/// Tick counts are in 100ms ticks: an idle-mode pass advances them by idle_tick_step(),
+/// so the 2s/10s pulses keep their wall-clock cadence at either speed
+/// Implements multi-clock system:
+/// - Every pass: process_world_tick() for each world without its own schedule
+/// - Every 20 ticks (2 seconds): transfer event cleanup
+/// - Every 100 ticks (10 seconds): tunnel formation, chat cleanup
+#[spacetimedb::reducer]
+pub fn game_loop(ctx: &ReducerContext, _arg: GameLoopSchedule) -> Result<(), String> {
+    LOG_LEVEL_APPLIED.get_or_init(|| apply_log_level(ctx));
+
+    // Get or initialize tick counter
+    let counter = ctx.db.game_tick_counter().id().find(&0).unwrap_or(GameTickCounter {
+        id: 0,
+        tick_count: 0,
+        idle_ticks: 0,
+        idle_mode: false,
+    });
+    // The interval that just elapsed is the one the previous pass scheduled
+    let step = idle_tick_step(counter.idle_mode);
+    let tick_count = counter.tick_count + step;
+
+    // Slow down after a sustained idle period, snap back as soon as anything is active
+    let (idle_ticks, idle_mode) = next_idle_state(counter.idle_ticks, game_is_idle(ctx));
+    if idle_mode != counter.idle_mode {
+        if idle_mode {
+            log::info!("[GameLoop] Idle for {} ticks, slowing to {}ms", idle_ticks, GAME_LOOP_IDLE_INTERVAL_MS);
+            reschedule_game_loop(ctx, GAME_LOOP_IDLE_INTERVAL_MS);
+        } else {
+            log::info!("[GameLoop] Activity detected, resuming {}ms ticks", GAME_LOOP_ACTIVE_INTERVAL_MS);
+            reschedule_game_loop(ctx, GAME_LOOP_ACTIVE_INTERVAL_MS);
+        }
+    }
+
+    ctx.db.game_tick_counter().id().delete(&0);
+    ctx.db.game_tick_counter().insert(GameTickCounter {
+        id: 0,
+        tick_count,
+        idle_ticks,
+        idle_mode,
+    });
+
+    // Fan out to every world that doesn't tick on its own world_game_loop_schedule
+    let dedicated: std::collections::HashSet<u64> = ctx.db.world_game_loop_schedule()
+        .iter()
+        .map(|s| s.world_id)
+        .collect();
+    let worlds: Vec<World> = ctx.db.world()
+        .iter()
+        .filter(|w| !dedicated.contains(&w.world_id))
+        .collect();
+    // One spawn budget for the whole fan-out, not one per world
+    let mut emissions = EmissionPass::new();
+    for world in worlds {
+        process_world_tick(ctx, &world, &mut emissions, step)?;
+    }
+
+    // Session/extraction cleanup when their intervals have elapsed
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+    run_scheduled_maintenance(ctx, current_time)?;
+
+    // Credit arrived extractions for players who opted out of client capture
+    process_auto_captures(ctx, current_time);
+
+    // Clean up transfer and orb events clients have had time to see (every 20 ticks)
+    if pulse_due(counter.tick_count, tick_count, 20) {
+        cleanup_expired_transfer_events(ctx);
+        cleanup_expired_orb_depleted_events(ctx);
+        cleanup_expired_orb_spawn_events(ctx);
+    }
+
+    // Cross-world work on the ten-second cadence (every 100 ticks)
+    if pulse_due(counter.tick_count, tick_count, 100) {
+        // Link fully charged tunnels to their counterparts in neighboring worlds
+        process_tunnel_formation(ctx)?;
+        // Clean up expired chat messages
+        cleanup_expired_broadcast_messages(ctx);
+        cleanup_expired_player_chat_messages(ctx);
+    }
+
+    Ok(())
 }
 
-/// Main game loop reducer - runs at 10Hz (100ms intervals)
-/// Implements multi-clock system:
-/// - Every 100ms: Check arrivals via process_packet_transfers()
-/// - Every 20 ticks (2 seconds): Object↔Sphere departures
-/// - Every 100 ticks (10 seconds): Sphere↔Sphere departures
+/// Scheduled tick for a world with its own world_game_loop_schedule row
 #[spacetimedb::reducer]
-pub fn game_loop(ctx: &ReducerContext, _arg: GameLoopSchedule) -> Result<(), String> {
-    // Get or initialize tick counter
-    let tick_count = match ctx.db.game_tick_counter().id().find(&0) {
-        Some(counter) => {
-            let new_count = counter.tick_count + 1;
-            let mut updated = counter.clone();
-            updated.tick_count = new_count;
-            ctx.db.game_tick_counter().delete(counter);
-            ctx.db.game_tick_counter().insert(updated);
-            new_count
-        }
+pub fn world_game_loop(ctx: &ReducerContext, arg: WorldGameLoopSchedule) -> Result<(), String> {
+    let world = match ctx.db.world().world_id().find(&arg.world_id) {
+        Some(world) => world,
         None => {
-            // Initialize counter
-            ctx.db.game_tick_counter().insert(GameTickCounter {
-                id: 0,
-                tick_count: 1,
-            });
-            1
+            // World is gone - drop its schedule
+            log::warn!("[WorldLoop] World {} not found, removing its schedule", arg.world_id);
+            ctx.db.world_game_loop_schedule().delete(arg);
+            return Ok(());
         }
     };
 
+    process_world_tick(ctx, &world, &mut EmissionPass::new(), 1)
+}
+
+/// One pass of a single world, advancing that world's own tick counter by `step` ticks:
+/// - Every pass: arrivals via process_packet_transfers(), source movement
+/// - Every 20 world ticks (2 seconds): Object↔Sphere departures, circuit emission
+/// - Every 100 world ticks (10 seconds): Sphere↔Sphere departures, dissipation, throughput
+fn process_world_tick(ctx: &ReducerContext, world: &World, emissions: &mut EmissionPass, step: u64) -> Result<(), String> {
+    let existing = ctx.db.world_tick_counter().world_id().find(&world.world_id);
+    let previous_tick = existing.as_ref().map_or(0, |counter| counter.tick_count);
+    let counter = next_world_tick_counter(existing.as_ref(), world, step);
+    let tick_count = counter.tick_count;
+    ctx.db.world_tick_counter().world_id().delete(&world.world_id);
+    ctx.db.world_tick_counter().insert(counter);
+
     // Process packet arrivals EVERY tick (100ms granularity for accurate arrival timing)
-    process_packet_transfers(ctx)?;
+    process_packet_transfers(ctx, &world.world_coords)?;
 
     // Process source movement EVERY tick (smooth movement at 10Hz)
-    process_source_movement(ctx);
+    process_source_movement(ctx, world);
 
     // Two-second pulse: Object↔Sphere departures (every 20 ticks)
-    if tick_count % 20 == 0 {
-        two_second_pulse(ctx)?;
+    if pulse_due(previous_tick, tick_count, 20) {
+        two_second_pulse(ctx, world, emissions)?;
     }
 
     // Ten-second pulse: Sphere↔Sphere departures (every 100 ticks)
-    if tick_count % 100 == 0 {
-        ten_second_pulse(ctx)?;
-        // Clean up expired chat messages
-        cleanup_expired_broadcast_messages(ctx);
-        cleanup_expired_player_chat_messages(ctx);
+    if pulse_due(previous_tick, tick_count, 100) {
+        ten_second_pulse(ctx, &world.world_coords)?;
+    }
+
+    Ok(())
+}
+
+/// A world's counter after `step` more ticks - counts from 0 for a world that has never ticked
+fn next_world_tick_counter(counter: Option<&WorldTickCounter>, world: &World, step: u64) -> WorldTickCounter {
+    match counter {
+        Some(counter) => WorldTickCounter {
+            tick_count: counter.tick_count + step,
+            ..counter.clone()
+        },
+        None => WorldTickCounter {
+            world_id: world.world_id,
+            world_coords: world.world_coords,
+            tick_count: step,
+        },
+    }
+}
+
+/// Whether a pulse every `every` ticks falls in (previous_tick, tick_count]
+fn pulse_due(previous_tick: u64, tick_count: u64, every: u64) -> bool {
+    tick_count / every > previous_tick / every
+}
+
+/// How many 100ms ticks one game loop pass stands for at the current speed
+fn idle_tick_step(idle_mode: bool) -> u64 {
+    if idle_mode {
+        GAME_LOOP_IDLE_INTERVAL_MS / GAME_LOOP_ACTIVE_INTERVAL_MS
+    } else {
+        1
+    }
+}
+
+/// Idle tick count and idle mode after a game loop pass
+/// Any activity resets the count and leaves idle mode immediately
+fn next_idle_state(idle_ticks: u32, is_idle: bool) -> (u32, bool) {
+    let idle_ticks = if is_idle { idle_ticks.saturating_add(1) } else { 0 };
+    (idle_ticks, idle_ticks >= GAME_LOOP_IDLE_TICKS)
+}
+
+/// ADMIN: Give a world its own tick schedule, detaching it from the global fan-out
+#[spacetimedb::reducer]
+pub fn start_world_game_loop(ctx: &ReducerContext, world_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    ctx.db.world().world_id().find(&world_id)
+        .ok_or(GameError::NotFound("World".to_string()))?;
+
+    if ctx.db.world_game_loop_schedule().iter().any(|s| s.world_id == world_id) {
+        return Err(GameError::InvalidState("World already has its own game loop".to_string()).into());
+    }
+
+    ctx.db.world_game_loop_schedule().insert(WorldGameLoopSchedule {
+        scheduled_id: 0, // auto_inc will assign
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(GAME_LOOP_ACTIVE_INTERVAL_MS).into()),
+        world_id,
+    });
+
+    log::info!("World {} now ticks on its own schedule ({}ms)", world_id, GAME_LOOP_ACTIVE_INTERVAL_MS);
+    Ok(())
+}
+
+/// ADMIN: Remove a world's own tick schedule, returning it to the global fan-out
+#[spacetimedb::reducer]
+pub fn stop_world_game_loop(ctx: &ReducerContext, world_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let schedules: Vec<WorldGameLoopSchedule> = ctx.db.world_game_loop_schedule()
+        .iter()
+        .filter(|s| s.world_id == world_id)
+        .collect();
+    if schedules.is_empty() {
+        return Err(GameError::NotFound("World game loop".to_string()).into());
+    }
+    for schedule in schedules {
+        ctx.db.world_game_loop_schedule().delete(schedule);
+    }
+
+    log::info!("World {} returned to the global game loop", world_id);
+    Ok(())
+}
+
+/// Run cleanup jobs whose interval has elapsed, recording run times in maintenance_schedule
+fn run_scheduled_maintenance(ctx: &ReducerContext, current_time: u64) -> Result<(), String> {
+    let schedule = ctx.db.maintenance_schedule().id().find(&0).unwrap_or(MaintenanceSchedule {
+        id: 0,
+        last_session_cleanup: 0,
+        last_extraction_cleanup: 0,
+        last_logged_out_cleanup: 0,
+        last_mining_session_cleanup: 0,
+    });
+
+    let mut updated = schedule.clone();
+
+    if current_time >= schedule.last_session_cleanup + SESSION_CLEANUP_INTERVAL_MS {
+        cleanup_expired_sessions(ctx)?;
+        updated.last_session_cleanup = current_time;
+    }
+
+    if current_time >= schedule.last_extraction_cleanup + EXTRACTION_CLEANUP_INTERVAL_MS {
+        cleanup_old_extractions(ctx)?;
+        updated.last_extraction_cleanup = current_time;
+    }
+
+    if current_time >= schedule.last_logged_out_cleanup + LOGGED_OUT_CLEANUP_INTERVAL_MS {
+        purge_stale_logged_out_players(ctx, LOGGED_OUT_RETENTION_DAYS);
+        updated.last_logged_out_cleanup = current_time;
+    }
+
+    if current_time >= schedule.last_mining_session_cleanup + MINING_SESSION_CLEANUP_INTERVAL_MS {
+        purge_inactive_mining_sessions(ctx, INACTIVE_MINING_SESSION_RETENTION_MS);
+        updated.last_mining_session_cleanup = current_time;
+    }
+
+    if updated.last_session_cleanup != schedule.last_session_cleanup ||
+        updated.last_extraction_cleanup != schedule.last_extraction_cleanup ||
+        updated.last_logged_out_cleanup != schedule.last_logged_out_cleanup ||
+        updated.last_mining_session_cleanup != schedule.last_mining_session_cleanup
+    {
+        ctx.db.maintenance_schedule().id().delete(&0);
+        ctx.db.maintenance_schedule().insert(updated);
     }
 
     Ok(())
 }
 
+/// True when nothing needs full-speed ticks: no players online and no transfers in flight
+/// Unlike the original "no sources either" idea, orbs don't keep the loop awake: circuits
+/// keep every world stocked with orbs, so the loop would never slow down. Orb movement and
+/// expiry are timestamp-based and keep working at the idle interval
+fn game_is_idle(ctx: &ReducerContext) -> bool {
+    ctx.db.player().iter().next().is_none()
+        && !ctx.db.packet_transfer().iter().any(|t| !t.completed)
+}
+
+/// Replace the game loop schedule with one at the given interval
+fn reschedule_game_loop(ctx: &ReducerContext, interval_ms: u64) {
+    let schedules: Vec<GameLoopSchedule> = ctx.db.game_loop_schedule().iter().collect();
+    for schedule in schedules {
+        ctx.db.game_loop_schedule().delete(schedule);
+    }
+
+    ctx.db.game_loop_schedule().insert(GameLoopSchedule {
+        scheduled_id: 0, // auto_inc will assign
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(interval_ms).into()),
+    });
+}
+
+/// Snap an idle-slowed game loop back to full speed (player joined, transfer, mining or capture started)
+/// Does nothing if the loop is already at full speed or stopped
+fn wake_game_loop(ctx: &ReducerContext) {
+    let counter = match ctx.db.game_tick_counter().id().find(&0) {
+        Some(counter) if counter.idle_mode => counter,
+        _ => return,
+    };
+    if ctx.db.game_loop_schedule().iter().next().is_none() {
+        return;
+    }
+
+    reschedule_game_loop(ctx, GAME_LOOP_ACTIVE_INTERVAL_MS);
+
+    let mut updated = counter.clone();
+    updated.idle_ticks = 0;
+    updated.idle_mode = false;
+    ctx.db.game_tick_counter().delete(counter);
+    ctx.db.game_tick_counter().insert(updated);
+
+    log::info!("[GameLoop] Woken from idle, resuming {}ms ticks", GAME_LOOP_ACTIVE_INTERVAL_MS);
+}
+
 /// Start the game loop
 #[spacetimedb::reducer]
 pub fn start_game_loop(ctx: &ReducerContext) -> Result<(), String> {
@@ -4775,10 +8894,19 @@ pub fn start_game_loop(ctx: &ReducerContext) -> Result<(), String> {
         return Err("Game loop is already running".to_string());
     }
 
+    // Always start at full speed; clear any idle state left from a previous run
+    if let Some(counter) = ctx.db.game_tick_counter().id().find(&0) {
+        let mut updated = counter.clone();
+        updated.idle_ticks = 0;
+        updated.idle_mode = false;
+        ctx.db.game_tick_counter().delete(counter);
+        ctx.db.game_tick_counter().insert(updated);
+    }
+
     // Initialize schedule
     ctx.db.game_loop_schedule().insert(GameLoopSchedule {
         scheduled_id: 0, // auto_inc will assign
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(100).into()),
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(GAME_LOOP_ACTIVE_INTERVAL_MS).into()),
     });
 
     log::info!("Game loop started at 10Hz (100ms intervals)");
@@ -4800,13 +8928,171 @@ pub fn stop_game_loop(ctx: &ReducerContext) -> Result<(), String> {
 // Packet Transfer Processing
 // ============================================================================
 
+/// Record a transfer lifecycle event for clients
+fn record_transfer_event(ctx: &ReducerContext, transfer_id: u64, event_type: &str, position: DbVector3) {
+    ctx.db.transfer_event().insert(TransferEvent {
+        event_id: 0, // auto_inc
+        transfer_id,
+        event_type: event_type.to_string(),
+        position,
+        timestamp: ctx.timestamp,
+    });
+}
+
+/// transfer_log row for a transfer completed at `completed_at`, credited to the sending player
+fn transfer_log_entry(transfer: &PacketTransfer, completed_at: Timestamp) -> TransferLog {
+    let player_id = if transfer.source_object_type == "Player" {
+        transfer.source_object_id
+    } else {
+        transfer.player_id
+    };
+
+    let actual_duration_ms = completed_at
+        .duration_since(transfer.initiated_at)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64);
+
+    TransferLog {
+        log_id: 0, // auto_inc
+        player_id,
+        transfer_id: transfer.transfer_id,
+        composition: transfer.composition.clone(),
+        packet_count: transfer.packet_count,
+        source_type: transfer.source_object_type.clone(),
+        dest_type: transfer.destination_object_type.clone(),
+        completed_at,
+        actual_duration_ms,
+        hop_count: transfer.route_spire_ids.len() as u32,
+    }
+}
+
+/// Append a completed transfer to transfer_log, pruning the player's oldest entries
+fn record_transfer_log(ctx: &ReducerContext, transfer: &PacketTransfer) {
+    let entry = transfer_log_entry(transfer, ctx.timestamp);
+    let player_id = entry.player_id;
+    ctx.db.transfer_log().insert(entry);
+
+    let mut entries: Vec<TransferLog> = ctx.db.transfer_log()
+        .iter()
+        .filter(|e| e.player_id == player_id)
+        .collect();
+    if entries.len() > TRANSFER_LOG_MAX_PER_PLAYER {
+        entries.sort_by_key(|e| e.log_id);
+        let excess = entries.len() - TRANSFER_LOG_MAX_PER_PLAYER;
+        for entry in entries.into_iter().take(excess) {
+            ctx.db.transfer_log().delete(entry);
+        }
+    }
+}
+
+/// Write the caller's most recent transfer_log entries (newest first) to transfer_history
+#[spacetimedb::reducer]
+pub fn get_transfer_history(ctx: &ReducerContext, limit: u32) -> Result<(), String> {
+    let player = ctx.db.player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or(GameError::NotFound("Player".to_string()))?;
+
+    let mut entries: Vec<TransferLog> = ctx.db.transfer_log()
+        .iter()
+        .filter(|e| e.player_id == player.player_id)
+        .collect();
+    entries.sort_by(|a, b| b.log_id.cmp(&a.log_id));
+    entries.truncate(limit as usize);
+
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.transfer_history().requester().find(&ctx.sender) {
+        ctx.db.transfer_history().delete(existing);
+    }
+
+    ctx.db.transfer_history().insert(TransferHistory {
+        requester: ctx.sender,
+        entries,
+        refreshed_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Record that an orb emptied out, at its last position
+fn record_orb_depleted_event(ctx: &ReducerContext, source: &WavePacketSource, last_miner_player_id: Option<u64>) {
+    ctx.db.orb_depleted_event().insert(OrbDepletedEvent {
+        event_id: 0, // auto_inc
+        source_id: source.source_id,
+        world_coords: source.world_coords,
+        position: source.position,
+        last_miner_player_id,
+        timestamp: ctx.timestamp,
+    });
+}
+
+/// Record that a circuit emitted an orb, at its spawn position
+fn record_orb_spawn_event(ctx: &ReducerContext, source: &WavePacketSource, circuit_id: u64) {
+    ctx.db.orb_spawn_event().insert(OrbSpawnEvent {
+        event_id: 0, // auto_inc
+        source_id: source.source_id,
+        circuit_id,
+        world_coords: source.world_coords,
+        position: source.position,
+        composition: source.wave_packet_composition.clone(),
+        timestamp: ctx.timestamp,
+    });
+}
+
+/// Remove orb spawn events older than ORB_SPAWN_EVENT_TTL_MS
+fn cleanup_expired_orb_spawn_events(ctx: &ReducerContext) {
+    let expired: Vec<OrbSpawnEvent> = ctx.db.orb_spawn_event()
+        .iter()
+        .filter(|e| {
+            ctx.timestamp
+                .duration_since(e.timestamp)
+                .map_or(false, |age| age.as_millis() as u64 >= ORB_SPAWN_EVENT_TTL_MS)
+        })
+        .collect();
+
+    for event in expired {
+        ctx.db.orb_spawn_event().delete(event);
+    }
+}
+
+/// Remove orb depletion events older than ORB_DEPLETED_EVENT_TTL_MS
+fn cleanup_expired_orb_depleted_events(ctx: &ReducerContext) {
+    let expired: Vec<OrbDepletedEvent> = ctx.db.orb_depleted_event()
+        .iter()
+        .filter(|e| {
+            ctx.timestamp
+                .duration_since(e.timestamp)
+                .map_or(false, |age| age.as_millis() as u64 >= ORB_DEPLETED_EVENT_TTL_MS)
+        })
+        .collect();
+
+    for event in expired {
+        ctx.db.orb_depleted_event().delete(event);
+    }
+}
+
+/// Remove transfer events older than TRANSFER_EVENT_TTL_MS
+fn cleanup_expired_transfer_events(ctx: &ReducerContext) {
+    let expired: Vec<TransferEvent> = ctx.db.transfer_event()
+        .iter()
+        .filter(|e| {
+            ctx.timestamp
+                .duration_since(e.timestamp)
+                .map_or(false, |age| age.as_millis() as u64 >= TRANSFER_EVENT_TTL_MS)
+        })
+        .collect();
+
+    for event in expired {
+        ctx.db.transfer_event().delete(event);
+    }
+}
+
 /// Process all packet transfers - check for arrivals based on predicted_arrival_time
 /// Runs every 100ms to catch arrivals with high precision
-fn process_packet_transfers(ctx: &ReducerContext) -> Result<(), String> {
+fn process_packet_transfers(ctx: &ReducerContext, world_coords: &WorldCoords) -> Result<(), String> {
     let now = ctx.timestamp;
 
     for transfer in ctx.db.packet_transfer().iter() {
-        if transfer.completed {
+        if transfer.completed || transfer.world_coords != *world_coords {
             continue;
         }
 
@@ -4849,23 +9135,32 @@ fn process_object_to_sphere_arrival(ctx: &ReducerContext, transfer: &PacketTrans
         .find(&sphere_id)
         .ok_or(format!("First sphere {} not found", sphere_id))?;
 
-    // Add packets to sphere's transit buffer
+    // Add packets to sphere's transit buffer - anything over its capacity is lost from the transfer
+    let mut updated_transfer = transfer.clone();
     let mut updated_sphere = sphere.clone();
-    add_to_buffer(&mut updated_sphere.transit_buffer, &transfer.composition);
-    updated_sphere.packets_routed += transfer.packet_count as u64;
+    add_to_sphere_buffer(&mut updated_sphere, &mut updated_transfer.composition);
+    updated_transfer.packet_count = composition::total_count(&updated_transfer.composition);
+    updated_sphere.packets_routed += updated_transfer.packet_count as u64;
     updated_sphere.last_packet_time = now;
+    let updated_sphere_position = updated_sphere.sphere_position;
 
     ctx.db.distribution_sphere().delete(sphere);
     ctx.db.distribution_sphere().insert(updated_sphere);
 
+    if updated_transfer.composition.is_empty() {
+        finish_dropped_transfer(ctx, transfer, updated_sphere_position);
+        return Ok(());
+    }
+
     // Mark transfer as arrived, waiting for departure pulse
-    let mut updated_transfer = transfer.clone();
     updated_transfer.current_leg_type = "ArrivedAtSphere".to_string();
     updated_transfer.predicted_arrival_time = Timestamp::UNIX_EPOCH; // Clear arrival time
 
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(updated_transfer);
 
+    record_transfer_event(ctx, transfer.transfer_id, "ArrivedAtSphere", updated_sphere_position);
+
     log::info!("[Arrival] Transfer {} arrived at sphere {} - waiting for departure pulse",
         transfer.transfer_id, sphere_id);
 
@@ -4888,29 +9183,65 @@ fn process_sphere_to_sphere_arrival(ctx: &ReducerContext, transfer: &PacketTrans
         .find(&sphere_id)
         .ok_or(format!("Sphere {} not found", sphere_id))?;
 
-    // Add packets to sphere's transit buffer
+    // Add packets to sphere's transit buffer - anything over its capacity is lost from the transfer
+    let mut updated_transfer = transfer.clone();
     let mut updated_sphere = sphere.clone();
-    add_to_buffer(&mut updated_sphere.transit_buffer, &transfer.composition);
-    updated_sphere.packets_routed += transfer.packet_count as u64;
+    add_to_sphere_buffer(&mut updated_sphere, &mut updated_transfer.composition);
+    updated_transfer.packet_count = composition::total_count(&updated_transfer.composition);
+    updated_sphere.packets_routed += updated_transfer.packet_count as u64;
     updated_sphere.last_packet_time = now;
+    let updated_sphere_position = updated_sphere.sphere_position;
 
     ctx.db.distribution_sphere().delete(sphere);
     ctx.db.distribution_sphere().insert(updated_sphere);
 
+    if updated_transfer.composition.is_empty() {
+        finish_dropped_transfer(ctx, transfer, updated_sphere_position);
+        return Ok(());
+    }
+
     // Mark transfer as arrived, waiting for departure pulse
-    let mut updated_transfer = transfer.clone();
     updated_transfer.current_leg_type = "ArrivedAtSphere".to_string();
     updated_transfer.predicted_arrival_time = Timestamp::UNIX_EPOCH; // Clear arrival time
 
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(updated_transfer);
 
+    record_transfer_event(ctx, transfer.transfer_id, "ArrivedAtSphere", updated_sphere_position);
+
     log::info!("[Arrival] Transfer {} arrived at sphere {} - waiting for departure pulse",
         transfer.transfer_id, sphere_id);
 
     Ok(())
 }
 
+/// Split an arriving composition into what a storage device accepts and what it
+/// rejects, by free room per frequency. Frequencies `allowed` rejects have no room
+fn split_storage_delivery(
+    storage: &StorageDevice,
+    samples: &[WavePacketSample],
+    allowed: impl Fn(f32) -> bool,
+) -> (Vec<WavePacketSample>, Vec<WavePacketSample>) {
+    let mut accepted = Vec::new();
+    let mut overflow = Vec::new();
+    for sample in samples {
+        let room = if allowed(sample.frequency) {
+            storage_capacity_for(storage, sample.frequency)
+                .saturating_sub(stored_at_frequency(storage, sample.frequency))
+        } else {
+            0
+        };
+        let fits = sample.count.min(room);
+        if fits > 0 {
+            accepted.push(WavePacketSample { count: fits, ..sample.clone() });
+        }
+        if sample.count > fits {
+            overflow.push(WavePacketSample { count: sample.count - fits, ..sample.clone() });
+        }
+    }
+    (accepted, overflow)
+}
+
 /// Handle Sphere→Object ARRIVALS (final delivery to player, storage, miner, etc.)
 fn process_sphere_to_object_arrival(ctx: &ReducerContext, transfer: &PacketTransfer) -> Result<(), String> {
     let now = ctx.timestamp;
@@ -4923,12 +9254,31 @@ fn process_sphere_to_object_arrival(ctx: &ReducerContext, transfer: &PacketTrans
                 .find(&transfer.destination_object_id)
                 .ok_or(format!("StorageDevice {} not found", transfer.destination_object_id))?;
 
+            // Other transfers may have filled the device (or the world's allowed
+            // frequencies changed) since this one was sent - accept what fits and
+            // hand the rest back to the sender
+            let (accepted, overflow) = split_storage_delivery(&storage, &transfer.composition,
+                |frequency| world_allows_frequency(ctx, &storage.world_coords, frequency));
+
             let mut updated_storage = storage.clone();
-            add_to_buffer(&mut updated_storage.stored_composition, &transfer.composition);
+            composition::merge_compositions(&mut updated_storage.stored_composition, &accepted);
 
             ctx.db.storage_device().delete(storage);
             ctx.db.storage_device().insert(updated_storage);
 
+            if !overflow.is_empty() {
+                let overflow_total = composition::total_count(&overflow);
+                let returned = refund_to_transfer_source(ctx, transfer, &overflow);
+                log::warn!("[Arrival] StorageDevice {} rejected {} packets (full or disallowed), {} returned to sender",
+                    transfer.destination_object_id, overflow_total, returned);
+            }
+
+            if transfer.source_object_type == "Player" {
+                update_player_stats(ctx, transfer.source_object_id, |stats| {
+                    stats.total_transferred += transfer.packet_count as u64;
+                });
+            }
+
             log::info!("[Arrival] Delivered {} packets to StorageDevice {}",
                 transfer.packet_count, transfer.destination_object_id);
         }
@@ -4953,6 +9303,11 @@ fn process_sphere_to_object_arrival(ctx: &ReducerContext, transfer: &PacketTrans
 
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(completed_transfer);
+    record_transfer_log(ctx, transfer);
+
+    // Final waypoint is the destination object's position
+    let delivered_position = transfer.route_waypoints.last().copied().unwrap_or(DbVector3::zero());
+    record_transfer_event(ctx, transfer.transfer_id, "Delivered", delivered_position);
 
     log::info!("[Arrival] Transfer {} completed - delivered to {} {}",
         transfer.transfer_id, transfer.destination_object_type, transfer.destination_object_id);
@@ -4964,18 +9319,27 @@ fn process_sphere_to_object_arrival(ctx: &ReducerContext, transfer: &PacketTrans
 // Pulse Functions (Departures)
 // ============================================================================
 
-/// Two-second pulse: Process Object→Sphere and Sphere→Object DEPARTURES
+/// Two-second pulse: Process Object→Sphere and Sphere→Object DEPARTURES + Circuit Emission
 /// Called every 20 ticks (2 seconds)
-fn two_second_pulse(ctx: &ReducerContext) -> Result<(), String> {
+fn two_second_pulse(ctx: &ReducerContext, world: &World, emissions: &mut EmissionPass) -> Result<(), String> {
+    let world_coords = &world.world_coords;
     let now = ctx.timestamp;
 
-    log::info!("[2s Pulse] Processing Object→Sphere and Sphere→Object departures");
+    log::info!("[2s Pulse] World ({},{},{}): processing Object→Sphere and Sphere→Object departures",
+        world_coords.x, world_coords.y, world_coords.z);
 
     // Process all transfers pending at source objects for Object→Sphere departure
     // LIMIT: Only one transfer per source object per pulse
     let mut departed_sources: std::collections::HashSet<(String, u64)> = std::collections::HashSet::new();
-    
-    for transfer in ctx.db.packet_transfer().iter() {
+
+    // Highest priority first, oldest first within the same priority
+    let mut pending: Vec<PacketTransfer> = ctx.db.packet_transfer()
+        .iter()
+        .filter(|t| t.world_coords == *world_coords)
+        .collect();
+    pending.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.transfer_id.cmp(&b.transfer_id)));
+
+    for transfer in pending {
         if transfer.completed {
             continue;
         }
@@ -5003,7 +9367,7 @@ fn two_second_pulse(ctx: &ReducerContext) -> Result<(), String> {
 
     // Process all transfers waiting at spheres for Sphere→Object departure (final leg)
     for transfer in ctx.db.packet_transfer().iter() {
-        if transfer.completed || transfer.current_leg_type != "ArrivedAtSphere" {
+        if transfer.completed || transfer.current_leg_type != "ArrivedAtSphere" || transfer.world_coords != *world_coords {
             continue;
         }
 
@@ -5020,6 +9384,14 @@ fn two_second_pulse(ctx: &ReducerContext) -> Result<(), String> {
         // If there are more spheres, this will be handled by ten_second_pulse
     }
 
+    // Circuit emissions - checked every 2s so per-circuit phase offsets spread
+    // spawns across the emission interval instead of all landing on one pulse
+    let current_time = now
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
+    process_circuit_emissions(ctx, world, current_time, emissions)?;
+
     Ok(())
 }
 
@@ -5039,28 +9411,11 @@ fn merge_sphere_transfers(ctx: &ReducerContext, transfers: &[PacketTransfer]) ->
 
     // Merge compositions from all transfers using wave superposition
     // Same frequencies add together, different frequencies coexist
-    let mut merged_composition: HashMap<u32, u32> = HashMap::new();
-    let mut total_packets: u32 = 0;
-
+    let mut composition: Vec<WavePacketSample> = Vec::new();
     for transfer in transfers {
-        for sample in &transfer.composition {
-            // Use frequency bits as key (f32 to bits for exact matching)
-            let freq_key = sample.frequency.to_bits();
-            *merged_composition.entry(freq_key).or_insert(0) += sample.count;
-            total_packets += sample.count;
-        }
+        composition::merge_compositions(&mut composition, &transfer.composition);
     }
-
-    // Convert merged composition back to Vec<WavePacketSample>
-    let composition: Vec<WavePacketSample> = merged_composition
-        .into_iter()
-        .map(|(freq_bits, count)| WavePacketSample {
-            frequency: f32::from_bits(freq_bits),
-            amplitude: 1.0,
-            phase: 0.0,
-            count,
-        })
-        .collect();
+    let total_packets = composition::total_count(&composition);
 
     // Delete non-primary transfers from database
     for transfer in transfers.iter().skip(1) {
@@ -5082,23 +9437,18 @@ fn merge_sphere_transfers(ctx: &ReducerContext, transfers: &[PacketTransfer]) ->
     Ok(updated)
 }
 
-/// Ten-second pulse: Process Sphere→Sphere DEPARTURES + Circuit Emission
-/// Called every 100 ticks (10 seconds)
-fn ten_second_pulse(ctx: &ReducerContext) -> Result<(), String> {
-    let now = ctx.timestamp;
-    let current_time = now
-        .duration_since(Timestamp::UNIX_EPOCH)
-        .expect("Valid timestamp")
-        .as_millis() as u64;
-
-    log::info!("[10s Pulse] Processing Sphere→Sphere departures and circuit emission");
+/// Ten-second pulse: Process Sphere→Sphere DEPARTURES + orb dissipation in one world
+/// Called every 100 world ticks (10 seconds); tunnel formation spans worlds and runs in game_loop
+fn ten_second_pulse(ctx: &ReducerContext, world_coords: &WorldCoords) -> Result<(), String> {
+    log::info!("[10s Pulse] World ({},{},{}): processing Sphere→Sphere departures",
+        world_coords.x, world_coords.y, world_coords.z);
 
     // Step 1: Collect all transfers waiting at spheres for Sphere→Sphere departure
     // Group by (current_sphere_id, next_sphere_id) for bundling
     let mut transfer_groups: HashMap<(u64, u64), Vec<PacketTransfer>> = HashMap::new();
 
     for transfer in ctx.db.packet_transfer().iter() {
-        if transfer.completed || transfer.current_leg_type != "ArrivedAtSphere" {
+        if transfer.completed || transfer.current_leg_type != "ArrivedAtSphere" || transfer.world_coords != *world_coords {
             continue;
         }
 
@@ -5129,25 +9479,191 @@ fn ten_second_pulse(ctx: &ReducerContext) -> Result<(), String> {
         }
     }
 
-    // Process circuit emissions - now with proper radius checking and movement
-    let circuits: Vec<WorldCircuit> = ctx.db.world_circuit().iter().collect();
-    for circuit in circuits {
-        if current_time >= circuit.last_emission_time + circuit.emission_interval_ms {
-            process_circuit_emission(ctx, &circuit)?;
-
-            // Update circuit emission time
-            let mut updated_circuit = circuit.clone();
-            updated_circuit.last_emission_time = current_time;
-            ctx.db.world_circuit().circuit_id().delete(&circuit.circuit_id);
-            ctx.db.world_circuit().insert(updated_circuit);
+    // Process orb dissipation (50% chance to lose 1 packet every 10 seconds)
+    process_orb_dissipation(ctx, world_coords)?;
+
+    // Sample per-sphere routing throughput for client graphs
+    record_sphere_throughput(ctx, world_coords);
+
+    // Remove sources past their lifetime (flagged expiring_soon by process_source_movement beforehand)
+    cleanup_expired_wave_packet_sources(ctx, world_coords);
+
+    Ok(())
+}
+
+/// Append a throughput sample per sphere in a world and prune samples outside the window
+fn record_sphere_throughput(ctx: &ReducerContext, world_coords: &WorldCoords) {
+    let spheres: Vec<DistributionSphere> = ctx.db.distribution_sphere()
+        .iter()
+        .filter(|s| s.world_coords == *world_coords)
+        .collect();
+    let all_samples: Vec<SphereThroughputSample> = ctx.db.sphere_throughput_sample().iter().collect();
+
+    for sphere in spheres {
+        let mut samples: Vec<&SphereThroughputSample> = all_samples
+            .iter()
+            .filter(|s| s.sphere_id == sphere.sphere_id)
+            .collect();
+        samples.sort_by_key(|s| s.sample_id);
+
+        let previous_total = samples.last().map(|s| s.packets_routed_total).unwrap_or(0);
+
+        ctx.db.sphere_throughput_sample().insert(SphereThroughputSample {
+            sample_id: 0, // auto_inc
+            sphere_id: sphere.sphere_id,
+            sampled_at: ctx.timestamp,
+            packets_routed_delta: sphere.packets_routed.saturating_sub(previous_total),
+            packets_routed_total: sphere.packets_routed,
+        });
+
+        // Existing samples plus the new one - drop the oldest beyond the window
+        let excess = (samples.len() + 1).saturating_sub(THROUGHPUT_SAMPLE_WINDOW);
+        for old in samples.into_iter().take(excess) {
+            ctx.db.sphere_throughput_sample().sample_id().delete(&old.sample_id);
+        }
+    }
+}
+
+/// Form quantum tunnels between neighboring worlds
+/// A "Charging" tunnel at full charge pairs with the opposing, same-color tunnel
+/// in the world its direction points at, once that tunnel is fully charged too
+fn process_tunnel_formation(ctx: &ReducerContext) -> Result<(), String> {
+    let charged: Vec<QuantumTunnel> = ctx.db.quantum_tunnel()
+        .iter()
+        .filter(|t| t.tunnel_status == "Charging" && t.ring_charge >= 100.0)
+        .collect();
+
+    for tunnel in charged {
+        // Re-read: an earlier iteration may already have linked this tunnel
+        let tunnel = match ctx.db.quantum_tunnel().tunnel_id().find(&tunnel.tunnel_id) {
+            Some(t) if t.tunnel_status == "Charging" => t,
+            _ => continue,
+        };
+
+        let offset = direction_world_offset(&tunnel.cardinal_direction);
+        let neighbor_coords = WorldCoords {
+            x: tunnel.world_coords.x + offset.x,
+            y: tunnel.world_coords.y + offset.y,
+            z: tunnel.world_coords.z + offset.z,
+        };
+
+        let partner = ctx.db.quantum_tunnel().iter().find(|t| {
+            let partner_offset = direction_world_offset(&t.cardinal_direction);
+            t.world_coords == neighbor_coords &&
+                t.tunnel_status == "Charging" &&
+                t.ring_charge >= 100.0 &&
+                t.tunnel_color == tunnel.tunnel_color &&
+                partner_offset.x == -offset.x &&
+                partner_offset.y == -offset.y &&
+                partner_offset.z == -offset.z
+        });
+
+        let partner = match partner {
+            Some(p) => p,
+            None => continue,
+        };
+
+        let tunnel_sphere_id = ctx.db.distribution_sphere()
+            .iter()
+            .find(|s| s.world_coords == tunnel.world_coords &&
+                     s.cardinal_direction == tunnel.cardinal_direction)
+            .map(|s| s.sphere_id);
+        let partner_sphere_id = ctx.db.distribution_sphere()
+            .iter()
+            .find(|s| s.world_coords == partner.world_coords &&
+                     s.cardinal_direction == partner.cardinal_direction)
+            .map(|s| s.sphere_id);
+
+        let mut updated_tunnel = tunnel.clone();
+        updated_tunnel.tunnel_status = "Active".to_string();
+        updated_tunnel.connected_to_world = Some(partner.world_coords);
+        updated_tunnel.connected_to_sphere_id = partner_sphere_id;
+        updated_tunnel.formed_at = Some(ctx.timestamp);
+
+        let mut updated_partner = partner.clone();
+        updated_partner.tunnel_status = "Active".to_string();
+        updated_partner.connected_to_world = Some(tunnel.world_coords);
+        updated_partner.connected_to_sphere_id = tunnel_sphere_id;
+        updated_partner.formed_at = Some(ctx.timestamp);
+
+        ctx.db.quantum_tunnel().delete(tunnel.clone());
+        ctx.db.quantum_tunnel().insert(updated_tunnel);
+        ctx.db.quantum_tunnel().delete(partner.clone());
+        ctx.db.quantum_tunnel().insert(updated_partner);
+
+        log::info!("[Tunnels] Formed {} tunnel: ({},{},{}) {} <-> ({},{},{}) {}",
+            tunnel.tunnel_color,
+            tunnel.world_coords.x, tunnel.world_coords.y, tunnel.world_coords.z, tunnel.cardinal_direction,
+            partner.world_coords.x, partner.world_coords.y, partner.world_coords.z, partner.cardinal_direction);
+    }
+
+    Ok(())
+}
+
+/// Find the fewest-hop route between two worlds through active tunnels
+/// Breadth-first search over connected_to_world links; the result (or unreachable)
+/// replaces the caller's row in tunnel_path_result
+#[spacetimedb::reducer]
+pub fn find_tunnel_path(ctx: &ReducerContext, from_world: WorldCoords, to_world: WorldCoords) -> Result<(), String> {
+    // Active tunnel graph: world -> neighboring worlds
+    let mut neighbors: HashMap<WorldCoords, Vec<WorldCoords>> = HashMap::new();
+    for tunnel in ctx.db.quantum_tunnel().iter() {
+        if tunnel.tunnel_status != "Active" {
+            continue;
+        }
+        if let Some(connected) = tunnel.connected_to_world {
+            neighbors.entry(tunnel.world_coords).or_insert_with(Vec::new).push(connected);
+        }
+    }
+
+    let mut previous: HashMap<WorldCoords, WorldCoords> = HashMap::new();
+    let mut visited: std::collections::HashSet<WorldCoords> = std::collections::HashSet::new();
+    let mut queue: std::collections::VecDeque<WorldCoords> = std::collections::VecDeque::new();
+    visited.insert(from_world);
+    queue.push_back(from_world);
+
+    while let Some(world) = queue.pop_front() {
+        if world == to_world {
+            break;
+        }
+        for next in neighbors.get(&world).into_iter().flatten() {
+            if visited.insert(*next) {
+                previous.insert(*next, world);
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    // Walk back from the destination to rebuild the path
+    let mut path = Vec::new();
+    if visited.contains(&to_world) {
+        let mut current = to_world;
+        path.push(current);
+        while let Some(prev) = previous.get(&current) {
+            current = *prev;
+            path.push(current);
         }
+        path.reverse();
     }
+    let reachable = !path.is_empty();
 
-    // Process orb dissipation (50% chance to lose 1 packet every 10 seconds)
-    process_orb_dissipation(ctx)?;
+    log::info!("[Tunnels] Path ({},{},{}) -> ({},{},{}): {}",
+        from_world.x, from_world.y, from_world.z, to_world.x, to_world.y, to_world.z,
+        if reachable { format!("{} hops", path.len() - 1) } else { "unreachable".to_string() });
 
-    // Clean up expired wave packet sources - DISABLED: sources should only be deleted when depleted
-    // cleanup_expired_wave_packet_sources(ctx)?;
+    // Replace any previous result for this requester
+    if let Some(existing) = ctx.db.tunnel_path_result().requester().find(&ctx.sender) {
+        ctx.db.tunnel_path_result().delete(existing);
+    }
+
+    ctx.db.tunnel_path_result().insert(TunnelPathResult {
+        requester: ctx.sender,
+        from_world,
+        to_world,
+        reachable,
+        path,
+        computed_at: ctx.timestamp,
+    });
 
     Ok(())
 }
@@ -5156,10 +9672,15 @@ fn ten_second_pulse(ctx: &ReducerContext) -> Result<(), String> {
 fn depart_object_to_sphere(ctx: &ReducerContext, transfer: &PacketTransfer) -> Result<(), String> {
     let now = ctx.timestamp;
 
-    // Get source object position
-    let source_pos = get_object_position(ctx,
-        &transfer.source_object_type,
-        transfer.source_object_id)?;
+    // Get source object position - orbs drift and can be depleted before the
+    // transfer departs, so orb transfers leave from the recorded extraction point
+    let source_pos = if transfer.source_object_type == "Orb" {
+        transfer.route_waypoints.first().copied().ok_or("Transfer has no waypoints")?
+    } else {
+        get_object_position(ctx,
+            &transfer.source_object_type,
+            transfer.source_object_id)?
+    };
 
     // Get first sphere in route
     let first_sphere_id = transfer.route_spire_ids[0];
@@ -5172,7 +9693,7 @@ fn depart_object_to_sphere(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
 
     // Calculate distance and travel time (with height transition from 1 to 10)
     let distance = calculate_distance(&source_pos, &sphere_pos);
-    let travel_time = calculate_travel_time(distance, "ObjectToSphere");
+    let travel_time = calculate_travel_time(distance, "ObjectToSphere", get_world_constants(ctx).packet_speed);
 
     // Start transfer to first sphere
     let mut updated_transfer = transfer.clone();
@@ -5183,6 +9704,8 @@ fn depart_object_to_sphere(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(updated_transfer.clone());
 
+    record_transfer_event(ctx, transfer.transfer_id, "Departed", source_pos);
+
     log::info!("[Departure] Transfer {} departing {} {} → sphere {} (distance: {:.1}, ETA: {}s)",
         transfer.transfer_id,
         transfer.source_object_type, transfer.source_object_id,
@@ -5196,8 +9719,8 @@ fn depart_object_to_sphere(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
 fn depart_sphere_to_object(ctx: &ReducerContext, transfer: &PacketTransfer) -> Result<(), String> {
     let now = ctx.timestamp;
 
-    // Get current sphere position
-    let current_sphere_idx = (transfer.current_leg as usize).saturating_sub(1);
+    // Get current sphere position (current_leg indexes the sphere the transfer is waiting at)
+    let current_sphere_idx = transfer.current_leg as usize;
     let sphere_id = transfer.route_spire_ids.get(current_sphere_idx)
         .ok_or("Invalid sphere index")?;
     let sphere = ctx.db.distribution_sphere()
@@ -5214,7 +9737,9 @@ fn depart_sphere_to_object(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
 
     // Calculate distance and travel time (with height transition from 10 to 1)
     let distance = calculate_distance(&sphere_pos, &dest_pos);
-    let travel_time = calculate_travel_time(distance, "SphereToObject");
+    let travel_time = calculate_travel_time(distance, "SphereToObject", get_world_constants(ctx).packet_speed);
+
+    release_from_sphere_buffer(ctx, transfer);
 
     // Advance transfer to final leg
     let mut updated_transfer = transfer.clone();
@@ -5226,6 +9751,8 @@ fn depart_sphere_to_object(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(updated_transfer.clone());
 
+    record_transfer_event(ctx, transfer.transfer_id, "Departed", sphere.sphere_position);
+
     log::info!("[Departure] Transfer {} departing sphere {} → {} {} (distance: {:.1}, ETA: {}s)",
         transfer.transfer_id, sphere_id,
         transfer.destination_object_type, transfer.destination_object_id,
@@ -5262,7 +9789,9 @@ fn depart_sphere_to_sphere(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
 
     // Calculate distance and travel time (constant height at 10)
     let distance = calculate_distance(&sphere_pos, &next_pos);
-    let travel_time = calculate_travel_time(distance, "SphereToSphere");
+    let travel_time = calculate_travel_time(distance, "SphereToSphere", get_world_constants(ctx).packet_speed);
+
+    release_from_sphere_buffer(ctx, transfer);
 
     // Advance transfer to next sphere leg
     let mut updated_transfer = transfer.clone();
@@ -5274,6 +9803,8 @@ fn depart_sphere_to_sphere(ctx: &ReducerContext, transfer: &PacketTransfer) -> R
     ctx.db.packet_transfer().delete(transfer.clone());
     ctx.db.packet_transfer().insert(updated_transfer.clone());
 
+    record_transfer_event(ctx, transfer.transfer_id, "Departed", sphere.sphere_position);
+
     log::info!("[Departure] Transfer {} departing sphere {} → sphere {} (distance: {:.1}, ETA: {}s)",
         transfer.transfer_id, sphere_id, next_sphere_id,
         distance, travel_time.as_secs());
@@ -5295,7 +9826,7 @@ fn calculate_distance(pos1: &DbVector3, pos2: &DbVector3) -> f32 {
 
 /// Calculate travel time based on distance, leg type, and height transitions
 /// Accounts for both horizontal travel and vertical height changes
-fn calculate_travel_time(horizontal_distance: f32, leg_type: &str) -> Duration {
+fn calculate_travel_time(horizontal_distance: f32, leg_type: &str, packet_speed: f32) -> Duration {
     // Determine vertical distance based on leg type
     let vertical_distance = match leg_type {
         "ObjectToSphere" => {
@@ -5321,19 +9852,27 @@ fn calculate_travel_time(horizontal_distance: f32, leg_type: &str) -> Duration {
     // Two-phase movement: vertical THEN horizontal (or horizontal THEN vertical)
     let total_distance = vertical_distance + horizontal_distance;
 
-    let seconds = (total_distance / PACKET_SPEED).ceil() as u64;
+    let seconds = (total_distance / packet_speed).ceil() as u64;
     Duration::from_secs(seconds)
 }
 
+/// Last position of a logged-out player, for transfers they sent before disconnecting
+fn logged_out_position(mut logged_out: impl Iterator<Item = LoggedOutPlayer>, player_id: u64) -> Option<DbVector3> {
+    logged_out
+        .find(|p| p.player_id == player_id)
+        .map(|p| p.last_position)
+}
+
 /// Get position of an object by type and ID
 fn get_object_position(ctx: &ReducerContext, object_type: &str, object_id: u64) -> Result<DbVector3, String> {
     match object_type {
         "Player" => {
-            let player = ctx.db.player()
-                .player_id()
-                .find(&object_id)
-                .ok_or(format!("Player {} not found", object_id))?;
-            Ok(player.position.clone())
+            if let Some(player) = ctx.db.player().player_id().find(&object_id) {
+                return Ok(player.position.clone());
+            }
+            // Senders who disconnected mid-transfer depart from where they logged out
+            logged_out_position(ctx.db.logged_out_player().iter(), object_id)
+                .ok_or(format!("Player {} not found", object_id))
         }
         "StorageDevice" => {
             let device = ctx.db.storage_device()
@@ -5350,23 +9889,71 @@ fn get_object_position(ctx: &ReducerContext, object_type: &str, object_id: u64)
     }
 }
 
-/// Add wave packet samples to a buffer, merging frequencies
-fn add_to_buffer(buffer: &mut Vec<WavePacketSample>, samples: &[WavePacketSample]) {
-    for sample in samples {
-        let mut found = false;
-        for existing in buffer.iter_mut() {
-            if (existing.frequency - sample.frequency).abs() < 0.01 {
-                existing.count += sample.count;
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            buffer.push(sample.clone());
-        }
+/// Add an arriving transfer's packets to a sphere's transit buffer
+/// Packets beyond the sphere's free capacity are cut from the transfer's composition
+/// (highest frequencies first), so the buffer never holds packets no transfer carries
+/// Returns the number of packets dropped
+fn add_to_sphere_buffer(sphere: &mut DistributionSphere, arriving: &mut Vec<WavePacketSample>) -> u32 {
+    let free = sphere.buffer_capacity
+        .saturating_sub(composition::total_count(&sphere.transit_buffer));
+    let dropped = composition::truncate_composition(arriving, free);
+    composition::merge_compositions(&mut sphere.transit_buffer, arriving);
+
+    if dropped > 0 {
+        log::warn!("[Buffer] Sphere {} at capacity {}: dropped {} arriving packets",
+            sphere.sphere_id, sphere.buffer_capacity, dropped);
+    }
+
+    dropped
+}
+
+/// Sphere whose transit buffer holds a transfer's packets right now, if any
+fn buffered_at_sphere(transfer: &PacketTransfer) -> Option<u64> {
+    if transfer.completed {
+        return None;
+    }
+    if transfer.current_leg_type == "ArrivedAtSphere" {
+        return transfer.route_spire_ids.get(transfer.current_leg as usize).copied();
+    }
+    // Legacy pulse flow (tick_player_transfers/world_sphere_pulse) parks packets
+    // at the sphere before current_leg
+    if transfer.state == "InTransit" && transfer.current_leg > 0 {
+        return transfer.route_spire_ids.get(transfer.current_leg as usize - 1).copied();
+    }
+    None
+}
+
+/// Take a transfer's packets back out of the buffer of the sphere it is waiting at
+/// Call before the transfer leaves the sphere - departure, delivery or reclaim
+fn release_from_sphere_buffer(ctx: &ReducerContext, transfer: &PacketTransfer) {
+    let sphere = buffered_at_sphere(transfer)
+        .and_then(|sphere_id| ctx.db.distribution_sphere().sphere_id().find(&sphere_id));
+
+    if let Some(sphere) = sphere {
+        let mut updated_sphere = sphere.clone();
+        composition::remove_up_to(&mut updated_sphere.transit_buffer, &transfer.composition);
+        ctx.db.distribution_sphere().delete(sphere);
+        ctx.db.distribution_sphere().insert(updated_sphere);
     }
 }
 
+/// End a transfer that lost every packet to a full sphere buffer
+fn finish_dropped_transfer(ctx: &ReducerContext, transfer: &PacketTransfer, position: DbVector3) {
+    let mut dropped_transfer = transfer.clone();
+    dropped_transfer.composition = Vec::new();
+    dropped_transfer.packet_count = 0;
+    dropped_transfer.completed = true;
+    dropped_transfer.state = "Dropped".to_string();
+    dropped_transfer.current_leg_type = "Dropped".to_string();
+
+    ctx.db.packet_transfer().delete(transfer.clone());
+    ctx.db.packet_transfer().insert(dropped_transfer);
+
+    record_transfer_event(ctx, transfer.transfer_id, "Dropped", position);
+
+    log::warn!("[Buffer] Transfer {} dropped entirely - sphere buffer full", transfer.transfer_id);
+}
+
 // ============================================================================
 // Wave Packet Source Movement Helper Functions
 // ============================================================================
@@ -5377,17 +9964,7 @@ fn get_tangent_direction(surface_normal: &DbVector3, index: u32) -> DbVector3 {
     let angle = (index as f32) * PI / 4.0;  // 8 directions, 45° apart
 
     // Create orthonormal basis on tangent plane
-    // Choose an arbitrary vector not parallel to normal
-    let arbitrary = if surface_normal.y.abs() < 0.9 {
-        DbVector3::new(0.0, 1.0, 0.0)  // Y-up
-    } else {
-        DbVector3::new(1.0, 0.0, 0.0)  // X-right
-    };
-
-    // tangent1 = normal × arbitrary (perpendicular to both)
-    let tangent1 = surface_normal.cross(&arbitrary).normalize();
-    // tangent2 = normal × tangent1 (perpendicular to both)
-    let tangent2 = surface_normal.cross(&tangent1);
+    let (tangent1, tangent2) = surface_normal.tangent_basis();
 
     // Combine tangents with angle to get direction
     DbVector3::new(
@@ -5416,12 +9993,11 @@ fn rotate_around_normal(direction: &DbVector3, normal: &DbVector3, angle: f32) -
 /// Travel along sphere surface from start position in given direction for given distance
 /// Returns the destination position on the sphere surface
 fn travel_on_sphere_surface(start: &DbVector3, direction: &DbVector3, distance: f32) -> DbVector3 {
-    // Arc length on sphere: distance = radius * angle
-    // angle = distance / radius
-    let angle = distance / WORLD_RADIUS;
-
     // Get current radius (might have height offset)
     let start_radius = start.magnitude();
+
+    // Arc length on the sphere through start: distance = radius * angle
+    let angle = distance / start_radius;
     let start_normal = start.normalize();
 
     // Use Rodrigues' rotation to move along great circle
@@ -5440,76 +10016,23 @@ fn travel_on_sphere_surface(start: &DbVector3, direction: &DbVector3, distance:
     );
 
     // Project back onto sphere at original radius
-    moved.normalize().scale(start_radius)
+    moved.surface_project(start_radius)
 }
 
 /// Map cardinal direction name to frequency constant
 fn get_direction_frequency(direction: &str) -> f32 {
-    match direction {
-        // Cardinal directions (6 faces)
-        "North" | "South" => FREQ_GREEN,
-        "East" | "West" => FREQ_RED,
-        "Forward" | "Back" => FREQ_BLUE,
-
-        // Edge directions (12 edges) - Yellow for XY, Cyan for YZ, Magenta for XZ
-        d if d.contains("North") && d.contains("East") && !d.contains("Forward") && !d.contains("Back") => FREQ_YELLOW,
-        d if d.contains("North") && d.contains("West") && !d.contains("Forward") && !d.contains("Back") => FREQ_YELLOW,
-        d if d.contains("South") && d.contains("East") && !d.contains("Forward") && !d.contains("Back") => FREQ_YELLOW,
-        d if d.contains("South") && d.contains("West") && !d.contains("Forward") && !d.contains("Back") => FREQ_YELLOW,
-        "NorthForward" | "NorthBack" | "SouthForward" | "SouthBack" => FREQ_CYAN,
-        "EastForward" | "EastBack" | "WestForward" | "WestBack" => FREQ_MAGENTA,
-
-        // Vertex directions (8 corners) - White (use all frequencies equally, just pick one)
-        _ => FREQ_RED,  // For white vertices, default to red
-    }
+    direction_metadata(direction).2
 }
 
 /// Find which of the 26 cardinal directions is closest to a position
 fn closest_cardinal_direction(position: &DbVector3) -> String {
     let normalized = position.normalize();
 
-    const SQRT2: f32 = 1.414213562373095;
-    const SQRT3: f32 = 1.732050807568877;
-
-    // All 26 directions with their normalized vectors
-    let directions: Vec<(&str, DbVector3)> = vec![
-        // 6 Cardinal (face centers)
-        ("North", DbVector3::new(0.0, 1.0, 0.0)),
-        ("South", DbVector3::new(0.0, -1.0, 0.0)),
-        ("East", DbVector3::new(1.0, 0.0, 0.0)),
-        ("West", DbVector3::new(-1.0, 0.0, 0.0)),
-        ("Forward", DbVector3::new(0.0, 0.0, 1.0)),
-        ("Back", DbVector3::new(0.0, 0.0, -1.0)),
-
-        // 12 Edge centers
-        ("NorthEast", DbVector3::new(1.0/SQRT2, 1.0/SQRT2, 0.0)),
-        ("NorthWest", DbVector3::new(-1.0/SQRT2, 1.0/SQRT2, 0.0)),
-        ("SouthEast", DbVector3::new(1.0/SQRT2, -1.0/SQRT2, 0.0)),
-        ("SouthWest", DbVector3::new(-1.0/SQRT2, -1.0/SQRT2, 0.0)),
-        ("NorthForward", DbVector3::new(0.0, 1.0/SQRT2, 1.0/SQRT2)),
-        ("NorthBack", DbVector3::new(0.0, 1.0/SQRT2, -1.0/SQRT2)),
-        ("SouthForward", DbVector3::new(0.0, -1.0/SQRT2, 1.0/SQRT2)),
-        ("SouthBack", DbVector3::new(0.0, -1.0/SQRT2, -1.0/SQRT2)),
-        ("EastForward", DbVector3::new(1.0/SQRT2, 0.0, 1.0/SQRT2)),
-        ("EastBack", DbVector3::new(1.0/SQRT2, 0.0, -1.0/SQRT2)),
-        ("WestForward", DbVector3::new(-1.0/SQRT2, 0.0, 1.0/SQRT2)),
-        ("WestBack", DbVector3::new(-1.0/SQRT2, 0.0, -1.0/SQRT2)),
-
-        // 8 Vertex (corners)
-        ("NorthEastForward", DbVector3::new(1.0/SQRT3, 1.0/SQRT3, 1.0/SQRT3)),
-        ("NorthEastBack", DbVector3::new(1.0/SQRT3, 1.0/SQRT3, -1.0/SQRT3)),
-        ("NorthWestForward", DbVector3::new(-1.0/SQRT3, 1.0/SQRT3, 1.0/SQRT3)),
-        ("NorthWestBack", DbVector3::new(-1.0/SQRT3, 1.0/SQRT3, -1.0/SQRT3)),
-        ("SouthEastForward", DbVector3::new(1.0/SQRT3, -1.0/SQRT3, 1.0/SQRT3)),
-        ("SouthEastBack", DbVector3::new(1.0/SQRT3, -1.0/SQRT3, -1.0/SQRT3)),
-        ("SouthWestForward", DbVector3::new(-1.0/SQRT3, -1.0/SQRT3, 1.0/SQRT3)),
-        ("SouthWestBack", DbVector3::new(-1.0/SQRT3, -1.0/SQRT3, -1.0/SQRT3)),
-    ];
-
     let mut best_direction = "North";
     let mut best_dot = -2.0f32;
 
-    for (name, dir) in directions {
+    for name in ALL_DIRECTIONS {
+        let (dir, _, _) = direction_metadata(name);
         let dot = normalized.dot(&dir);
         if dot > best_dot {
             best_dot = dot;
@@ -5552,24 +10075,117 @@ fn create_mixed_composition(
     composition
 }
 
+/// Scale a composition template to `total_packets`, keeping each sample's share
+/// Template counts act as weights; rounding remainder goes to the largest share
+fn scale_composition(template: &[WavePacketSample], total_packets: u32) -> Vec<WavePacketSample> {
+    let weight_total: u32 = template.iter().map(|s| s.count).sum();
+    if weight_total == 0 {
+        return Vec::new();
+    }
+
+    let mut composition: Vec<WavePacketSample> = template
+        .iter()
+        .map(|s| {
+            let mut scaled = s.clone();
+            scaled.count = (total_packets as u64 * s.count as u64 / weight_total as u64) as u32;
+            scaled
+        })
+        .collect();
+
+    let assigned: u32 = composition.iter().map(|s| s.count).sum();
+    if let Some(largest) = composition.iter_mut().max_by_key(|s| s.count) {
+        largest.count += total_packets - assigned;
+    }
+
+    composition.retain(|s| s.count > 0);
+    composition
+}
+
 /// Get circuit position on sphere surface based on cardinal direction
-fn get_circuit_surface_position(circuit: &WorldCircuit) -> DbVector3 {
-    get_cardinal_position(&circuit.cardinal_direction)
+fn get_circuit_surface_position(ctx: &ReducerContext, circuit: &WorldCircuit) -> DbVector3 {
+    get_cardinal_position(ctx, &circuit.world_coords, &circuit.cardinal_direction)
 }
 
 // ============================================================================
 // Source Movement Processing Functions
 // ============================================================================
 
-/// Process all source movement each game tick (called at 10Hz)
-fn process_source_movement(ctx: &ReducerContext) {
-    let sources: Vec<WavePacketSource> = ctx.db.wave_packet_source().iter().collect();
+/// Height above the surface a source should sit at for its state (1 once stationary, 0 before)
+/// Rising sources keep their height-0 start position; the client interpolates the climb
+fn source_target_height(state: u8) -> f32 {
+    if state == SOURCE_STATE_STATIONARY {
+        SOURCE_HEIGHT_1
+    } else {
+        SOURCE_HEIGHT_0
+    }
+}
+
+/// Re-project a source's position (and travel destination) onto its world's surface
+/// (ellipsoid semi-axes in `radii`) at the height for its state
+/// Returns None if both are already within tolerance
+fn resurface_source(source: &WavePacketSource, radii: &DbVector3, tolerance: f32) -> Option<WavePacketSource> {
+    let target_position = source.position.ellipsoid_project(radii, source_target_height(source.state));
+    let target_destination = source.destination.ellipsoid_project(radii, SOURCE_HEIGHT_0);
+
+    let position_off = source.position.distance_to(&target_position) > tolerance;
+    let destination_off = source.state == SOURCE_STATE_MOVING_H
+        && source.destination.distance_to(&target_destination) > tolerance;
+
+    if !position_off && !destination_off {
+        return None;
+    }
+
+    let mut updated = source.clone();
+    if position_off {
+        updated.position = target_position;
+    }
+    if destination_off {
+        updated.destination = target_destination;
+    }
+    Some(updated)
+}
+
+/// Process source movement in one world each game tick (called at 10Hz)
+fn process_source_movement(ctx: &ReducerContext, world: &World) {
+    let sources: Vec<WavePacketSource> = ctx.db.wave_packet_source()
+        .iter()
+        .filter(|s| s.world_coords == world.world_coords)
+        .collect();
+    let constants = get_world_constants(ctx);
+    let radii = radii_of(Some(world), constants.world_radius);
+    let current_time = ctx.timestamp
+        .duration_since(Timestamp::UNIX_EPOCH)
+        .expect("Valid timestamp")
+        .as_millis() as u64;
 
     for source in sources {
+        // Flag once when the end of lifetime is near so clients can start fading
+        let source = if !source.expiring_soon && source_expiring_soon(&source, current_time) {
+            let mut updated = source.clone();
+            updated.expiring_soon = true;
+            ctx.db.wave_packet_source().source_id().delete(&source.source_id);
+            ctx.db.wave_packet_source().insert(updated.clone());
+            updated
+        } else {
+            source
+        };
+
+        // Undo floating-point drift off the surface from repeated projection
+        let source = match resurface_source(&source, &radii, SOURCE_RADIUS_TOLERANCE) {
+            Some(corrected) => {
+                log::info!("[Source Movement] ID={} drifted to radius {:.4}, resurfaced",
+                    source.source_id, source.position.magnitude());
+                ctx.db.wave_packet_source().source_id().delete(&source.source_id);
+                ctx.db.wave_packet_source().insert(corrected.clone());
+                corrected
+            }
+            None => source,
+        };
+
         match source.state {
-            SOURCE_STATE_MOVING_H => process_horizontal_movement(ctx, source),
-            SOURCE_STATE_ARRIVED_H0 => start_rising(ctx, source),
-            SOURCE_STATE_RISING => process_vertical_movement(ctx, source),
+            SOURCE_STATE_MOVING_H => process_horizontal_movement(ctx, source, &constants),
+            SOURCE_STATE_ARRIVED_H0 => start_rising(ctx, source, &constants),
+            SOURCE_STATE_RISING => process_vertical_movement(ctx, source, &constants, &radii),
             SOURCE_STATE_STATIONARY => {}, // No movement
             _ => {},
         }
@@ -5594,7 +10210,7 @@ fn rotate_vector(v: &DbVector3, axis: &DbVector3, angle: f32) -> DbVector3 {
 
 /// Process horizontal movement along sphere surface using spherical rotation
 /// Only updates database on state transition (arrival) - client calculates position locally
-fn process_horizontal_movement(ctx: &ReducerContext, source: WavePacketSource) {
+fn process_horizontal_movement(ctx: &ReducerContext, source: WavePacketSource, constants: &WorldConstants) {
     // Calculate elapsed time since state started (in seconds)
     let now = ctx.timestamp.to_micros_since_unix_epoch() as u64;
     let elapsed_micros = now.saturating_sub(source.state_start_timestamp);
@@ -5603,33 +10219,25 @@ fn process_horizontal_movement(ctx: &ReducerContext, source: WavePacketSource) {
     // Spherical movement: rotation around world center
     // Angular velocity: ω = v / r (linear speed / radius)
     let speed = source.velocity.magnitude();
-    let angular_velocity = speed / WORLD_RADIUS;
+    let angular_velocity = speed / constants.world_radius;
     let angle = angular_velocity * elapsed_secs;
 
-    // Rotation axis: perpendicular to position and velocity (cross product)
-    // pos × vel gives axis pointing "into" the rotation
-    let pos_normal = source.position.normalize();
-    let vel_normal = source.velocity.normalize();
-    let rotation_axis = pos_normal.cross(&vel_normal).normalize();
-
-    // Rotate start position around axis by angle (Rodrigues' rotation)
-    let current_pos = rotate_vector(&source.position, &rotation_axis, angle);
-
-    // Check if arrived at destination
-    let distance_to_dest = current_pos.distance_to(&source.destination);
-    let arrival_threshold = SOURCE_MOVE_SPEED * 0.2; // Arrive within 0.2 seconds of movement
+    // Progress along the travel arc: total arc angle between start and destination
+    // (arc distance on a unit sphere). Arrival is when the travelled angle passes it,
+    // so fast sources snap exactly instead of overshooting a distance threshold
+    let total_angle = source.position.arc_distance_to(&source.destination, 1.0);
 
     // DEBUG: Log movement processing every ~10 seconds (every 100 calls)
     static mut DEBUG_COUNTER: u32 = 0;
     unsafe {
         DEBUG_COUNTER += 1;
         if DEBUG_COUNTER % 100 == 1 {
-            log::info!("[Source Movement] ID={}, elapsed={:.1}s, dist_to_dest={:.2}, threshold={:.2}",
-                source.source_id, elapsed_secs, distance_to_dest, arrival_threshold);
+            log::info!("[Source Movement] ID={}, elapsed={:.1}s, angle={:.4}/{:.4} rad",
+                source.source_id, elapsed_secs, angle, total_angle);
         }
     }
 
-    if distance_to_dest < arrival_threshold {
+    if angle >= total_angle {
         // Arrived - snap to destination and transition to next state
         let mut updated = source.clone();
         updated.position = source.destination;
@@ -5647,7 +10255,7 @@ fn process_horizontal_movement(ctx: &ReducerContext, source: WavePacketSource) {
 }
 
 /// Start rising from height 0 to height 1
-fn start_rising(ctx: &ReducerContext, source: WavePacketSource) {
+fn start_rising(ctx: &ReducerContext, source: WavePacketSource, constants: &WorldConstants) {
     let surface_normal = source.position.normalize();
     let now = ctx.timestamp.to_micros_since_unix_epoch() as u64;
 
@@ -5655,7 +10263,7 @@ fn start_rising(ctx: &ReducerContext, source: WavePacketSource) {
     updated.state = SOURCE_STATE_RISING;
     updated.state_start_timestamp = now;  // Reset timestamp for rising state
     // Set radial velocity (pointing outward from sphere center)
-    updated.velocity = surface_normal.scale(SOURCE_RISE_SPEED);
+    updated.velocity = surface_normal.scale(constants.source_rise_speed);
 
     ctx.db.wave_packet_source().source_id().delete(&source.source_id);
     ctx.db.wave_packet_source().insert(updated);
@@ -5663,7 +10271,7 @@ fn start_rising(ctx: &ReducerContext, source: WavePacketSource) {
 
 /// Process vertical (radial) movement from height 0 to height 1
 /// Only updates database on state transition (reaching final height) - client calculates position locally
-fn process_vertical_movement(ctx: &ReducerContext, source: WavePacketSource) {
+fn process_vertical_movement(ctx: &ReducerContext, source: WavePacketSource, constants: &WorldConstants, radii: &DbVector3) {
     // Calculate elapsed time since state started (in seconds)
     let now = ctx.timestamp.to_micros_since_unix_epoch() as u64;
     let elapsed_micros = now.saturating_sub(source.state_start_timestamp);
@@ -5671,12 +10279,13 @@ fn process_vertical_movement(ctx: &ReducerContext, source: WavePacketSource) {
 
     let surface_normal = source.position.normalize();
     // Calculate current height based on elapsed time since rising started
-    let start_height = source.position.magnitude() - WORLD_RADIUS;
-    let current_height = start_height + SOURCE_RISE_SPEED * elapsed_secs;
+    let surface_distance = source.position.ellipsoid_project(radii, 0.0).magnitude();
+    let start_height = source.position.magnitude() - surface_distance;
+    let current_height = start_height + constants.source_rise_speed * elapsed_secs;
 
     if current_height >= SOURCE_HEIGHT_1 {
         // Reached final height - become stationary
-        let final_pos = surface_normal.scale(WORLD_RADIUS + SOURCE_HEIGHT_1);
+        let final_pos = surface_normal.ellipsoid_project(radii, SOURCE_HEIGHT_1);
 
         let mut updated = source.clone();
         updated.position = final_pos;
@@ -5826,6 +10435,22 @@ fn cleanup_expired_player_chat_messages(ctx: &ReducerContext) {
 pub fn __init__(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("=== DATABASE INITIALIZATION START ===");
     
+    // Module owner is the first admin - must precede the admin-gated spawns below
+    ctx.db.admin_identity().insert(AdminIdentity {
+        identity: ctx.sender,
+        granted_at: ctx.timestamp,
+    });
+    log::info!("[Init] Registered module owner as admin");
+    
+    // Seed tunable world constants from compile-time defaults
+    ctx.db.world_constants().insert(WorldConstants::defaults());
+    log::info!("[Init] Seeded world constants");
+    apply_log_level(ctx);
+    
+    // World rows come first - the game loop only ticks worlds that have one
+    init_worlds(ctx)?;
+    log::info!("[Init] Created center and shell 1 worlds");
+    
     // Spawn initial world objects
     spawn_all_26_spires(ctx, 0, 0, 0)?;
     log::info!("[Init] Created 26 energy spires");
@@ -5840,3 +10465,886 @@ pub fn __init__(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("=== DATABASE INITIALIZATION COMPLETE ===");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(frequency: f32, count: u32) -> WavePacketSample {
+        WavePacketSample { frequency, amplitude: 1.0, phase: 0.0, count }
+    }
+
+    fn test_sphere(buffer_capacity: u32) -> DistributionSphere {
+        DistributionSphere {
+            sphere_id: 1,
+            world_coords: WorldCoords { x: 0, y: 0, z: 0 },
+            cardinal_direction: "North".to_string(),
+            sphere_position: DbVector3::zero(),
+            sphere_radius: 40,
+            packets_routed: 0,
+            last_packet_time: Timestamp::UNIX_EPOCH,
+            transit_buffer: Vec::new(),
+            buffer_capacity,
+        }
+    }
+
+    fn test_source(creation_time: u64, lifetime_ms: u32) -> WavePacketSource {
+        WavePacketSource {
+            source_id: 1,
+            world_coords: WorldCoords { x: 0, y: 0, z: 0 },
+            position: DbVector3::new(0.0, WORLD_RADIUS, 0.0),
+            velocity: DbVector3::zero(),
+            destination: DbVector3::new(0.0, WORLD_RADIUS, 0.0),
+            state: SOURCE_STATE_STATIONARY,
+            state_start_timestamp: 0,
+            wave_packet_composition: vec![sample(FREQ_RED, 10)],
+            total_wave_packets: 10,
+            creation_time,
+            lifetime_ms,
+            last_dissipation: creation_time,
+            active_miner_count: 0,
+            last_depletion: creation_time,
+            expiring_soon: false,
+            dissipates: true,
+        }
+    }
+
+    #[test]
+    fn orb_is_flagged_expiring_before_it_expires() {
+        let source = test_source(1_000, 60_000);
+        let end_of_life = 61_000;
+
+        assert!(!source_expiring_soon(&source, 1_000));
+        assert!(source_expiring_soon(&source, end_of_life - SOURCE_EXPIRY_WARNING_MS));
+        assert!(!source_expired(&source, end_of_life - 1));
+        assert!(source_expired(&source, end_of_life));
+    }
+
+    #[test]
+    fn frozen_orb_never_expires() {
+        let source = WavePacketSource { dissipates: false, ..test_source(1_000, 60_000) };
+
+        assert!(!source_expiring_soon(&source, 61_000));
+        assert!(!source_expired(&source, 61_000));
+        assert!(!source_expired(&source, u64::MAX / 2));
+    }
+
+    fn test_device(stored: Vec<WavePacketSample>) -> StorageDevice {
+        StorageDevice {
+            device_id: 1,
+            owner_player_id: 1,
+            world_coords: WorldCoords { x: 0, y: 0, z: 0 },
+            position: DbVector3::zero(),
+            device_name: "Test".to_string(),
+            capacity_per_frequency: DEFAULT_STORAGE_CAPACITY_PER_FREQUENCY,
+            stored_composition: stored,
+            created_at: Timestamp::UNIX_EPOCH,
+            per_frequency_capacity: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn storage_capacity_cannot_drop_below_contents() {
+        let mut device = test_device(vec![sample(FREQ_GREEN, 400)]);
+        assert!(check_storage_capacity(&device).is_ok());
+
+        device.capacity_per_frequency = 399;
+        assert!(check_storage_capacity(&device).is_err());
+
+        device.capacity_per_frequency = 400;
+        assert!(check_storage_capacity(&device).is_ok());
+    }
+
+    #[test]
+    fn storage_capacity_total_is_bounded() {
+        let mut device = test_device(Vec::new());
+        device.capacity_per_frequency = MAX_STORAGE_CAPACITY / 6 + 1;
+        assert!(check_storage_capacity(&device).is_err());
+
+        // Large enough to overflow a u32 sum
+        device.capacity_per_frequency = u32::MAX;
+        assert!(check_storage_capacity(&device).is_err());
+    }
+
+    #[test]
+    fn red_silo_takes_more_red_but_still_limits_blue() {
+        let mut device = test_device(Vec::new());
+        device.capacity_per_frequency = 500;
+        device.per_frequency_capacity.push(FrequencyCapacity { frequency: FREQ_RED, capacity: 3500 });
+        assert!(check_storage_capacity(&device).is_ok());
+
+        assert_eq!(storage_capacity_for(&device, FREQ_RED), 3500);
+        assert_eq!(storage_capacity_for(&device, FREQ_BLUE), 500);
+
+        // Raising the default again would push the device past its total
+        device.capacity_per_frequency = DEFAULT_STORAGE_CAPACITY_PER_FREQUENCY;
+        assert!(check_storage_capacity(&device).is_err());
+    }
+
+    #[test]
+    fn same_frequency_tolerance_boundary() {
+        assert!(same_frequency(FREQ_GREEN, FREQ_GREEN + FREQUENCY_TOLERANCE * 0.5));
+        assert!(!same_frequency(FREQ_GREEN, FREQ_GREEN + FREQUENCY_TOLERANCE * 1.5));
+        assert!(!same_frequency(FREQ_RED, FREQ_YELLOW));
+    }
+
+    #[test]
+    fn frequency_distance_wraps_around_the_circle() {
+        assert!(frequency_distance(0.001, 2.0 * PI - 0.001) < 0.01);
+        assert!((frequency_distance(FREQ_RED, FREQ_CYAN) - PI).abs() < 0.01);
+        // Just below 2π is red, not magenta
+        assert!(same_frequency(2.0 * PI - 0.001, FREQ_RED));
+    }
+
+    #[test]
+    fn canonical_frequency_snaps_to_nearest_color() {
+        assert_eq!(canonical_frequency(0.3), FREQ_RED);
+        assert_eq!(canonical_frequency(0.8), FREQ_YELLOW);
+        assert_eq!(canonical_frequency(4.2), FREQ_BLUE);
+        assert_eq!(canonical_frequency(6.2), FREQ_RED);
+        for frequency in ALL_FREQUENCIES {
+            assert_eq!(canonical_frequency(frequency), frequency);
+        }
+    }
+
+    #[test]
+    fn transfer_batches_limit_near_duplicate_frequencies_together() {
+        // 4 + 4 packets of (nearly) the same red count against one 5-per-frequency limit
+        let batches = create_transfer_batches(&[sample(FREQ_RED, 4), sample(FREQ_RED + 0.004, 4)]);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0], vec![sample(FREQ_RED, 5)]);
+        assert_eq!(batches[1], vec![sample(FREQ_RED, 3)]);
+    }
+
+    #[test]
+    fn transfer_batches_cap_total_per_batch() {
+        let all: Vec<WavePacketSample> = ALL_FREQUENCIES.iter().map(|f| sample(*f, 12)).collect();
+        let batches = create_transfer_batches(&all);
+
+        let sent: u32 = batches.iter().map(|b| composition::total_count(b)).sum();
+        assert_eq!(sent, 72);
+        for batch in &batches {
+            assert!(composition::total_count(batch) <= 30);
+            assert!(batch.iter().all(|s| s.count <= 5));
+        }
+    }
+
+    #[test]
+    fn tuning_loss_does_not_overflow() {
+        assert_eq!(tuned_amount(10), 8);
+        assert_eq!(tuned_amount(1), 0);
+        assert_eq!(tuned_amount(u32::MAX), (u32::MAX as u64 * 80 / 100) as u32);
+    }
+
+    fn test_crystal(crystal_type: CrystalType, extra_types: Vec<CrystalType>) -> PlayerCrystal {
+        PlayerCrystal {
+            player_id: 1,
+            crystal_type,
+            slot_count: 1 + extra_types.len() as u8,
+            chosen_at: 0,
+            extra_types,
+        }
+    }
+
+    #[test]
+    fn one_crystal_covers_its_color_and_neighbours() {
+        let red = test_crystal(CrystalType::Red, Vec::new());
+        assert!(crystal_slots_cover(&red, FREQ_MAGENTA));
+        assert!(crystal_slots_cover(&red, FREQ_RED));
+        assert!(crystal_slots_cover(&red, FREQ_YELLOW));
+        assert!(!crystal_slots_cover(&red, FREQ_GREEN));
+        assert!(!crystal_slots_cover(&red, FREQ_CYAN));
+        assert!(!crystal_slots_cover(&red, FREQ_BLUE));
+    }
+
+    #[test]
+    fn second_slot_mines_colors_one_slot_cannot() {
+        let one_slot = test_crystal(CrystalType::Red, Vec::new());
+        let two_slots = test_crystal(CrystalType::Red, vec![CrystalType::Blue]);
+
+        // Yellow and Cyan together need both crystals
+        assert!(crystal_slots_cover(&two_slots, FREQ_YELLOW) && crystal_slots_cover(&two_slots, FREQ_CYAN));
+        assert!(!crystal_slots_cover(&one_slot, FREQ_CYAN));
+    }
+
+    #[test]
+    fn shell_lattice_sizes() {
+        let shell_1 = shell_lattice_coords(1);
+        assert_eq!(shell_1.len(), 6);
+        assert!(shell_1.iter().all(|(_, world_type)| *world_type == "Prime"));
+
+        assert_eq!(shell_lattice_coords(2).len(), 18);
+        assert_eq!(shell_lattice_coords(3).len(), 26);
+    }
+
+    #[test]
+    fn shell_coords_scale_with_level_and_have_unique_names() {
+        let shell = shell_lattice_coords(3);
+        for (coords, _) in &shell {
+            assert!([coords.x, coords.y, coords.z].iter().all(|c| *c == 0 || c.abs() == 3));
+        }
+
+        let mut names: Vec<String> = shell.iter().map(|(coords, _)| shell_world_name(coords, 3)).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), shell.len());
+        assert!(names.contains(&"North 3".to_string()));
+    }
+
+    #[test]
+    fn packet_speed_changes_travel_time() {
+        // 100 units flat at speed 5 vs 20
+        assert_eq!(calculate_travel_time(100.0, "SphereToSphere", 5.0), Duration::from_secs(20));
+        assert_eq!(calculate_travel_time(100.0, "SphereToSphere", 20.0), Duration::from_secs(5));
+
+        // Rising legs add the sphere/object height difference
+        let climb = SPHERE_PACKET_HEIGHT - OBJECT_PACKET_HEIGHT;
+        assert_eq!(calculate_travel_time(100.0 - climb, "ObjectToSphere", 20.0), Duration::from_secs(5));
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{} is not close to {}", actual, expected);
+    }
+
+    #[test]
+    fn surface_project_keeps_direction_and_sets_radius() {
+        let point = DbVector3::new(3.0, 4.0, 12.0);
+        let projected = point.surface_project(300.0);
+        assert_close(projected.magnitude(), 300.0);
+        assert_close(projected.normalize().dot(&point.normalize()), 1.0);
+    }
+
+    #[test]
+    fn ellipsoid_project_matches_surface_project_on_a_sphere() {
+        let point = DbVector3::new(-2.0, 7.0, 1.5);
+        let radii = DbVector3::new(300.0, 300.0, 300.0);
+        let on_ellipsoid = point.ellipsoid_project(&radii, 5.0);
+        let on_sphere = point.surface_project(305.0);
+        assert_close(on_ellipsoid.distance_to(&on_sphere) / 305.0, 0.0);
+    }
+
+    #[test]
+    fn ellipsoid_project_lands_on_the_ellipsoid() {
+        let radii = DbVector3::new(400.0, 250.0, 300.0);
+        for point in [
+            DbVector3::new(1.0, 0.0, 0.0),
+            DbVector3::new(0.0, -3.0, 0.0),
+            DbVector3::new(3.0, 4.0, 12.0),
+            DbVector3::new(-5.0, 2.0, -1.0),
+        ] {
+            let projected = point.ellipsoid_project(&radii, 0.0);
+            let (x, y, z) = (projected.x / radii.x, projected.y / radii.y, projected.z / radii.z);
+            assert_close(x * x + y * y + z * z, 1.0);
+            assert_close(projected.normalize().dot(&point.normalize()), 1.0);
+        }
+    }
+
+    #[test]
+    fn arc_distance_is_angle_times_radius() {
+        let x = DbVector3::new(1.0, 0.0, 0.0);
+        let y = DbVector3::new(0.0, 2.0, 0.0);
+        assert_close(x.arc_distance_to(&y, 300.0), PI / 2.0 * 300.0);
+        // Only direction matters, not magnitude
+        assert_close(x.arc_distance_to(&x.scale(5.0), 300.0), 0.0);
+        assert_close(x.arc_distance_to(&x.scale(-1.0), 1.0), PI);
+    }
+
+    #[test]
+    fn tangent_basis_is_orthonormal() {
+        let normals = [
+            DbVector3::new(1.0, 0.0, 0.0),
+            DbVector3::new(0.0, 1.0, 0.0),
+            DbVector3::new(0.0, -300.0, 0.0),
+            DbVector3::new(0.3, 0.95, 0.1),
+            DbVector3::new(-2.0, 1.0, 5.0),
+        ];
+        for point in normals {
+            let normal = point.normalize();
+            let (t1, t2) = point.tangent_basis();
+            assert_close(t1.magnitude(), 1.0);
+            assert_close(t2.magnitude(), 1.0);
+            assert_close(t1.dot(&t2), 0.0);
+            assert_close(t1.dot(&normal), 0.0);
+            assert_close(t2.dot(&normal), 0.0);
+        }
+    }
+
+    #[test]
+    fn sphere_buffer_overflow_is_cut_from_arriving_transfer() {
+        let mut sphere = test_sphere(10);
+
+        let mut first = vec![sample(FREQ_RED, 6)];
+        assert_eq!(add_to_sphere_buffer(&mut sphere, &mut first), 0);
+        assert_eq!(composition::total_count(&first), 6);
+
+        // 4 slots left: the 3 highest-frequency packets of the second arrival are dropped
+        let mut second = vec![sample(FREQ_RED, 2), sample(FREQ_BLUE, 5)];
+        assert_eq!(add_to_sphere_buffer(&mut sphere, &mut second), 3);
+        assert_eq!(second, vec![sample(FREQ_RED, 2), sample(FREQ_BLUE, 2)]);
+        assert_eq!(composition::total_count(&sphere.transit_buffer), 10);
+    }
+
+    #[test]
+    fn full_sphere_buffer_drops_whole_arrival() {
+        let mut sphere = test_sphere(5);
+        sphere.transit_buffer = vec![sample(FREQ_GREEN, 5)];
+
+        let mut arriving = vec![sample(FREQ_GREEN, 3)];
+        assert_eq!(add_to_sphere_buffer(&mut sphere, &mut arriving), 3);
+        assert!(arriving.is_empty());
+        assert_eq!(sphere.transit_buffer, vec![sample(FREQ_GREEN, 5)]);
+    }
+
+    #[test]
+    fn departing_packets_leave_sphere_buffer() {
+        let mut sphere = test_sphere(100);
+        let mut a = vec![sample(FREQ_RED, 4), sample(FREQ_CYAN, 2)];
+        let mut b = vec![sample(FREQ_RED, 3)];
+        add_to_sphere_buffer(&mut sphere, &mut a);
+        add_to_sphere_buffer(&mut sphere, &mut b);
+
+        composition::remove_up_to(&mut sphere.transit_buffer, &a);
+        assert_eq!(sphere.transit_buffer, vec![sample(FREQ_RED, 3)]);
+
+        composition::remove_up_to(&mut sphere.transit_buffer, &b);
+        assert!(sphere.transit_buffer.is_empty());
+    }
+
+    #[test]
+    fn nan_or_infinite_position_is_rejected() {
+        let origin = WorldCoords { x: 0, y: 0, z: 0 };
+        let radii = DbVector3::new(300.0, 300.0, 300.0);
+        assert!(check_player_position(&DbVector3::new(f32::NAN, 301.0, 0.0), &origin, &radii).is_err());
+        assert!(check_player_position(&DbVector3::new(0.0, f32::INFINITY, 0.0), &origin, &radii).is_err());
+    }
+
+    #[test]
+    fn surface_position_is_accepted_on_center_and_outer_worlds() {
+        let radii = DbVector3::new(300.0, 300.0, 300.0);
+        let surface = DbVector3::new(0.0, 301.0, 0.0);
+        for world in [WorldCoords { x: 0, y: 0, z: 0 }, WorldCoords { x: 1, y: 0, z: -1 }] {
+            let position = world_center(&world).add(&surface);
+            assert!(check_player_position(&position, &world, &radii).is_ok());
+        }
+    }
+
+    #[test]
+    fn position_far_from_its_world_is_rejected() {
+        let radii = DbVector3::new(300.0, 300.0, 300.0);
+        let outer = WorldCoords { x: 1, y: 0, z: 0 };
+        // On the center world's surface, but claimed to be on (1,0,0)
+        assert!(check_player_position(&DbVector3::new(0.0, 301.0, 0.0), &outer, &radii).is_err());
+        let far = world_center(&outer).add(&DbVector3::new(0.0, 300.0 * MAX_POSITION_RADII + 1.0, 0.0));
+        assert!(check_player_position(&far, &outer, &radii).is_err());
+    }
+
+    #[test]
+    fn teleport_jump_is_rejected() {
+        let start = DbVector3::new(0.0, 301.0, 0.0);
+        let jump = start.add(&DbVector3::new(1000.0, 0.0, 0.0));
+        assert!(check_player_movement(&start, &jump, 100, MAX_PLAYER_SPEED).is_err());
+    }
+
+    #[test]
+    fn normal_walk_is_accepted() {
+        let start = DbVector3::new(0.0, 301.0, 0.0);
+        // One second at full speed, then a 100ms step
+        let walked = start.add(&DbVector3::new(MAX_PLAYER_SPEED, 0.0, 0.0));
+        assert!(check_player_movement(&start, &walked, 1000, MAX_PLAYER_SPEED).is_ok());
+        let step = start.add(&DbVector3::new(0.0, 0.0, MAX_PLAYER_SPEED * 0.1));
+        assert!(check_player_movement(&start, &step, 100, MAX_PLAYER_SPEED).is_ok());
+    }
+
+    #[test]
+    fn first_update_after_travel_is_measured_from_the_new_spawn() {
+        let old_position = DbVector3::new(0.0, 301.0, 0.0);
+        let destination = WorldCoords { x: 1, y: 0, z: 0 };
+        // travel_to_world moves the stored position to the destination's north pole
+        let spawn = world_center(&destination).add(&DbVector3::new(0.0, 300.0 + SURFACE_OFFSET, 0.0));
+        let first_update = spawn.add(&DbVector3::new(0.5, 0.0, 0.5));
+
+        assert!(check_player_movement(&old_position, &first_update, 100, MAX_PLAYER_SPEED).is_err());
+        assert!(check_player_movement(&spawn, &first_update, 100, MAX_PLAYER_SPEED).is_ok());
+    }
+
+    #[test]
+    fn game_error_display_per_variant() {
+        assert_eq!(GameError::NotFound("Orb".to_string()).to_string(), "Orb not found");
+        assert_eq!(GameError::Unauthorized("Not your device".to_string()).to_string(), "Not your device");
+        assert_eq!(GameError::CapacityExceeded("Inventory full".to_string()).to_string(), "Inventory full");
+        assert_eq!(GameError::Cooldown(1500).to_string(), "On cooldown (1500 ms remaining)");
+        assert_eq!(GameError::InvalidInput("Bad frequency".to_string()).to_string(), "Bad frequency");
+        assert_eq!(GameError::InvalidState("Transfer already completed".to_string()).to_string(), "Transfer already completed");
+    }
+
+    #[test]
+    fn game_error_into_string_matches_display() {
+        let errors = [
+            GameError::NotFound("Player".to_string()),
+            GameError::Unauthorized("Admin only".to_string()),
+            GameError::CapacityExceeded("Device full".to_string()),
+            GameError::Cooldown(250),
+            GameError::InvalidInput("Empty name".to_string()),
+            GameError::InvalidState("Session inactive".to_string()),
+        ];
+
+        for error in errors {
+            let expected = error.to_string();
+            let message: String = error.into();
+            assert_eq!(message, expected);
+        }
+
+        let result: Result<(), String> = Err(GameError::NotFound("Orb".to_string()).into());
+        assert_eq!(result, Err("Orb not found".to_string()));
+    }
+
+    #[test]
+    fn direction_metadata_normals_and_colors_follow_the_tier() {
+        for direction in ALL_DIRECTIONS {
+            let (normal, color, frequency) = direction_metadata(direction);
+            assert_close(normal.magnitude(), 1.0);
+
+            let axes = ["East", "West", "North", "South", "Forward", "Back"]
+                .iter()
+                .filter(|axis| direction.contains(*axis))
+                .count();
+            let tier_colors: &[&str] = match axes {
+                1 => &["Red", "Green", "Blue"],
+                2 => &["Yellow", "Cyan", "Magenta"],
+                _ => &["White"],
+            };
+            assert!(tier_colors.contains(&color.as_str()), "{} has color {}", direction, color);
+            assert!(frequency >= FREQ_RED && frequency <= FREQ_MAGENTA, "{} has frequency {}", direction, frequency);
+        }
+
+        let (normal, color, frequency) = direction_metadata("East");
+        assert_eq!((color.as_str(), frequency), ("Red", FREQ_RED));
+        assert_close(normal.x, 1.0);
+        assert_eq!(direction_metadata("North").1, "Green");
+        assert_eq!(direction_metadata("Back").1, "Blue");
+
+        let (normal, color, frequency) = direction_metadata("NorthEast");
+        assert_eq!((color.as_str(), frequency), ("Yellow", FREQ_YELLOW));
+        assert_close(normal.x, std::f32::consts::FRAC_1_SQRT_2);
+        assert_close(normal.y, std::f32::consts::FRAC_1_SQRT_2);
+        assert_close(normal.z, 0.0);
+
+        let (normal, color, frequency) = direction_metadata("SouthWestBack");
+        assert_eq!((color.as_str(), frequency), ("White", FREQ_RED));
+        let inv_sqrt3 = 1.0 / 3.0_f32.sqrt();
+        assert_close(normal.x, -inv_sqrt3);
+        assert_close(normal.y, -inv_sqrt3);
+        assert_close(normal.z, -inv_sqrt3);
+    }
+
+    #[test]
+    fn unknown_direction_is_grey_and_points_north() {
+        for direction in ["Up", "", "northeast"] {
+            let (normal, color, frequency) = direction_metadata(direction);
+            assert_eq!((color.as_str(), frequency), ("Grey", FREQ_RED));
+            assert_close(normal.y, 1.0);
+        }
+        assert_eq!(get_tunnel_color("Up"), "Grey");
+    }
+
+    fn test_world(world_id: u64, world_coords: WorldCoords) -> World {
+        World {
+            world_id,
+            world_coords,
+            world_name: format!("World {}", world_id),
+            world_type: "Prime".to_string(),
+            shell_level: 1,
+            allowed_frequencies: Vec::new(),
+            radius_x: None,
+            radius_y: None,
+            radius_z: None,
+        }
+    }
+
+    #[test]
+    fn ticking_one_world_leaves_other_world_counters_alone() {
+        let busy = test_world(1, WorldCoords { x: 1, y: 0, z: 0 });
+        let quiet = test_world(2, WorldCoords { x: -1, y: 0, z: 0 });
+        let mut counters: HashMap<u64, WorldTickCounter> = HashMap::new();
+
+        for _ in 0..25 {
+            let counter = next_world_tick_counter(counters.get(&busy.world_id), &busy, 1);
+            counters.insert(counter.world_id, counter);
+        }
+
+        assert_eq!(counters[&busy.world_id].tick_count, 25);
+        assert_eq!(counters[&busy.world_id].world_coords, busy.world_coords);
+        assert!(counters.get(&quiet.world_id).is_none());
+
+        // The quiet world starts its own count from 1, so its pulses aren't pulled forward
+        let quiet_counter = next_world_tick_counter(counters.get(&quiet.world_id), &quiet, 1);
+        assert_eq!(quiet_counter.tick_count, 1);
+        assert_eq!(quiet_counter.world_coords, quiet.world_coords);
+        assert_eq!(counters[&busy.world_id].tick_count, 25);
+    }
+
+    #[test]
+    fn game_loop_slows_when_idle_and_wakes_on_activity() {
+        let mut idle_ticks = 0;
+        let mut idle_mode = false;
+
+        for tick in 1..GAME_LOOP_IDLE_TICKS {
+            (idle_ticks, idle_mode) = next_idle_state(idle_ticks, true);
+            assert!(!idle_mode, "slowed after only {} idle ticks", tick);
+        }
+        assert_eq!(idle_tick_step(idle_mode), 1);
+
+        (idle_ticks, idle_mode) = next_idle_state(idle_ticks, true);
+        assert!(idle_mode);
+        assert_eq!(idle_tick_step(idle_mode), GAME_LOOP_IDLE_INTERVAL_MS / GAME_LOOP_ACTIVE_INTERVAL_MS);
+
+        (idle_ticks, idle_mode) = next_idle_state(idle_ticks, true);
+        assert!(idle_mode);
+
+        // One busy pass snaps straight back to full speed
+        (idle_ticks, idle_mode) = next_idle_state(idle_ticks, false);
+        assert_eq!((idle_ticks, idle_mode), (0, false));
+        assert_eq!(idle_tick_step(idle_mode), 1);
+    }
+
+    #[test]
+    fn pulses_keep_wall_clock_cadence_while_idle() {
+        let pulses = |step: u64, passes: u64, every: u64| {
+            (0..passes).filter(|pass| pulse_due(pass * step, (pass + 1) * step, every)).count()
+        };
+
+        // 20 seconds: 200 fast passes or 20 idle passes fire the same pulses
+        let idle_step = idle_tick_step(true);
+        assert_eq!(pulses(1, 200, 20), 10);
+        assert_eq!(pulses(idle_step, 200 / idle_step, 20), 10);
+        assert_eq!(pulses(1, 200, 100), 2);
+        assert_eq!(pulses(idle_step, 200 / idle_step, 100), 2);
+    }
+
+    #[test]
+    fn distribution_fills_devices_in_order() {
+        let first = StorageDevice { device_id: 1, capacity_per_frequency: 1000, ..test_device(Vec::new()) };
+        let second = StorageDevice { device_id: 2, capacity_per_frequency: 1000, ..test_device(Vec::new()) };
+
+        let allocations = allocate_to_devices(&[first.clone(), second.clone()], &[sample(FREQ_BLUE, 2000)]).unwrap();
+        assert_eq!(allocations, vec![vec![sample(FREQ_BLUE, 1000)], vec![sample(FREQ_BLUE, 1000)]]);
+
+        // Existing contents shrink the first device's share; order decides who fills first
+        let partly_full = StorageDevice { stored_composition: vec![sample(FREQ_BLUE, 900)], ..first };
+        let allocations = allocate_to_devices(&[second.clone(), partly_full.clone()], &[sample(FREQ_BLUE, 1050)]).unwrap();
+        assert_eq!(allocations, vec![vec![sample(FREQ_BLUE, 1000)], vec![sample(FREQ_BLUE, 50)]]);
+
+        let allocations = allocate_to_devices(&[partly_full, second], &[sample(FREQ_BLUE, 1050)]).unwrap();
+        assert_eq!(allocations, vec![vec![sample(FREQ_BLUE, 100)], vec![sample(FREQ_BLUE, 950)]]);
+    }
+
+    #[test]
+    fn distribution_rejects_what_the_devices_cannot_hold() {
+        let first = StorageDevice { device_id: 1, capacity_per_frequency: 1000, ..test_device(Vec::new()) };
+        let second = StorageDevice { device_id: 2, capacity_per_frequency: 1000, ..test_device(Vec::new()) };
+
+        let result = allocate_to_devices(&[first.clone(), second.clone()], &[sample(FREQ_BLUE, 2001)]);
+        assert!(result.unwrap_err().contains("1 more packets"));
+
+        // Room is per frequency - blue headroom doesn't help red
+        let red_full = StorageDevice { stored_composition: vec![sample(FREQ_RED, 1000)], ..first };
+        let red_full_too = StorageDevice { stored_composition: vec![sample(FREQ_RED, 1000)], ..second };
+        assert!(allocate_to_devices(&[red_full, red_full_too], &[sample(FREQ_RED, 1)]).is_err());
+    }
+
+    fn test_inventory(composition: Vec<WavePacketSample>) -> PlayerInventory {
+        PlayerInventory {
+            player_id: 1,
+            total_count: composition::total_count(&composition),
+            inventory_composition: composition,
+            last_updated: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn transfer_on_a_deleted_sphere_is_reclaimed_to_inventory() {
+        let mut inventory = test_inventory(vec![sample(FREQ_RED, 200), sample(FREQ_BLUE, 50)]);
+        let sent = vec![sample(FREQ_RED, 120), sample(FREQ_BLUE, 50)];
+        composition::subtract_composition(&mut inventory.inventory_composition, &sent).unwrap();
+        inventory.total_count -= composition::total_count(&sent);
+
+        let route = [11, 12, 13];
+        let mut spheres: Vec<u64> = route.to_vec();
+        assert!(!route_has_missing_sphere(&route, |id| spheres.contains(id)));
+
+        spheres.retain(|id| *id != 12);
+        assert!(route_has_missing_sphere(&route, |id| spheres.contains(id)));
+
+        assert_eq!(add_to_inventory_capped(&mut inventory, &sent), 170);
+        assert_eq!(inventory.total_count, 250);
+        let count_of = |frequency: f32| inventory.inventory_composition.iter()
+            .filter(|s| same_frequency(s.frequency, frequency))
+            .map(|s| s.count)
+            .sum::<u32>();
+        assert_eq!(count_of(FREQ_RED), 200);
+        assert_eq!(count_of(FREQ_BLUE), 50);
+    }
+
+    #[test]
+    fn inventory_refund_stops_at_the_cap() {
+        let mut inventory = test_inventory(vec![sample(FREQ_GREEN, MAX_INVENTORY_PACKETS - 10)]);
+
+        assert_eq!(add_to_inventory_capped(&mut inventory, &[sample(FREQ_RED, 25)]), 10);
+        assert_eq!(inventory.total_count, MAX_INVENTORY_PACKETS);
+        assert_eq!(add_to_inventory_capped(&mut inventory, &[sample(FREQ_RED, 5)]), 0);
+    }
+
+    fn test_transfer(composition: Vec<WavePacketSample>, route_spire_ids: Vec<u64>) -> PacketTransfer {
+        PacketTransfer {
+            transfer_id: 1,
+            player_id: 7,
+            packet_count: composition::total_count(&composition),
+            composition,
+            route_waypoints: Vec::new(),
+            route_spire_ids,
+            destination_device_id: 1,
+            initiated_at: Timestamp::UNIX_EPOCH,
+            completed: false,
+            current_leg: 0,
+            leg_start_time: Timestamp::UNIX_EPOCH,
+            state: "InTransit".to_string(),
+            source_object_type: "Player".to_string(),
+            source_object_id: 7,
+            destination_object_type: "StorageDevice".to_string(),
+            destination_object_id: 1,
+            current_leg_type: "PendingAtObject".to_string(),
+            predicted_arrival_time: Timestamp::UNIX_EPOCH,
+            priority: 0,
+            world_coords: WorldCoords { x: 0, y: 0, z: 0 },
+        }
+    }
+
+    #[test]
+    fn transfer_completes_after_its_sender_disconnects() {
+        let transfer = test_transfer(vec![sample(FREQ_GREEN, 40)], vec![11, 12]);
+        let device = test_device(Vec::new());
+
+        // Sender 7 logged out after initiating: only the logged_out_player row is left
+        let logged_out = vec![
+            LoggedOutPlayer {
+                identity: Identity::ZERO,
+                player_id: 7,
+                name: "Sender".to_string(),
+                account_id: None,
+                logout_time: Timestamp::UNIX_EPOCH,
+                last_world: transfer.world_coords,
+                last_position: DbVector3::new(0.0, WORLD_RADIUS, 5.0),
+                last_rotation: DbQuaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+            },
+        ];
+        let departure = logged_out_position(logged_out.clone().into_iter(), transfer.source_object_id).unwrap();
+        assert_close(departure.z, 5.0);
+        assert!(logged_out_position(logged_out.into_iter(), 8).is_none());
+
+        // Delivery goes by the device alone - nothing on the way needs the sender's Player row
+        let (accepted, overflow) = split_storage_delivery(&device, &transfer.composition, |_| true);
+        assert_eq!(accepted, transfer.composition);
+        assert!(overflow.is_empty());
+    }
+
+    #[test]
+    fn completed_single_hop_transfer_logs_its_duration() {
+        let mut transfer = test_transfer(vec![sample(FREQ_RED, 10)], vec![11]);
+        transfer.initiated_at = Timestamp::from_micros_since_unix_epoch(1_000_000);
+        let completed_at = Timestamp::from_micros_since_unix_epoch(3_500_000);
+
+        let entry = transfer_log_entry(&transfer, completed_at);
+        assert_eq!(entry.actual_duration_ms, 2_500);
+        assert_eq!(entry.hop_count, 1);
+        assert_eq!(entry.player_id, 7);
+        assert_eq!(entry.packet_count, 10);
+
+        // A clock that reads earlier than initiation logs 0 rather than wrapping
+        let entry = transfer_log_entry(&transfer, Timestamp::from_micros_since_unix_epoch(0));
+        assert_eq!(entry.actual_duration_ms, 0);
+    }
+
+    #[test]
+    fn nearest_spire_matches_brute_force_on_a_full_world() {
+        let spires: Vec<DistributionSphere> = ALL_DIRECTIONS.iter()
+            .enumerate()
+            .map(|(i, direction)| DistributionSphere {
+                sphere_id: i as u64 + 1,
+                cardinal_direction: direction.to_string(),
+                sphere_position: direction_metadata(direction).0.scale(WORLD_RADIUS + 40.0),
+                ..test_sphere(100)
+            })
+            .collect();
+
+        // Reference: full scan with true distances, first strictly-nearest wins
+        let brute_force = |position: &DbVector3| {
+            let mut nearest = &spires[0];
+            for spire in &spires {
+                if spire.sphere_position.distance_to(position) < nearest.sphere_position.distance_to(position) {
+                    nearest = spire;
+                }
+            }
+            nearest.sphere_id
+        };
+
+        for i in 0..500 {
+            let theta = i as f32 * 0.37;
+            let phi = i as f32 * 0.23;
+            let position = DbVector3::new(phi.sin() * theta.cos(), phi.cos(), phi.sin() * theta.sin())
+                .scale(WORLD_RADIUS * (0.5 + (i % 7) as f32 * 0.25));
+            assert_eq!(nearest_spire_in(&spires, position).unwrap().sphere_id, brute_force(&position), "probe {}", i);
+        }
+
+        assert!(nearest_spire_in(&[], DbVector3::zero()).is_none());
+        assert_eq!(nearest_spire_in(&spires[3..4], DbVector3::new(0.0, WORLD_RADIUS, 0.0)).unwrap().sphere_id, 4);
+    }
+
+    #[test]
+    fn force_completing_a_fresh_transfer_charges_every_sphere_and_fills_storage() {
+        let mut transfer = test_transfer(vec![sample(FREQ_BLUE, 30), sample(FREQ_RED, 20)], vec![11, 12, 13]);
+        assert_eq!(first_unreached_sphere(&transfer), 0);
+
+        let mut device = test_device(vec![sample(FREQ_BLUE, 5)]);
+        let (accepted, overflow) = split_storage_delivery(&device, &transfer.composition, |_| true);
+        assert!(overflow.is_empty());
+        composition::merge_compositions(&mut device.stored_composition, &accepted);
+        assert_eq!(stored_at_frequency(&device, FREQ_BLUE), 35);
+        assert_eq!(stored_at_frequency(&device, FREQ_RED), 20);
+
+        // Part-way transfers only charge the spheres still ahead of them
+        transfer.current_leg_type = "SphereToSphere".to_string();
+        transfer.current_leg = 1;
+        assert_eq!(first_unreached_sphere(&transfer), 1);
+        transfer.current_leg_type = "ArrivedAtSphere".to_string();
+        assert_eq!(first_unreached_sphere(&transfer), 2);
+        transfer.current_leg_type = "SphereToObject".to_string();
+        assert_eq!(first_unreached_sphere(&transfer), 3);
+    }
+
+    #[test]
+    fn orb_extraction_routed_to_storage_lands_in_the_device() {
+        let extraction = vec![sample(FREQ_BLUE, 40), sample(FREQ_CYAN, 15)];
+        let mut device = StorageDevice { capacity_per_frequency: 50, ..test_device(vec![sample(FREQ_BLUE, 10)]) };
+        assert!(check_storage_room(&device, &extraction).is_ok());
+
+        // The orb is the transfer source, so nothing is taken from or refunded to an inventory
+        let mut transfer = test_transfer(extraction.clone(), vec![11, 12]);
+        transfer.source_object_type = "Orb".to_string();
+        transfer.source_object_id = 42;
+
+        let (accepted, overflow) = split_storage_delivery(&device, &transfer.composition, |_| true);
+        assert!(overflow.is_empty());
+        composition::merge_compositions(&mut device.stored_composition, &accepted);
+        assert_eq!(stored_at_frequency(&device, FREQ_BLUE), 50);
+        assert_eq!(stored_at_frequency(&device, FREQ_CYAN), 15);
+
+        // A device without room for the whole extraction is refused before the orb is touched
+        let err = check_storage_room(&device, &[sample(FREQ_BLUE, 1)]).unwrap_err();
+        assert!(err.starts_with("Storage full for frequency"));
+    }
+
+    #[test]
+    fn extract_by_color_blue_requests_the_blue_packets() {
+        let requests = color_extraction_requests(&["Blue".to_string()], &[5]).unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!((requests[0].frequency, requests[0].count), (FREQ_BLUE, 5));
+
+        // The extraction matches orb samples by frequency, so only the blue sample is hit
+        let orb = [sample(FREQ_RED, 20), sample(FREQ_BLUE, 8), sample(FREQ_MAGENTA, 3)];
+        let matched: Vec<&WavePacketSample> = orb.iter()
+            .filter(|s| same_frequency(s.frequency, requests[0].frequency))
+            .collect();
+        assert_eq!(matched, vec![&orb[1]]);
+    }
+
+    #[test]
+    fn color_names_map_to_canonical_frequencies() {
+        assert_eq!(color_frequency("Red"), Some(FREQ_RED));
+        assert_eq!(color_frequency("yellow"), Some(FREQ_YELLOW));
+        assert_eq!(color_frequency("GREEN"), Some(FREQ_GREEN));
+        assert_eq!(color_frequency(" Cyan "), Some(FREQ_CYAN));
+        assert_eq!(color_frequency("blue"), Some(FREQ_BLUE));
+        assert_eq!(color_frequency("Magenta"), Some(FREQ_MAGENTA));
+        assert_eq!(color_frequency("Grey"), None);
+
+        let colors = vec!["Red".to_string(), "Teal".to_string()];
+        assert!(color_extraction_requests(&colors, &[1, 2]).unwrap_err().contains("Unknown color: Teal"));
+        assert!(color_extraction_requests(&colors, &[1]).is_err());
+    }
+
+    #[test]
+    fn warn_log_level_suppresses_info() {
+        let warn = log_level_filter("warn").unwrap();
+        assert!(log::Level::Info > warn);
+        assert!(log::Level::Warn <= warn);
+        assert!(log::Level::Error <= warn);
+
+        let info = log_level_filter("info").unwrap();
+        assert!(log::Level::Info <= info);
+        assert!(log::Level::Debug > info);
+        assert!(log::Level::Debug <= log_level_filter("debug").unwrap());
+
+        // Warnings and errors can't be switched off
+        assert_eq!(log_level_filter("error"), None);
+        assert_eq!(log_level_filter("off"), None);
+        assert_eq!(log_level_filter("WARN"), None);
+    }
+
+    #[test]
+    fn net_worth_is_inventory_plus_storage_after_a_deposit() {
+        let mut inventory = vec![sample(FREQ_RED, 100), sample(FREQ_BLUE, 10)];
+        let mut device = test_device(Vec::new());
+        let other_device = test_device(vec![sample(FREQ_GREEN, 5)]);
+
+        // Deposit 40 red + 10 blue: out of inventory, into the first device
+        let deposit = vec![sample(FREQ_RED, 40), sample(FREQ_BLUE, 10)];
+        composition::subtract_composition(&mut inventory, &deposit).unwrap();
+        composition::merge_compositions(&mut device.stored_composition, &deposit);
+
+        let storage = merged_compositions([&device.stored_composition, &other_device.stored_composition]);
+        let in_flight: Vec<WavePacketSample> = Vec::new();
+        let holdings = merged_compositions([&inventory, &storage, &in_flight]);
+
+        assert_eq!(composition::total_count(&holdings),
+            composition::total_count(&inventory) + composition::total_count(&storage));
+        assert_eq!(composition::total_count(&holdings), 115);
+        let count_of = |frequency: f32| holdings.iter()
+            .filter(|s| same_frequency(s.frequency, frequency))
+            .map(|s| s.count)
+            .sum::<u32>();
+        assert_eq!((count_of(FREQ_RED), count_of(FREQ_BLUE), count_of(FREQ_GREEN)), (100, 10, 5));
+    }
+
+    #[test]
+    fn blue_orb_makes_blue_available_but_not_magenta() {
+        let world = WorldCoords { x: 0, y: 0, z: 0 };
+        let mut sources = vec![WavePacketSource {
+            wave_packet_composition: vec![sample(FREQ_BLUE, 12), sample(FREQ_GREEN, 3)],
+            total_wave_packets: 15,
+            ..test_source(0, 60_000)
+        }];
+
+        assert_eq!(frequency_availability_in(&sources, &world, FREQ_BLUE), (1, 12));
+        assert_eq!(frequency_availability_in(&sources, &world, FREQ_MAGENTA), (0, 0));
+
+        // Orbs still moving or in another world aren't counted
+        sources.push(WavePacketSource { source_id: 2, state: SOURCE_STATE_MOVING_H, ..sources[0].clone() });
+        sources.push(WavePacketSource { source_id: 3, world_coords: WorldCoords { x: 1, y: 0, z: 0 }, ..sources[0].clone() });
+        assert_eq!(frequency_availability_in(&sources, &world, FREQ_BLUE), (1, 12));
+    }
+
+    #[test]
+    fn radii_of_fills_unset_axes_with_the_default_radius() {
+        let mut oblate = test_world(1, WorldCoords { x: 0, y: 0, z: 0 });
+        oblate.radius_y = Some(240.0);
+        let radii = radii_of(Some(&oblate), 300.0);
+        assert_close(radii.x, 300.0);
+        assert_close(radii.y, 240.0);
+        assert_close(radii.z, 300.0);
+
+        let missing = radii_of(None, 300.0);
+        assert_close(missing.x, 300.0);
+        assert_close(missing.y, 300.0);
+        assert_close(missing.z, 300.0);
+    }
+}